@@ -0,0 +1,30 @@
+//! Detects properties of the target which are not available as stable `cfg`
+//! options.
+use std::env;
+
+/// The prefixes of the architecture component of ARM target triples with the
+/// `dmb` and `dsb` instructions, which are ARMv6-M, ARMv7, and ARMv8.
+const ARM_BARRIER_ARCHES: [&str; 7] = [
+    "armv7", "armebv7", "armv8", "armebv8", "thumbv6m", "thumbv7", "thumbv8",
+];
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rustc-check-cfg=cfg(arm_barriers)");
+    println!("cargo:rustc-check-cfg=cfg(armv6m)");
+
+    let target = env::var("TARGET").unwrap_or_default();
+    let arch = target.split('-').next().unwrap_or_default();
+    if env::var("CARGO_CFG_TARGET_ARCH").as_deref() == Ok("arm")
+        && ARM_BARRIER_ARCHES
+            .iter()
+            .any(|prefix| arch.starts_with(prefix))
+    {
+        println!("cargo:rustc-cfg=arm_barriers");
+    }
+    // The same condition the `cortex-m` crate uses for omitting the cache
+    // maintenance of the `SCB`.
+    if arch.starts_with("thumbv6m") {
+        println!("cargo:rustc-cfg=armv6m");
+    }
+}
@@ -0,0 +1,37 @@
+use core::ptr;
+
+/// Reads the value at `src` with [`ptr::read_volatile`], or with
+/// [`ptr::read`] if the `host-test` feature is enabled.
+///
+/// # Safety
+/// The requirements are the same as for [`ptr::read_volatile`].
+#[inline(always)]
+pub(crate) unsafe fn read<T>(src: *const T) -> T {
+    if cfg!(feature = "host-test") {
+        // SAFETY: The caller must ensure `src` is valid for reads, aligned,
+        // and points to an initialized `T`.
+        unsafe { ptr::read(src) }
+    } else {
+        // SAFETY: The caller must ensure `src` is valid for reads, aligned,
+        // and points to an initialized `T`.
+        unsafe { ptr::read_volatile(src) }
+    }
+}
+
+/// Writes `val` to `dst` with [`ptr::write_volatile`], or with
+/// [`ptr::write`] if the `host-test` feature is enabled.
+///
+/// # Safety
+/// The requirements are the same as for [`ptr::write_volatile`].
+#[inline(always)]
+pub(crate) unsafe fn write<T>(dst: *mut T, val: T) {
+    if cfg!(feature = "host-test") {
+        // SAFETY: The caller must ensure `dst` is valid for writes and
+        // aligned.
+        unsafe { ptr::write(dst, val) }
+    } else {
+        // SAFETY: The caller must ensure `dst` is valid for writes and
+        // aligned.
+        unsafe { ptr::write_volatile(dst, val) }
+    }
+}
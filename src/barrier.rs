@@ -0,0 +1,145 @@
+//! Memory barriers for memory-mapped I/O, for the target architecture.
+//!
+//! These allow portable drivers to order device accesses with respect to other
+//! memory accesses, such as when coordinating with a DMA engine, without each
+//! carrying their own architecture-specific code. They are also used by the
+//! [`DeviceFence`](crate::fence::DeviceFence) fence policy.
+//!
+//! | Architecture                  | Read barrier | Write barrier |
+//! |-------------------------------|--------------|---------------|
+//! | AArch64                       | `dsb ld`     | `dsb st`      |
+//! | ARMv6-M, ARMv7, 32-bit ARMv8  | `dmb`        | `dsb`         |
+//! | RISC-V                        | `fence i, r` | `fence w, o`  |
+//! | x86 (with SSE2)               | `mfence`     | `sfence`      |
+//! | PowerPC                       | `sync`       | `sync`        |
+//!
+//! On PowerPC, `eieio` does not order stores to cacheable memory before stores
+//! to caching-inhibited memory, so the write barrier is also a `sync`.
+//!
+//! On other architectures, both barriers are a sequentially consistent
+//! [`fence`](core::sync::atomic::fence). This is also the case under Miri and
+//! with the `host-test` feature, since inline assembly cannot be interpreted by
+//! Miri. Each barrier also prevents the compiler from reordering memory
+//! accesses across it.
+macro_rules! barriers {
+    ($read:expr, $write:expr) => {
+        /// Ensures that device reads before the barrier complete before memory
+        /// accesses after it.
+        #[inline]
+        pub fn device_read_barrier() {
+            $read
+        }
+
+        /// Ensures that memory accesses before the barrier complete before
+        /// device writes after it.
+        #[inline]
+        pub fn device_write_barrier() {
+            $write
+        }
+    };
+}
+
+// Unused on architectures with barrier instructions, except under Miri and with
+// the `host-test` feature.
+#[allow(unused_macros)]
+macro_rules! fence_barriers {
+    () => {
+        barriers!(
+            core::sync::atomic::fence(core::sync::atomic::Ordering::SeqCst),
+            core::sync::atomic::fence(core::sync::atomic::Ordering::SeqCst)
+        );
+    };
+}
+
+// Unused on architectures without barrier instructions.
+#[allow(unused_macros)]
+macro_rules! asm_barriers {
+    ($read:literal, $write:literal) => {
+        #[cfg(not(any(miri, feature = "host-test")))]
+        barriers!(
+            // SAFETY: The instruction is a barrier, which has no other effect.
+            unsafe { core::arch::asm!($read, options(nostack, preserves_flags)) },
+            // SAFETY: The instruction is a barrier, which has no other effect.
+            unsafe { core::arch::asm!($write, options(nostack, preserves_flags)) }
+        );
+        #[cfg(any(miri, feature = "host-test"))]
+        fence_barriers!();
+    };
+}
+
+#[cfg(target_arch = "aarch64")]
+asm_barriers!("dsb ld", "dsb st");
+
+#[cfg(all(target_arch = "arm", arm_barriers))]
+asm_barriers!("dmb", "dsb");
+
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+asm_barriers!("fence i, r", "fence w, o");
+
+#[cfg(any(
+    target_arch = "x86_64",
+    all(target_arch = "x86", target_feature = "sse2")
+))]
+asm_barriers!("mfence", "sfence");
+
+#[cfg(any(target_arch = "powerpc", target_arch = "powerpc64"))]
+asm_barriers!("sync", "sync");
+
+#[cfg(not(any(
+    target_arch = "aarch64",
+    all(target_arch = "arm", arm_barriers),
+    target_arch = "riscv32",
+    target_arch = "riscv64",
+    target_arch = "x86_64",
+    all(target_arch = "x86", target_feature = "sse2"),
+    target_arch = "powerpc",
+    target_arch = "powerpc64",
+)))]
+fence_barriers!();
+
+/// Runs `f` between a leading [`device_write_barrier`] and a trailing
+/// [`device_read_barrier`].
+///
+/// Only the boundaries of the block have hardware barriers, so the volatile
+/// accesses within it are not ordered with respect to each other beyond the
+/// usual guarantees of volatile accesses. This suits patterns such as
+/// publishing DMA descriptors, where memory writes before the block must
+/// complete before a doorbell register is written within it, and consuming
+/// them, where a status register read within the block must complete before the
+/// descriptors are read after it.
+///
+/// See [`OrderedScope`] for a guard with the same effect.
+pub fn ordered_scope<R>(f: impl FnOnce() -> R) -> R {
+    let _scope = OrderedScope::new();
+    f()
+}
+
+/// A guard which issues a [`device_write_barrier`] when created and a
+/// [`device_read_barrier`] when dropped.
+///
+/// See [`ordered_scope`] for details.
+#[derive(Debug)]
+#[must_use = "the trailing barrier is issued when the guard is dropped"]
+pub struct OrderedScope {
+    _private: (),
+}
+
+impl OrderedScope {
+    /// Issues the leading barrier and returns the guard.
+    pub fn new() -> Self {
+        device_write_barrier();
+        OrderedScope { _private: () }
+    }
+}
+
+impl Default for OrderedScope {
+    fn default() -> Self {
+        OrderedScope::new()
+    }
+}
+
+impl Drop for OrderedScope {
+    fn drop(&mut self) {
+        device_read_barrier();
+    }
+}
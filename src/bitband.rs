@@ -0,0 +1,96 @@
+use core::{mem, ptr};
+
+use crate::Volatile;
+
+/// The bit-band regions of the Cortex-M3 and Cortex-M4, as the base address of
+/// each region, and the base address of its alias region.
+const REGIONS: [(usize, usize); 2] = [
+    // SRAM.
+    (0x2000_0000, 0x2200_0000),
+    // Peripherals.
+    (0x4000_0000, 0x4200_0000),
+];
+
+/// The size of a bit-band region in bytes.
+const REGION_SIZE: usize = 0x10_0000;
+
+/// Returns the address of the word in the bit-band alias region for bit `bit`
+/// of the byte at `addr`, or `None` if `addr` is not in a bit-band region.
+fn alias_addr(addr: usize, bit: usize) -> Option<usize> {
+    REGIONS.iter().find_map(|&(base, alias)| {
+        let offset = addr.checked_sub(base).filter(|&off| off < REGION_SIZE)?;
+        Some(alias + offset * 32 + bit * 4)
+    })
+}
+
+impl<T: Copy, P> Volatile<T, P> {
+    /// Returns a reference to the word in the bit-band alias region of the
+    /// Cortex-M3 or Cortex-M4 for bit `n` of `self`, or `None` if `self` is
+    /// not entirely within a bit-band region.
+    ///
+    /// Bits are numbered from the least significant bit of the first byte,
+    /// as on a little-endian Cortex-M. Reading the alias word yields `0` or
+    /// `1`, and writing it sets or clears the bit in a single bus transaction,
+    /// without a read-modify-write of `self` which could race with interrupt
+    /// handlers or the hardware.
+    ///
+    /// # Safety
+    /// The target must implement the bit-banding of the Cortex-M3 and
+    /// Cortex-M4 for the address of `self`. Other cores, such as the
+    /// Cortex-M0, Cortex-M7, and Cortex-M33, do not, and the alias address may
+    /// then be unmapped or used for something else.
+    ///
+    /// # Panics
+    /// Panics if `n` is not less than the number of bits in `T`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use volatile_mem::{Volatile, VolatileWrite};
+    ///
+    /// // The `GPIOC_ODR` register of an STM32F1, which is a Cortex-M3.
+    /// let odr = unsafe { Volatile::<u32>::from_mut_ptr(0x4001_100c as *mut u32) };
+    /// unsafe { odr.bit_mut(13) }.unwrap().write(1);
+    /// ```
+    pub unsafe fn bit(&self, n: usize) -> Option<&Volatile<u32, P>> {
+        let ptr = self.bit_ptr(n)?;
+        // SAFETY: The caller must ensure the target implements bit-banding for
+        // `self`, so the alias word of a bit of `self` is valid and aligned,
+        // and only accesses the bit, which `self` permits.
+        Some(unsafe { Volatile::from_ptr(ptr) })
+    }
+
+    /// Returns a mutable reference to the word in the bit-band alias region
+    /// for bit `n` of `self`, or `None` if `self` is not entirely within a
+    /// bit-band region, like [`bit`](Self::bit).
+    ///
+    /// # Safety
+    /// The target must implement the bit-banding of the Cortex-M3 and
+    /// Cortex-M4 for the address of `self`, as for [`bit`](Self::bit).
+    ///
+    /// # Panics
+    /// Panics if `n` is not less than the number of bits in `T`.
+    pub unsafe fn bit_mut(&mut self, n: usize) -> Option<&mut Volatile<u32, P>> {
+        let ptr = self.bit_ptr(n)?;
+        // SAFETY: The caller must ensure the target implements bit-banding for
+        // `self`, so the alias word of a bit of `self` is valid and aligned,
+        // and only accesses the bit, which `self` permits. `self` is borrowed
+        // mutably, so the bit is not otherwise accessed.
+        Some(unsafe { Volatile::from_mut_ptr(ptr) })
+    }
+
+    fn bit_ptr(&self, n: usize) -> Option<*mut u32> {
+        let size = mem::size_of::<T>();
+        assert!(
+            n < size * 8,
+            "bit index {} is out of range for a {}-bit value",
+            n,
+            size * 8
+        );
+        let addr = self.addr();
+        // Both ends must be in a bit-band region, which is then the same one.
+        alias_addr(addr, 0)?;
+        alias_addr(addr.checked_add(size - 1)?, 0)?;
+        let alias = alias_addr(addr + n / 8, n % 8)?;
+        Some(ptr::with_exposed_provenance_mut(alias))
+    }
+}
@@ -0,0 +1,355 @@
+//! Named bit fields of integer registers.
+//!
+//! Fields are typically declared with [`register_bitfields!`](crate::register_bitfields),
+//! and are accessed through [`Volatile::read_field`], [`Volatile::write_field`],
+//! and [`Volatile::modify_fields`]. Individual bits can also be accessed using
+//! masks, through [`Volatile::set_bits`], [`Volatile::clear_bits`],
+//! [`Volatile::toggle_bits`], and [`Volatile::test_bits`].
+use core::{
+    fmt,
+    marker::PhantomData,
+    ops::{Add, AddAssign, BitAnd, BitOr, BitXor, Not, Shl, Shr},
+};
+
+use crate::{
+    volatile::{Read, Write},
+    Volatile, VolatileRead, VolatileWrite,
+};
+
+/// An unsigned integer type which can hold the value of a register.
+pub trait UInt:
+    Copy
+    + Eq
+    + fmt::Debug
+    + BitAnd<Output = Self>
+    + BitOr<Output = Self>
+    + BitXor<Output = Self>
+    + Not<Output = Self>
+    + Shl<u32, Output = Self>
+    + Shr<u32, Output = Self>
+{
+    /// The value zero.
+    const ZERO: Self;
+    /// The size of the type in bits.
+    const BITS: u32;
+}
+
+macro_rules! impl_uint {
+    ($($ty:ty),*) => {
+        $(
+            impl UInt for $ty {
+                const ZERO: Self = 0;
+                const BITS: u32 = <$ty>::BITS;
+            }
+        )*
+    };
+}
+
+impl_uint!(u8, u16, u32, u64, u128, usize);
+
+/// A type which can hold the value of a field of a register of type `T`.
+///
+/// This is implemented for `T` itself, and, with the `arbitrary-int` feature,
+/// for the types of the `arbitrary-int` crate, such as `u3`, which guarantee
+/// that a value written to a field fits in the field.
+pub trait FieldType<T: UInt>: Copy {
+    /// The size of the type in bits.
+    const BITS: u32;
+
+    /// Converts the value of a field to `Self`.
+    ///
+    /// `value` is unshifted, and fits in the field.
+    fn from_field(value: T) -> Self;
+
+    /// Converts `self` to the unshifted value of a field.
+    fn into_field(self) -> T;
+}
+
+impl<T: UInt> FieldType<T> for T {
+    const BITS: u32 = <T as UInt>::BITS;
+
+    fn from_field(value: T) -> Self {
+        value
+    }
+
+    fn into_field(self) -> T {
+        self
+    }
+}
+
+#[cfg(feature = "arbitrary-int")]
+impl<T, U, const BITS: usize> FieldType<T> for arbitrary_int::UInt<U, BITS>
+where
+    T: UInt + arbitrary_int::Number,
+    Self: arbitrary_int::Number,
+{
+    const BITS: u32 = BITS as u32;
+
+    fn from_field(value: T) -> Self {
+        arbitrary_int::Number::masked_new(value)
+    }
+
+    fn into_field(self) -> T {
+        arbitrary_int::Number::masked_new(self)
+    }
+}
+
+/// A field of a register of type `T`.
+///
+/// `R` identifies the register the field belongs to, and `V` is the type of the
+/// field's value.
+pub struct Field<T: UInt, R = (), V: FieldType<T> = T> {
+    mask: T,
+    shift: u32,
+    _reg: PhantomData<(R, V)>,
+}
+
+impl<T: UInt, R, V: FieldType<T>> Field<T, R, V> {
+    /// Creates a field with the given unshifted `mask`, starting at bit
+    /// `shift`.
+    pub const fn new(mask: T, shift: u32) -> Self {
+        Field {
+            mask,
+            shift,
+            _reg: PhantomData,
+        }
+    }
+
+    /// Returns the unshifted mask of the field.
+    pub fn mask(self) -> T {
+        self.mask
+    }
+
+    /// Returns the bit position at which the field starts.
+    pub fn shift(self) -> u32 {
+        self.shift
+    }
+
+    /// Returns a field value setting the field to `value`.
+    ///
+    /// Bits of `value` which do not fit in the field are ignored.
+    pub fn val(self, value: V) -> FieldValue<T, R> {
+        FieldValue::new(
+            self.mask << self.shift,
+            (value.into_field() & self.mask) << self.shift,
+        )
+    }
+
+    /// Extracts the value of the field from the register value `reg`.
+    pub fn read(self, reg: T) -> V {
+        V::from_field(self.raw(reg))
+    }
+
+    /// Returns `true` if any bit of the field is set in the register value
+    /// `reg`.
+    pub fn is_set(self, reg: T) -> bool {
+        self.raw(reg) != T::ZERO
+    }
+
+    fn raw(self, reg: T) -> T {
+        (reg >> self.shift) & self.mask
+    }
+}
+
+impl<T: UInt, R, V: FieldType<T>> Clone for Field<T, R, V> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: UInt, R, V: FieldType<T>> Copy for Field<T, R, V> {}
+
+impl<T: UInt, R, V: FieldType<T>> fmt::Debug for Field<T, R, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Field")
+            .field("mask", &self.mask)
+            .field("shift", &self.shift)
+            .finish()
+    }
+}
+
+/// Values for one or more fields of a register of type `T`.
+///
+/// Field values for the same register can be combined with `+`.
+pub struct FieldValue<T: UInt, R = ()> {
+    mask: T,
+    value: T,
+    _reg: PhantomData<R>,
+}
+
+impl<T: UInt, R> FieldValue<T, R> {
+    /// Creates a field value which sets the bits in `mask` to the
+    /// corresponding bits of `value`.
+    ///
+    /// Both `mask` and `value` are already shifted into position.
+    pub const fn new(mask: T, value: T) -> Self {
+        FieldValue {
+            mask,
+            value,
+            _reg: PhantomData,
+        }
+    }
+
+    /// Returns the shifted mask of the fields.
+    pub fn mask(self) -> T {
+        self.mask
+    }
+
+    /// Returns the shifted value of the fields.
+    pub fn value(self) -> T {
+        self.value & self.mask
+    }
+
+    /// Returns the register value `reg` with the fields set to this value.
+    pub fn modify(self, reg: T) -> T {
+        (reg & !self.mask) | self.value()
+    }
+
+    /// Returns `true` if the fields have this value in the register value
+    /// `reg`.
+    pub fn matches(self, reg: T) -> bool {
+        reg & self.mask == self.value()
+    }
+}
+
+impl<T: UInt, R> Clone for FieldValue<T, R> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: UInt, R> Copy for FieldValue<T, R> {}
+
+impl<T: UInt, R> Add for FieldValue<T, R> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        FieldValue::new(self.mask | rhs.mask, self.value() | rhs.value())
+    }
+}
+
+impl<T: UInt, R> AddAssign for FieldValue<T, R> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: UInt, R> fmt::Debug for FieldValue<T, R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FieldValue")
+            .field("mask", &self.mask)
+            .field("value", &self.value())
+            .finish()
+    }
+}
+
+impl<T: UInt, P: Read> Volatile<T, P> {
+    /// Performs a volatile read of `self` and extracts the value of `field`.
+    pub fn read_field<R, V: FieldType<T>>(&self, field: Field<T, R, V>) -> V {
+        field.read(self.read())
+    }
+
+    /// Performs a volatile read of `self` and returns `true` if the fields
+    /// have the given value.
+    pub fn matches_fields<R>(&self, value: FieldValue<T, R>) -> bool {
+        value.matches(self.read())
+    }
+
+    /// Performs a volatile read of `self` and returns `true` if all of the
+    /// bits in `mask` are set.
+    pub fn test_bits(&self, mask: T) -> bool {
+        self.read() & mask == mask
+    }
+}
+
+impl<T: UInt, P: Write> Volatile<T, P> {
+    /// Performs a volatile write of `self`, setting the given fields to the
+    /// given value, and all other bits to zero.
+    pub fn write_field<R>(&mut self, value: FieldValue<T, R>) {
+        self.write(value.value());
+    }
+
+    /// Clears the bits in `mask` of a write-1-to-clear register, by performing
+    /// a volatile write of `mask` without reading the old value.
+    ///
+    /// Only the bits in `mask` are cleared, since writing zero to a bit of such
+    /// a register leaves it unchanged. For an ordinary register, this would
+    /// instead set the bits in `mask` and clear all others, so use
+    /// [`clear_bits`](Self::clear_bits) for those.
+    pub fn clear_w1c(&mut self, mask: T) {
+        self.write(mask);
+    }
+
+    /// Sets the bits in `mask` to the corresponding bits of `value`, using
+    /// `self` as a set register and `clear` as the corresponding clear
+    /// register.
+    ///
+    /// The bits of `value & mask` are written to `self`, and the bits of
+    /// `!value & mask` are written to `clear`, each with a single volatile
+    /// write, without reading either register. This is the equivalent of
+    /// [`modify_masked`](Self::modify_masked) for registers, such as GPIO
+    /// outputs, whose bits are set and cleared by writing ones to separate
+    /// write-only registers.
+    pub fn modify_masked_set_clear<Q: Write>(
+        &mut self,
+        clear: &mut Volatile<T, Q>,
+        mask: T,
+        value: T,
+    ) {
+        self.write(value & mask);
+        clear.write(!value & mask);
+    }
+}
+
+impl<T: UInt, P: Read + Write> Volatile<T, P> {
+    /// Performs a volatile read of `self`, sets the given fields to the given
+    /// value, and performs a volatile write of the result, leaving all other
+    /// bits unchanged.
+    ///
+    /// This is not atomic.
+    pub fn modify_fields<R>(&mut self, value: FieldValue<T, R>) {
+        self.modify_masked(value.mask(), value.value());
+    }
+
+    /// Performs a volatile read of `self`, sets the bits in `mask` to the
+    /// corresponding bits of `value`, and performs a volatile write of the
+    /// result, leaving all other bits unchanged.
+    ///
+    /// Bits of `value` outside of `mask` are ignored. This is not atomic.
+    pub fn modify_masked(&mut self, mask: T, value: T) {
+        let reg = self.read();
+        self.write((reg & !mask) | (value & mask));
+    }
+
+    /// Performs a volatile read of `self`, sets the bits in `mask`, and
+    /// performs a volatile write of the result.
+    ///
+    /// This is not atomic.
+    pub fn set_bits(&mut self, mask: T) {
+        let reg = self.read();
+        self.write(reg | mask);
+    }
+
+    /// Performs a volatile read of `self`, clears the bits in `mask`, and
+    /// performs a volatile write of the result.
+    ///
+    /// This is not atomic.
+    ///
+    /// Do not use this for write-1-to-clear registers, such as many interrupt
+    /// status registers. It writes back every bit which is set, except for
+    /// those in `mask`, which clears all of them instead. Use
+    /// [`clear_w1c`](Self::clear_w1c) for such registers.
+    pub fn clear_bits(&mut self, mask: T) {
+        let reg = self.read();
+        self.write(reg & !mask);
+    }
+
+    /// Performs a volatile read of `self`, inverts the bits in `mask`, and
+    /// performs a volatile write of the result.
+    ///
+    /// This is not atomic.
+    pub fn toggle_bits(&mut self, mask: T) {
+        let reg = self.read();
+        self.write(reg ^ mask);
+    }
+}
@@ -0,0 +1,36 @@
+use bitflags::Flags;
+
+use crate::{
+    volatile::{Read, Write},
+    Volatile, VolatileRead, VolatileWrite,
+};
+
+impl<F: Flags + Copy, P: Read> Volatile<F, P> {
+    /// Performs a volatile read of `self` and returns `true` if all of the
+    /// given flags are set.
+    pub fn contains_flags(&self, flags: F) -> bool {
+        self.read().contains(flags)
+    }
+}
+
+impl<F: Flags + Copy, P: Read + Write> Volatile<F, P> {
+    /// Performs a volatile read of `self`, sets the given flags, and performs
+    /// a volatile write of the result.
+    ///
+    /// This is not atomic.
+    pub fn insert_flags(&mut self, flags: F) {
+        let mut value = self.read();
+        value.insert(flags);
+        self.write(value);
+    }
+
+    /// Performs a volatile read of `self`, clears the given flags, and
+    /// performs a volatile write of the result.
+    ///
+    /// This is not atomic.
+    pub fn remove_flags(&mut self, flags: F) {
+        let mut value = self.read();
+        value.remove(flags);
+        self.write(value);
+    }
+}
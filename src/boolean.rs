@@ -0,0 +1,92 @@
+use core::{any::type_name, fmt};
+
+use crate::{
+    volatile::{Read, ReadWrite, Write},
+    Volatile, VolatileData, VolatileRead, VolatileWrite,
+};
+
+/// A boolean flag, stored as a byte.
+///
+/// Reading a [`Volatile<bool>`](Volatile) is undefined behavior if the memory
+/// holds a byte other than `0` or `1`, which hardware or another processor may
+/// well write. Instead, a `VolatileBool` reads the byte, and returns `true` for
+/// any value other than `0`, so it is safe to read regardless of the contents
+/// of the memory. It writes `1` for `true` and `0` for `false`.
+///
+/// # Example
+/// ```
+/// use volatile_mem::{Volatile, VolatileBool, VolatileRead, VolatileWrite};
+///
+/// let mut mem = 2u8;
+/// let flag: &mut VolatileBool = Volatile::from_mut(&mut mem).into();
+/// assert!(flag.read());
+/// flag.write(false);
+/// assert_eq!(flag.bits().read(), 0);
+/// ```
+#[repr(transparent)]
+pub struct VolatileBool<Permission = ReadWrite> {
+    bits: Volatile<u8, Permission>,
+}
+
+impl<P> VolatileBool<P> {
+    /// Converts a reference to a [`Volatile`] byte into a reference to a
+    /// `VolatileBool`.
+    pub const fn from_volatile(vol: &Volatile<u8, P>) -> &Self {
+        // SAFETY: It is safe to cast to `*const Self` because `Self` is
+        // transparent.
+        unsafe { &*(vol as *const Volatile<u8, P> as *const Self) }
+    }
+
+    /// Converts a mutable reference to a [`Volatile`] byte into a mutable
+    /// reference to a `VolatileBool`.
+    pub const fn from_volatile_mut(vol: &mut Volatile<u8, P>) -> &mut Self {
+        // SAFETY: It is safe to cast to `*mut Self` because `Self` is
+        // transparent.
+        unsafe { &mut *(vol as *mut Volatile<u8, P> as *mut Self) }
+    }
+
+    /// Returns a reference to the underlying [`Volatile`] byte.
+    pub const fn bits(&self) -> &Volatile<u8, P> {
+        &self.bits
+    }
+
+    /// Returns a mutable reference to the underlying [`Volatile`] byte.
+    pub const fn bits_mut(&mut self) -> &mut Volatile<u8, P> {
+        &mut self.bits
+    }
+}
+
+impl<P> VolatileData<bool> for VolatileBool<P> {}
+
+impl<P: Read> VolatileRead<bool> for VolatileBool<P> {
+    /// Performs a volatile read of the byte, returning `true` if it is not
+    /// zero.
+    fn read(&self) -> bool {
+        self.bits.read() != 0
+    }
+}
+
+impl<P: Write> VolatileWrite<bool> for VolatileBool<P> {
+    /// Performs a volatile write of `1` if `val` is `true`, or `0` otherwise.
+    fn write(&mut self, val: bool) {
+        self.bits.write(u8::from(val));
+    }
+}
+
+impl<'a, P> From<&'a Volatile<u8, P>> for &'a VolatileBool<P> {
+    fn from(vol: &'a Volatile<u8, P>) -> Self {
+        VolatileBool::from_volatile(vol)
+    }
+}
+
+impl<'a, P> From<&'a mut Volatile<u8, P>> for &'a mut VolatileBool<P> {
+    fn from(vol: &'a mut Volatile<u8, P>) -> Self {
+        VolatileBool::from_volatile_mut(vol)
+    }
+}
+
+impl<P> fmt::Debug for VolatileBool<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(type_name::<Self>())
+    }
+}
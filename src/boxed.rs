@@ -0,0 +1,129 @@
+use alloc::alloc::{alloc, dealloc, handle_alloc_error, Layout};
+use core::{
+    any::type_name,
+    fmt,
+    marker::PhantomData,
+    mem,
+    ops::{Deref, DerefMut},
+    ptr::{self, NonNull},
+};
+
+use crate::{claim::Claim, volatile::ReadWrite, Volatile};
+
+/// Heap-allocated volatile memory, with an alignment which may be greater than
+/// that of `T`.
+///
+/// This is intended for host-side simulations of device memory, and for
+/// buffers shared with a device which must be aligned to a cache line. It
+/// dereferences to [`Volatile<T>`](Volatile).
+///
+/// With the `region-registry` feature, the memory is claimed in the registry
+/// while the box exists, unless the registry is full, in which case it is not
+/// tracked.
+///
+/// # Example
+/// ```
+/// use volatile_mem::{VolatileBox, VolatileRead, VolatileWrite};
+///
+/// let mut buffer: VolatileBox<[u8; 16]> = VolatileBox::new_aligned([0; 16], 64);
+/// assert_eq!(buffer.addr() % 64, 0);
+/// buffer[1].write(2);
+/// assert_eq!(buffer.read()[1], 2);
+/// ```
+pub struct VolatileBox<T: Copy, Permission = ReadWrite> {
+    ptr: NonNull<T>,
+    align: usize,
+    claim: Claim,
+    _perm: PhantomData<Permission>,
+}
+
+impl<T: Copy, P> VolatileBox<T, P> {
+    /// Allocates memory for `T`, aligned for `T`, and initializes it with
+    /// `val`.
+    pub fn new(val: T) -> Self {
+        Self::new_aligned(val, mem::align_of::<T>())
+    }
+
+    /// Allocates memory for `T`, aligned to at least `align` bytes, and
+    /// initializes it with `val`.
+    ///
+    /// The memory is always aligned for `T`, even if `align` is smaller.
+    ///
+    /// # Panics
+    /// Panics if `align` is not a power of two, or if the size of `T` rounded
+    /// up to `align` overflows `isize`.
+    pub fn new_aligned(val: T, align: usize) -> Self {
+        let align = align.max(mem::align_of::<T>());
+        let layout = Layout::from_size_align(mem::size_of::<T>(), align)
+            .expect("invalid alignment for `VolatileBox`");
+        let ptr = if layout.size() == 0 {
+            ptr::without_provenance_mut::<u8>(align)
+        } else {
+            // SAFETY: The layout has a non-zero size.
+            unsafe { alloc(layout) }
+        };
+        let ptr = match NonNull::new(ptr as *mut T) {
+            Some(ptr) => ptr,
+            None => handle_alloc_error(layout),
+        };
+        // SAFETY: The memory was just allocated with the layout of `T`, or `T`
+        // has size zero and the pointer is non-NULL and aligned.
+        unsafe { ptr.as_ptr().write(val) };
+        let mut boxed = VolatileBox {
+            ptr,
+            align,
+            claim: Claim::new(0, 0),
+            _perm: PhantomData,
+        };
+        boxed.claim = Claim::new_allocation(ptr.as_ptr().addr(), layout.size());
+        boxed
+    }
+
+    /// Returns the alignment of the memory, in bytes.
+    pub fn align(&self) -> usize {
+        self.align
+    }
+
+    fn layout(&self) -> Layout {
+        // SAFETY: The layout was valid when the memory was allocated.
+        unsafe { Layout::from_size_align_unchecked(mem::size_of::<T>(), self.align) }
+    }
+}
+
+impl<T: Copy, P> Deref for VolatileBox<T, P> {
+    type Target = Volatile<T, P>;
+
+    fn deref(&self) -> &Volatile<T, P> {
+        // SAFETY: The memory is allocated, initialized, and owned by `self`.
+        unsafe { Volatile::from_non_null(self.ptr) }
+    }
+}
+
+impl<T: Copy, P> DerefMut for VolatileBox<T, P> {
+    fn deref_mut(&mut self) -> &mut Volatile<T, P> {
+        // SAFETY: The memory is allocated, initialized, and owned by `self`.
+        unsafe { Volatile::from_non_null_mut(self.ptr) }
+    }
+}
+
+impl<T: Copy, P> Drop for VolatileBox<T, P> {
+    fn drop(&mut self) {
+        self.claim.release();
+        let layout = self.layout();
+        if layout.size() != 0 {
+            // SAFETY: The memory was allocated with this layout.
+            unsafe { dealloc(self.ptr.as_ptr() as *mut u8, layout) };
+        }
+    }
+}
+
+// SAFETY: `VolatileBox` owns its memory, like `Box`.
+unsafe impl<T: Copy + Send, P> Send for VolatileBox<T, P> {}
+// SAFETY: `VolatileBox` owns its memory, like `Box`.
+unsafe impl<T: Copy + Sync, P> Sync for VolatileBox<T, P> {}
+
+impl<T: Copy, P> fmt::Debug for VolatileBox<T, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(type_name::<Self>())
+    }
+}
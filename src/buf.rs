@@ -0,0 +1,216 @@
+use alloc::{
+    alloc::{alloc, dealloc, handle_alloc_error, Layout},
+    vec::Vec,
+};
+use core::{
+    any::type_name,
+    fmt,
+    marker::PhantomData,
+    mem,
+    ops::{Deref, DerefMut},
+    ptr::{self, NonNull},
+    slice,
+};
+
+use crate::{
+    claim::Claim,
+    volatile::{Read, ReadWrite},
+    Volatile, VolatileRead,
+};
+
+/// A heap-allocated, initialized buffer of `T`, with an alignment which may be
+/// greater than that of `T`, which is accessed as a slice of volatile data.
+///
+/// This is intended as a stand-in for device memory in unit tests, so that
+/// code taking a [`Volatile`] reference or slice can be tested without unsafe
+/// code. It dereferences to a slice of [`Volatile<T>`](Volatile), so a single
+/// element is accessed by indexing, and the whole buffer can be passed to
+/// code taking a volatile slice. Combined with the `host-test` feature, such
+/// tests can also run under Miri.
+///
+/// Unlike [`VolatileBox`](crate::VolatileBox), the length of the buffer is
+/// chosen at runtime.
+///
+/// With the `region-registry` feature, the memory is claimed in the registry
+/// while the buffer exists, unless the registry is full, in which case it is
+/// not tracked.
+///
+/// # Example
+/// ```
+/// use volatile_mem::{Volatile, VolatileBuf, VolatileWrite};
+///
+/// fn fill(regs: &mut [Volatile<u32>]) {
+///     for (i, reg) in regs.iter_mut().enumerate() {
+///         reg.write(i as u32);
+///     }
+/// }
+///
+/// let mut buf: VolatileBuf<u32> = VolatileBuf::new_aligned(0, 4, 64);
+/// assert_eq!(buf.as_ptr() as usize % 64, 0);
+/// fill(&mut buf);
+/// assert_eq!(buf.to_vec(), [0, 1, 2, 3]);
+/// ```
+pub struct VolatileBuf<T: Copy, Permission = ReadWrite> {
+    ptr: NonNull<T>,
+    len: usize,
+    align: usize,
+    claim: Claim,
+    _perm: PhantomData<Permission>,
+}
+
+impl<T: Copy, P> VolatileBuf<T, P> {
+    /// Allocates a buffer of `len` elements, aligned for `T`, with each element
+    /// initialized to `val`.
+    ///
+    /// # Panics
+    /// Panics if the size of the buffer overflows `isize`.
+    pub fn new(val: T, len: usize) -> Self {
+        Self::new_aligned(val, len, mem::align_of::<T>())
+    }
+
+    /// Allocates a buffer of `len` elements, aligned to at least `align` bytes,
+    /// with each element initialized to `val`.
+    ///
+    /// The memory is always aligned for `T`, even if `align` is smaller.
+    ///
+    /// # Panics
+    /// Panics if `align` is not a power of two, or if the size of the buffer
+    /// rounded up to `align` overflows `isize`.
+    pub fn new_aligned(val: T, len: usize, align: usize) -> Self {
+        let buf = Self::alloc(len, align);
+        for i in 0..len {
+            // SAFETY: The memory was just allocated for `len` elements.
+            unsafe { buf.ptr.as_ptr().add(i).write(val) };
+        }
+        buf
+    }
+
+    /// Allocates a buffer, aligned for `T`, initialized with a copy of
+    /// `vals`.
+    ///
+    /// # Panics
+    /// Panics if the size of the buffer overflows `isize`.
+    pub fn from_slice(vals: &[T]) -> Self {
+        Self::from_slice_aligned(vals, mem::align_of::<T>())
+    }
+
+    /// Allocates a buffer, aligned to at least `align` bytes, initialized with
+    /// a copy of `vals`.
+    ///
+    /// The memory is always aligned for `T`, even if `align` is smaller.
+    ///
+    /// # Panics
+    /// Panics if `align` is not a power of two, or if the size of the buffer
+    /// rounded up to `align` overflows `isize`.
+    pub fn from_slice_aligned(vals: &[T], align: usize) -> Self {
+        let buf = Self::alloc(vals.len(), align);
+        // SAFETY: The memory was just allocated for `vals.len()` elements, so
+        // it cannot overlap `vals`.
+        unsafe { ptr::copy_nonoverlapping(vals.as_ptr(), buf.ptr.as_ptr(), vals.len()) };
+        buf
+    }
+
+    /// Returns the number of elements in the buffer.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the buffer has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the alignment of the memory, in bytes.
+    pub fn align(&self) -> usize {
+        self.align
+    }
+
+    /// Returns a pointer to the first element of the buffer.
+    pub fn as_ptr(&self) -> *mut T {
+        self.ptr.as_ptr()
+    }
+
+    /// Allocates uninitialized memory for `len` elements.
+    fn alloc(len: usize, align: usize) -> Self {
+        let align = align.max(mem::align_of::<T>());
+        let layout = Layout::array::<T>(len)
+            .and_then(|layout| layout.align_to(align))
+            .expect("invalid size or alignment for `VolatileBuf`");
+        let ptr = if layout.size() == 0 {
+            ptr::without_provenance_mut::<u8>(align)
+        } else {
+            // SAFETY: The layout has a non-zero size.
+            unsafe { alloc(layout) }
+        };
+        let ptr = match NonNull::new(ptr as *mut T) {
+            Some(ptr) => ptr,
+            None => handle_alloc_error(layout),
+        };
+        let mut buf = VolatileBuf {
+            ptr,
+            len,
+            align,
+            claim: Claim::new(0, 0),
+            _perm: PhantomData,
+        };
+        buf.claim = Claim::new_allocation(ptr.as_ptr().addr(), layout.size());
+        buf
+    }
+
+    fn layout(&self) -> Layout {
+        // SAFETY: The layout was valid when the memory was allocated.
+        unsafe { Layout::from_size_align_unchecked(mem::size_of::<T>() * self.len, self.align) }
+    }
+}
+
+impl<T: Copy, P: Read> VolatileBuf<T, P> {
+    /// Reads every element of the buffer into a `Vec`, such as to check the
+    /// result of a test.
+    pub fn to_vec(&self) -> Vec<T> {
+        self.iter().map(VolatileRead::read).collect()
+    }
+}
+
+impl<T: Copy, P> Deref for VolatileBuf<T, P> {
+    type Target = [Volatile<T, P>];
+
+    fn deref(&self) -> &[Volatile<T, P>] {
+        // SAFETY: The memory is allocated, initialized, and owned by `self`.
+        Volatile::from_slice(unsafe { slice::from_raw_parts(self.ptr.as_ptr(), self.len) })
+    }
+}
+
+impl<T: Copy, P> DerefMut for VolatileBuf<T, P> {
+    fn deref_mut(&mut self) -> &mut [Volatile<T, P>] {
+        // SAFETY: The memory is allocated, initialized, and owned by `self`.
+        Volatile::from_slice_mut(unsafe { slice::from_raw_parts_mut(self.ptr.as_ptr(), self.len) })
+    }
+}
+
+impl<T: Copy, P> Drop for VolatileBuf<T, P> {
+    fn drop(&mut self) {
+        self.claim.release();
+        let layout = self.layout();
+        if layout.size() != 0 {
+            // SAFETY: The memory was allocated with this layout.
+            unsafe { dealloc(self.ptr.as_ptr() as *mut u8, layout) };
+        }
+    }
+}
+
+// SAFETY: `VolatileBuf` owns its memory, like `Vec`.
+unsafe impl<T: Copy + Send, P> Send for VolatileBuf<T, P> {}
+// SAFETY: `VolatileBuf` owns its memory, like `Vec`.
+unsafe impl<T: Copy + Sync, P> Sync for VolatileBuf<T, P> {}
+
+impl<T: Copy, P> fmt::Debug for VolatileBuf<T, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {{ {:p}, {:#x} }}",
+            type_name::<Self>(),
+            self.ptr,
+            self.len
+        )
+    }
+}
@@ -0,0 +1,173 @@
+//! Data cache maintenance for memory shared with DMA engines.
+//!
+//! When a DMA engine does not participate in cache coherency, memory which it
+//! reads must be cleaned from the data cache after it is written by the CPU,
+//! and memory which it writes must be invalidated in the data cache before it
+//! is read by the CPU. [`CacheOps`] abstracts over how this is done, so the
+//! same code can be used with or without a data cache. [`Volatile`] provides
+//! [`clean_cache`](Volatile::clean_cache),
+//! [`invalidate_cache`](Volatile::invalidate_cache), and
+//! [`clean_invalidate_cache`](Volatile::clean_invalidate_cache), which perform
+//! the operations on the memory of the `Volatile`.
+//!
+//! [`NoCache`] performs no operations, for systems without a data cache or
+//! with coherent DMA. With the `cortex-m` feature, [`CacheOps`] is implemented
+//! for `cortex_m::peripheral::SCB`, except on ARMv6-M, and with the
+//! `armv8-cache` feature on AArch64, `Armv8Cache` performs the operations using
+//! `dc` instructions.
+use core::mem;
+
+use crate::Volatile;
+
+/// Data cache maintenance operations by address range.
+///
+/// Each operation applies to every cache line containing any part of the
+/// range. There are no default implementations, since an operation which
+/// silently does nothing would leave stale data in the cache. Use [`NoCache`]
+/// where no maintenance is needed.
+pub trait CacheOps {
+    /// Cleans the range, writing any modified data in the cache back to
+    /// memory.
+    fn clean(&mut self, addr: usize, len: usize);
+
+    /// Invalidates the range, discarding any data in the cache, so that the
+    /// next read is from memory.
+    ///
+    /// # Safety
+    /// Any modified data in the cache which has not been written back to
+    /// memory is lost, including data outside of the range which shares a
+    /// cache line with it. The caller must ensure that this cannot cause
+    /// invalid or uninitialized values to be read.
+    unsafe fn invalidate(&mut self, addr: usize, len: usize);
+
+    /// Cleans and then invalidates the range.
+    fn clean_invalidate(&mut self, addr: usize, len: usize);
+}
+
+/// Cache operations for systems without a data cache, or whose DMA is coherent.
+///
+/// Every operation does nothing.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NoCache;
+
+impl CacheOps for NoCache {
+    fn clean(&mut self, _addr: usize, _len: usize) {}
+
+    unsafe fn invalidate(&mut self, _addr: usize, _len: usize) {}
+
+    fn clean_invalidate(&mut self, _addr: usize, _len: usize) {}
+}
+
+impl<T: Copy, P> Volatile<T, P> {
+    /// Cleans the memory of `self` from the data cache, such as before it is
+    /// read by a DMA engine.
+    pub fn clean_cache(&self, cache: &mut impl CacheOps) {
+        cache.clean(self as *const Self as usize, mem::size_of::<T>());
+    }
+
+    /// Invalidates the memory of `self` in the data cache, such as after it is
+    /// written by a DMA engine.
+    ///
+    /// # Safety
+    /// See [`CacheOps::invalidate`]. In particular, unless `self` is
+    /// write-only, the memory must contain a properly initialized value of
+    /// type `T`.
+    pub unsafe fn invalidate_cache(&mut self, cache: &mut impl CacheOps) {
+        // SAFETY: The caller must ensure it is safe to invalidate the memory.
+        unsafe { cache.invalidate(self as *mut Self as usize, mem::size_of::<T>()) }
+    }
+
+    /// Cleans and then invalidates the memory of `self` in the data cache.
+    pub fn clean_invalidate_cache(&mut self, cache: &mut impl CacheOps) {
+        cache.clean_invalidate(self as *mut Self as usize, mem::size_of::<T>());
+    }
+}
+
+/// The Cortex-M7 data cache.
+///
+/// For [`invalidate`](CacheOps::invalidate), the range must be aligned to the
+/// cache line size of 32 bytes, as must its length.
+#[cfg(all(feature = "cortex-m", not(armv6m)))]
+impl CacheOps for cortex_m::peripheral::SCB {
+    fn clean(&mut self, addr: usize, len: usize) {
+        self.clean_dcache_by_address(addr, len);
+    }
+
+    unsafe fn invalidate(&mut self, addr: usize, len: usize) {
+        // SAFETY: The caller must ensure it is safe to invalidate the range,
+        // and that it is aligned to the cache line size.
+        unsafe { self.invalidate_dcache_by_address(addr, len) }
+    }
+
+    fn clean_invalidate(&mut self, addr: usize, len: usize) {
+        self.clean_invalidate_dcache_by_address(addr, len);
+    }
+}
+
+/// Cache operations for AArch64, using the `dc cvac`, `dc ivac`, and
+/// `dc civac` instructions, each followed by a `dsb sy`.
+///
+/// These must be used at EL1 or higher. `dc ivac` is never permitted at EL0,
+/// so [`invalidate`](CacheOps::invalidate) traps there, and the other
+/// operations trap at EL0 unless `SCTLR_EL1.UCI` is set.
+#[cfg(all(feature = "armv8-cache", target_arch = "aarch64"))]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Armv8Cache;
+
+#[cfg(all(feature = "armv8-cache", target_arch = "aarch64"))]
+impl Armv8Cache {
+    /// Returns the smallest data cache line size, in bytes.
+    fn line_size() -> usize {
+        let ctr: u64;
+        // SAFETY: Reading `CTR_EL0` has no side effects.
+        unsafe {
+            core::arch::asm!("mrs {}, ctr_el0", out(reg) ctr, options(nomem, nostack, preserves_flags))
+        };
+        4 << ((ctr >> 16) & 0xf)
+    }
+
+    fn for_each_line(addr: usize, len: usize, mut f: impl FnMut(usize)) {
+        let line = Self::line_size();
+        let end = addr.saturating_add(len);
+        let mut line_addr = addr & !(line - 1);
+        while line_addr < end {
+            f(line_addr);
+            line_addr += line;
+        }
+        // SAFETY: The instruction is a barrier, which has no other effect.
+        unsafe { core::arch::asm!("dsb sy", options(nostack, preserves_flags)) };
+    }
+}
+
+#[cfg(all(feature = "armv8-cache", target_arch = "aarch64"))]
+impl CacheOps for Armv8Cache {
+    fn clean(&mut self, addr: usize, len: usize) {
+        Self::for_each_line(addr, len, |line| {
+            // SAFETY: Cleaning a cache line does not change the contents of
+            // memory as observed by the CPU.
+            unsafe {
+                core::arch::asm!("dc cvac, {}", in(reg) line, options(nostack, preserves_flags))
+            }
+        });
+    }
+
+    unsafe fn invalidate(&mut self, addr: usize, len: usize) {
+        Self::for_each_line(addr, len, |line| {
+            // SAFETY: The caller must ensure it is safe to invalidate the
+            // range.
+            unsafe {
+                core::arch::asm!("dc ivac, {}", in(reg) line, options(nostack, preserves_flags))
+            }
+        });
+    }
+
+    fn clean_invalidate(&mut self, addr: usize, len: usize) {
+        Self::for_each_line(addr, len, |line| {
+            // SAFETY: Cleaning before invalidating does not change the
+            // contents of memory as observed by the CPU.
+            unsafe {
+                core::arch::asm!("dc civac, {}", in(reg) line, options(nostack, preserves_flags))
+            }
+        });
+    }
+}
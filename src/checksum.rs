@@ -0,0 +1,83 @@
+use crate::VolatileRead;
+
+/// The lookup table for the reflected CRC-32 polynomial `0xedb88320`.
+const CRC32_TABLE: [u32; 256] = {
+    let mut table = [0; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 != 0 {
+                (crc >> 1) ^ 0xedb8_8320
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+};
+
+/// Checksums of data which is, or can be treated as, a readable slice of
+/// volatile bytes.
+///
+/// Each byte is read exactly once, with a volatile read. This is useful for
+/// validating firmware tables and messages in shared memory.
+///
+/// This trait has a blanket implementation for all types which meet the
+/// criteria.
+///
+/// # Example
+/// ```
+/// use volatile_mem::{Volatile, VolatileChecksum};
+///
+/// let mut mem = *b"123456789";
+/// let vol = Volatile::<[u8; 9]>::from_mut(&mut mem);
+/// assert_eq!(vol.crc32(), 0xcbf4_3926);
+/// assert_eq!(vol.checksum8(), 0xdd);
+/// ```
+pub trait VolatileChecksum<T>
+where
+    Self: AsRef<[T]>,
+    T: VolatileRead<u8>,
+{
+    /// Returns the sum of the bytes of `self`, wrapping around on overflow.
+    ///
+    /// Tables such as those of ACPI and SMBIOS are valid if this is zero.
+    fn checksum8(&self) -> u8 {
+        self.as_ref()
+            .iter()
+            .fold(0, |sum, byte| sum.wrapping_add(byte.read()))
+    }
+
+    /// Returns the sum of the little-endian 16-bit words of `self`, wrapping
+    /// around on overflow.
+    ///
+    /// If `self` has an odd length, the last byte is the low byte of a final
+    /// word whose high byte is zero.
+    fn checksum16(&self) -> u16 {
+        self.as_ref().chunks(2).fold(0, |sum, word| {
+            let low = word[0].read();
+            let high = word.get(1).map_or(0, VolatileRead::read);
+            sum.wrapping_add(u16::from_le_bytes([low, high]))
+        })
+    }
+
+    /// Returns the CRC-32 of the bytes of `self`, as used by Ethernet, zlib,
+    /// and UEFI, among others.
+    fn crc32(&self) -> u32 {
+        !self.as_ref().iter().fold(!0, |crc, byte| {
+            CRC32_TABLE[usize::from(crc as u8 ^ byte.read())] ^ (crc >> 8)
+        })
+    }
+}
+
+impl<S, T> VolatileChecksum<T> for S
+where
+    S: AsRef<[T]>,
+    T: VolatileRead<u8>,
+{
+}
@@ -0,0 +1,105 @@
+#[cfg(all(feature = "std", any(unix, windows)))]
+use std::io;
+
+#[cfg(all(feature = "region-registry", target_has_atomic = "8"))]
+use crate::registry::{self, RegionGuard};
+
+/// The claim of a region owned by a type of this crate, such as a
+/// `VolatileBox`, which is recorded in the `registry` if the
+/// `region-registry` feature is enabled, and does nothing otherwise.
+///
+/// Owners release the claim at the start of their `drop`, before the memory
+/// can be reused.
+pub(crate) struct Claim {
+    #[cfg(all(feature = "region-registry", target_has_atomic = "8"))]
+    guard: Option<RegionGuard>,
+}
+
+impl Claim {
+    /// Claims the `size` bytes at `addr` as writable, unless `size` is zero.
+    ///
+    /// # Panics
+    /// Panics if the region cannot be claimed.
+    #[track_caller]
+    pub(crate) fn new(addr: usize, size: usize) -> Self {
+        #[cfg(all(feature = "region-registry", target_has_atomic = "8"))]
+        {
+            Claim {
+                guard: (size != 0).then(|| registry::claim_raw(addr, size, true)),
+            }
+        }
+        #[cfg(not(all(feature = "region-registry", target_has_atomic = "8")))]
+        {
+            let _ = (addr, size);
+            Claim {}
+        }
+    }
+
+    /// Claims the `size` bytes of a heap allocation at `addr` as writable,
+    /// unless `size` is zero, like [`new`](Self::new), but leaves the region
+    /// untracked if the registry is full.
+    ///
+    /// Live heap allocations never overlap each other, and test fixtures may
+    /// allocate many of them at once, so running out of entries for them is
+    /// not an error.
+    ///
+    /// # Panics
+    /// Panics if the region overlaps a live region.
+    #[cfg(feature = "alloc")]
+    #[track_caller]
+    pub(crate) fn new_allocation(addr: usize, size: usize) -> Self {
+        #[cfg(all(feature = "region-registry", target_has_atomic = "8"))]
+        {
+            let guard = match size {
+                0 => None,
+                _ => match registry::try_claim_raw(addr, size, true) {
+                    Ok(guard) => Some(guard),
+                    Err(registry::ClaimError::Full) => None,
+                    Err(err) => panic!(
+                        "cannot claim {:#x}..{:#x}: {}",
+                        addr,
+                        addr.wrapping_add(size),
+                        err
+                    ),
+                },
+            };
+            Claim { guard }
+        }
+        #[cfg(not(all(feature = "region-registry", target_has_atomic = "8")))]
+        {
+            let _ = (addr, size);
+            Claim {}
+        }
+    }
+
+    /// Claims the `size` bytes at `addr` as writable, unless `size` is zero,
+    /// like [`new`](Self::new).
+    ///
+    /// # Errors
+    /// Returns an error if the region cannot be claimed.
+    #[cfg(all(feature = "std", any(unix, windows)))]
+    pub(crate) fn try_new(addr: usize, size: usize) -> io::Result<Self> {
+        #[cfg(all(feature = "region-registry", target_has_atomic = "8"))]
+        {
+            let guard = match size {
+                0 => None,
+                _ => Some(registry::try_claim_raw(addr, size, true).map_err(io::Error::other)?),
+            };
+            Ok(Claim { guard })
+        }
+        #[cfg(not(all(feature = "region-registry", target_has_atomic = "8")))]
+        {
+            let _ = (addr, size);
+            Ok(Claim {})
+        }
+    }
+
+    /// Releases the claim.
+    #[cfg_attr(not(feature = "alloc"), allow(dead_code))]
+    pub(crate) fn release(&mut self) {
+        #[cfg(all(feature = "region-registry", target_has_atomic = "8"))]
+        {
+            self.guard = None;
+        }
+    }
+}
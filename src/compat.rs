@@ -0,0 +1,133 @@
+//! A migration shim for the [`volatile`](https://docs.rs/volatile) crate.
+//!
+//! [`VolatilePtr`] mirrors the method names of `volatile::VolatilePtr`, but is
+//! implemented on top of [`Volatile`], so code can be migrated incrementally.
+//! Unlike `volatile::VolatilePtr`, it is not `Copy`, and writes require a
+//! mutable reference, since it wraps a mutable reference to a [`Volatile`].
+use core::{any::type_name, fmt, ptr::NonNull};
+
+use crate::{
+    volatile::{Read, Write},
+    Volatile, VolatileRead, VolatileWrite,
+};
+
+/// The access permissions of a [`VolatilePtr`].
+pub mod access {
+    pub use crate::volatile::{NoAccess, ReadOnly, ReadWrite, WriteOnly};
+}
+
+use access::{ReadOnly, ReadWrite, WriteOnly};
+
+/// A volatile pointer to a value of type `T`, with the method names of
+/// `volatile::VolatilePtr`.
+pub struct VolatilePtr<'a, T: Copy, A = ReadWrite> {
+    vol: &'a mut Volatile<T, A>,
+}
+
+impl<'a, T: Copy, A> VolatilePtr<'a, T, A> {
+    /// Creates a new volatile pointer from a [`NonNull`] pointer.
+    ///
+    /// # Safety
+    /// `pointer` must meet the requirements of
+    /// [`from_mut_ptr`](Volatile::from_mut_ptr) for the lifetime `'a`.
+    pub unsafe fn new(pointer: NonNull<T>) -> Self {
+        VolatilePtr {
+            // SAFETY: The caller must ensure the pointer is safe to use.
+            vol: unsafe { Volatile::from_mut_ptr(pointer.as_ptr()) },
+        }
+    }
+
+    /// Creates a new volatile pointer from a mutable reference to a
+    /// [`Volatile`].
+    pub fn from_volatile(vol: &'a mut Volatile<T, A>) -> Self {
+        VolatilePtr { vol }
+    }
+
+    /// Returns the underlying [`Volatile`].
+    pub fn into_volatile(self) -> &'a mut Volatile<T, A> {
+        self.vol
+    }
+
+    /// Returns the underlying raw pointer.
+    pub fn as_raw_ptr(&self) -> NonNull<T> {
+        NonNull::from(&*self.vol).cast()
+    }
+
+    /// Converts the pointer to a pointer to part of the value, such as a
+    /// struct field.
+    ///
+    /// # Safety
+    /// `f` must return a pointer to a value of type `U` which is entirely
+    /// contained in the value pointed to by its argument, and which is
+    /// properly aligned and initialized.
+    pub unsafe fn map<F, U>(self, f: F) -> VolatilePtr<'a, U, A>
+    where
+        F: FnOnce(NonNull<T>) -> NonNull<U>,
+        U: Copy,
+    {
+        let ptr = f(NonNull::from(self.vol).cast());
+        // SAFETY: The caller must ensure the new pointer is safe to use for
+        // the same lifetime as `self`.
+        unsafe { VolatilePtr::new(ptr) }
+    }
+
+    /// Restricts the pointer to read-only access.
+    pub fn read_only(self) -> VolatilePtr<'a, T, ReadOnly>
+    where
+        A: Read,
+    {
+        // SAFETY: `self` is readable, and the memory is only read through the
+        // new pointer.
+        VolatilePtr::from_volatile(unsafe { self.vol.assume_permission_mut() })
+    }
+
+    /// Restricts the pointer to write-only access.
+    pub fn write_only(self) -> VolatilePtr<'a, T, WriteOnly>
+    where
+        A: Write,
+    {
+        VolatilePtr::from_volatile(self.vol.as_write_only())
+    }
+}
+
+impl<T: Copy, A: Read> VolatilePtr<'_, T, A> {
+    /// Performs a volatile read of the value.
+    pub fn read(&self) -> T {
+        self.vol.read()
+    }
+}
+
+impl<T: Copy, A: Write> VolatilePtr<'_, T, A> {
+    /// Performs a volatile write of the value.
+    pub fn write(&mut self, value: T) {
+        self.vol.write(value)
+    }
+}
+
+impl<T: Copy, A: Read + Write> VolatilePtr<'_, T, A> {
+    /// Updates the value by reading it, passing it to `f`, and writing the
+    /// result.
+    ///
+    /// This is not atomic.
+    pub fn update<F>(&mut self, f: F)
+    where
+        F: FnOnce(T) -> T,
+    {
+        let value = self.vol.read();
+        self.vol.write(f(value));
+    }
+}
+
+impl<'a, T: Copy, A> From<&'a mut Volatile<T, A>> for VolatilePtr<'a, T, A> {
+    fn from(vol: &'a mut Volatile<T, A>) -> Self {
+        VolatilePtr::from_volatile(vol)
+    }
+}
+
+impl<T: Copy, A> fmt::Debug for VolatilePtr<'_, T, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple(type_name::<Self>())
+            .field(&self.as_raw_ptr())
+            .finish()
+    }
+}
@@ -0,0 +1,35 @@
+use cortex_m::{asm, interrupt};
+
+use crate::{
+    volatile::{Read, Write},
+    VolatileRead, VolatileShared,
+};
+
+impl<T: Copy, P: Read + Write> VolatileShared<T, P> {
+    /// Performs a volatile read of `self`, passes the value to `f`, and
+    /// performs a volatile write of the result, all with interrupts disabled.
+    ///
+    /// On single-core Cortex-M systems, this prevents the update from racing
+    /// with interrupt handlers which access the same data.
+    pub fn modify_irq_free(&self, f: impl FnOnce(T) -> T) {
+        interrupt::free(|_| {
+            let value = f(self.read());
+            self.write_shared(value);
+        });
+    }
+
+    /// The same as [`modify_irq_free`](Self::modify_irq_free), but with a data
+    /// memory barrier (`dmb`) before the read and after the write.
+    ///
+    /// The barriers ensure that memory accesses before the update complete
+    /// before it begins, and that the update completes before any memory
+    /// accesses after it, as is required by some peripherals and DMA engines.
+    pub fn modify_irq_free_dmb(&self, f: impl FnOnce(T) -> T) {
+        interrupt::free(|_| {
+            asm::dmb();
+            let value = f(self.read());
+            self.write_shared(value);
+            asm::dmb();
+        });
+    }
+}
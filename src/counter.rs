@@ -0,0 +1,65 @@
+use core::{any::type_name, fmt};
+
+use crate::{
+    volatile::{Read, ReadOnly},
+    Volatile, VolatileRead, VolatileReadOnly,
+};
+
+/// A 64-bit counter exposed as a pair of 32-bit registers, such as the counter
+/// of a timer.
+///
+/// The two halves cannot be read at the same time, so the low half may wrap
+/// around between reading it and reading the high half, resulting in a torn
+/// value. [`read`](Self::read) avoids this by reading the high half both before
+/// and after the low half, and retrying if it changed.
+///
+/// # Example
+/// ```
+/// use volatile_mem::{SplitCounter, Volatile};
+///
+/// let hi: &Volatile<u32> = Volatile::from_ref(&0x1);
+/// let lo: &Volatile<u32> = Volatile::from_ref(&0x2345_6789);
+/// assert_eq!(SplitCounter::new(hi, lo).read(), 0x1_2345_6789);
+/// ```
+pub struct SplitCounter<'a, P = ReadOnly, Q = ReadOnly> {
+    hi: &'a Volatile<u32, P>,
+    lo: &'a Volatile<u32, Q>,
+}
+
+impl<'a, P: Read, Q: Read> SplitCounter<'a, P, Q> {
+    /// Pairs the high half `hi` of a counter with its low half `lo`.
+    pub fn new(hi: &'a Volatile<u32, P>, lo: &'a Volatile<u32, Q>) -> Self {
+        SplitCounter { hi, lo }
+    }
+
+    /// Reads the value of the counter, without tearing.
+    ///
+    /// The high half is read, then the low half, then the high half again. If
+    /// the high half changed, the low half wrapped around between the reads,
+    /// so they are repeated.
+    pub fn read(&self) -> u64 {
+        loop {
+            let hi = self.hi.read();
+            let lo = self.lo.read();
+            if self.hi.read() == hi {
+                return (u64::from(hi) << 32) | u64::from(lo);
+            }
+        }
+    }
+
+    /// Returns the high half of the counter.
+    pub fn hi(&self) -> &'a VolatileReadOnly<u32> {
+        self.hi.as_read_only()
+    }
+
+    /// Returns the low half of the counter.
+    pub fn lo(&self) -> &'a VolatileReadOnly<u32> {
+        self.lo.as_read_only()
+    }
+}
+
+impl<P, Q> fmt::Debug for SplitCounter<'_, P, Q> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(type_name::<Self>())
+    }
+}
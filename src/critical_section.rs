@@ -0,0 +1,20 @@
+use crate::{
+    volatile::{Read, Write},
+    VolatileRead, VolatileShared,
+};
+
+impl<T: Copy, P: Read + Write> VolatileShared<T, P> {
+    /// Performs a volatile read of `self`, passes the value to `f`, and
+    /// performs a volatile write of the result, all within a critical section.
+    ///
+    /// On single-core systems, where the critical section disables interrupts,
+    /// this prevents the update from racing with interrupt handlers which
+    /// access the same data. Other accesses must also be made within critical
+    /// sections for them to be ordered with respect to this one.
+    pub fn modify_cs(&self, f: impl FnOnce(T) -> T) {
+        critical_section::with(|_| {
+            let value = f(self.read());
+            self.write_shared(value);
+        });
+    }
+}
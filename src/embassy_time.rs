@@ -0,0 +1,50 @@
+use embassy_time::{Duration, TimeoutError, Timer};
+
+use crate::{bitfield::UInt, volatile::Read, Timeout, Volatile, VolatileRead};
+
+impl<T: Copy, P: Read> Volatile<T, P> {
+    /// Performs volatile reads of `self` until `pred` returns `true` for the
+    /// value read, and returns that value, waiting on a [`Timer`] for
+    /// `interval` between reads.
+    ///
+    /// The returned future can be given a timeout or deadline with
+    /// [`WithTimeout`](embassy_time::WithTimeout), such as
+    /// `vol.poll_until(pred, interval).with_timeout(timeout)`.
+    pub async fn poll_until(&self, mut pred: impl FnMut(T) -> bool, interval: Duration) -> T {
+        loop {
+            let value = self.read();
+            if pred(value) {
+                return value;
+            }
+            Timer::after(interval).await;
+        }
+    }
+}
+
+impl<T: UInt, P: Read> Volatile<T, P> {
+    /// Performs volatile reads of `self` until all of the bits in `mask` are
+    /// set, and returns the value read, waiting on a [`Timer`] for `interval`
+    /// between reads.
+    ///
+    /// See [`poll_until`](Self::poll_until).
+    pub async fn poll_for_set(&self, mask: T, interval: Duration) -> T {
+        self.poll_until(|value| value & mask == mask, interval)
+            .await
+    }
+
+    /// Performs volatile reads of `self` until all of the bits in `mask` are
+    /// clear, and returns the value read, waiting on a [`Timer`] for `interval`
+    /// between reads.
+    ///
+    /// See [`poll_until`](Self::poll_until).
+    pub async fn poll_for_clear(&self, mask: T, interval: Duration) -> T {
+        self.poll_until(|value| value & mask == T::ZERO, interval)
+            .await
+    }
+}
+
+impl From<TimeoutError> for Timeout {
+    fn from(_: TimeoutError) -> Self {
+        Timeout
+    }
+}
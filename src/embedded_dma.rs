@@ -0,0 +1,20 @@
+use embedded_dma::{ReadTarget, WriteTarget};
+
+use crate::{
+    volatile::{Read, Write},
+    Volatile,
+};
+
+// SAFETY: `Volatile<T, P>` has the same layout as `T`, so the default
+// implementation returns the same buffer as `T` would. The memory is readable
+// because `P: Read`.
+unsafe impl<T: Copy + ReadTarget, P: Read> ReadTarget for Volatile<T, P> {
+    type Word = T::Word;
+}
+
+// SAFETY: `Volatile<T, P>` has the same layout as `T`, so the default
+// implementation returns the same buffer as `T` would. The memory is writable
+// because `P: Write`.
+unsafe impl<T: Copy + WriteTarget, P: Write> WriteTarget for Volatile<T, P> {
+    type Word = T::Word;
+}
@@ -0,0 +1,68 @@
+use core::convert::Infallible;
+
+use embedded_io::{ErrorType, Read, ReadReady, Write, WriteReady};
+
+use crate::{volatile, StatusGated};
+
+impl<S: Copy, PD, PS: volatile::Read> ErrorType for StatusGated<'_, u8, S, PD, PS> {
+    type Error = Infallible;
+}
+
+impl<S: Copy, PD: volatile::Read, PS: volatile::Read> Read for StatusGated<'_, u8, S, PD, PS> {
+    /// Waits until the data register is ready to be read, then reads bytes
+    /// from it until `buf` is full or it is no longer ready.
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+        buf[0] = self.read_blocking();
+        let mut count = 1;
+        for byte in &mut buf[1..] {
+            match self.read_if_ready() {
+                Some(val) => *byte = val,
+                None => break,
+            }
+            count += 1;
+        }
+        Ok(count)
+    }
+}
+
+impl<S: Copy, PD: volatile::Read, PS: volatile::Read> ReadReady for StatusGated<'_, u8, S, PD, PS> {
+    fn read_ready(&mut self) -> Result<bool, Self::Error> {
+        Ok(self.is_read_ready())
+    }
+}
+
+impl<S: Copy, PD: volatile::Write, PS: volatile::Read> Write for StatusGated<'_, u8, S, PD, PS> {
+    /// Waits until the data register is ready to be written, then writes bytes
+    /// from `buf` to it until they are all written or it is no longer ready.
+    fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+        self.write_blocking(buf[0]);
+        let mut count = 1;
+        for &byte in &buf[1..] {
+            if self.write_if_ready(byte).is_err() {
+                break;
+            }
+            count += 1;
+        }
+        Ok(count)
+    }
+
+    /// Does nothing, since each byte is written to the data register
+    /// immediately.
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+impl<S: Copy, PD: volatile::Write, PS: volatile::Read> WriteReady
+    for StatusGated<'_, u8, S, PD, PS>
+{
+    fn write_ready(&mut self) -> Result<bool, Self::Error> {
+        Ok(self.is_write_ready())
+    }
+}
@@ -0,0 +1,133 @@
+use core::{any::type_name, convert::TryFrom, fmt, marker::PhantomData};
+
+use crate::{
+    volatile::{Read, ReadWrite, Write},
+    InvalidValue, Volatile, VolatileData, VolatileRead, VolatileWrite,
+};
+
+/// A register holding an enum of type `E`, which is stored as an integer of
+/// type `U`.
+///
+/// Reading a [`Volatile<E>`](Volatile) is undefined behavior if the hardware
+/// returns a value which is not a valid `E`, such as a reserved encoding.
+/// Instead, a `VolatileEnum` reads the integer, and converts it to `E` with
+/// [`TryFrom<U>`](TryFrom), so an unexpected value is returned as an
+/// [`InvalidValue`] by [`try_read`](Self::try_read). Values are written using
+/// [`Into<U>`](Into).
+///
+/// The conversions generated by the `TryFromPrimitive` and `IntoPrimitive`
+/// derives of the `num_enum` crate can be used directly.
+///
+/// # Example
+/// ```
+/// use core::convert::TryFrom;
+/// use volatile_mem::{InvalidValue, Volatile, VolatileEnum, VolatileWrite};
+///
+/// #[derive(Clone, Copy, Debug, PartialEq)]
+/// #[repr(u8)]
+/// enum Mode {
+///     Off = 0,
+///     On = 1,
+/// }
+///
+/// impl TryFrom<u8> for Mode {
+///     type Error = ();
+///
+///     fn try_from(val: u8) -> Result<Self, ()> {
+///         match val {
+///             0 => Ok(Mode::Off),
+///             1 => Ok(Mode::On),
+///             _ => Err(()),
+///         }
+///     }
+/// }
+///
+/// impl From<Mode> for u8 {
+///     fn from(mode: Mode) -> u8 {
+///         mode as u8
+///     }
+/// }
+///
+/// let mut mem = 1u8;
+/// let reg: &mut VolatileEnum<Mode, u8> = Volatile::from_mut(&mut mem).into();
+/// assert_eq!(reg.try_read(), Ok(Mode::On));
+/// reg.write(Mode::Off);
+/// assert_eq!(reg.try_read(), Ok(Mode::Off));
+///
+/// let mut mem = 7u8;
+/// let reg = VolatileEnum::<Mode, u8>::from_volatile(Volatile::from_mut(&mut mem));
+/// assert_eq!(reg.try_read(), Err(InvalidValue(7)));
+/// ```
+#[repr(transparent)]
+pub struct VolatileEnum<E, U: Copy, Permission = ReadWrite> {
+    bits: Volatile<U, Permission>,
+    _enum: PhantomData<E>,
+}
+
+impl<E, U: Copy, P> VolatileEnum<E, U, P> {
+    /// Converts a reference to a [`Volatile`] integer into a reference to a
+    /// `VolatileEnum`.
+    pub const fn from_volatile(vol: &Volatile<U, P>) -> &Self {
+        // SAFETY: It is safe to cast to `*const Self` because `Self` is
+        // transparent.
+        unsafe { &*(vol as *const Volatile<U, P> as *const Self) }
+    }
+
+    /// Converts a mutable reference to a [`Volatile`] integer into a mutable
+    /// reference to a `VolatileEnum`.
+    pub const fn from_volatile_mut(vol: &mut Volatile<U, P>) -> &mut Self {
+        // SAFETY: It is safe to cast to `*mut Self` because `Self` is
+        // transparent.
+        unsafe { &mut *(vol as *mut Volatile<U, P> as *mut Self) }
+    }
+
+    /// Returns a reference to the underlying [`Volatile`] integer.
+    pub const fn bits(&self) -> &Volatile<U, P> {
+        &self.bits
+    }
+
+    /// Returns a mutable reference to the underlying [`Volatile`] integer.
+    pub const fn bits_mut(&mut self) -> &mut Volatile<U, P> {
+        &mut self.bits
+    }
+}
+
+impl<E: TryFrom<U>, U: Copy, P: Read> VolatileEnum<E, U, P> {
+    /// Performs a volatile read of the whole register, and converts it to `E`.
+    ///
+    /// # Errors
+    /// Returns [`InvalidValue`] with the value read if it does not correspond
+    /// to a value of `E`.
+    pub fn try_read(&self) -> Result<E, InvalidValue<U>> {
+        let raw = self.bits.read();
+        E::try_from(raw).map_err(|_| InvalidValue(raw))
+    }
+}
+
+impl<E: Copy, U: Copy, P> VolatileData<E> for VolatileEnum<E, U, P> {}
+
+impl<E: Copy + Into<U>, U: Copy, P: Write> VolatileWrite<E> for VolatileEnum<E, U, P> {
+    /// Converts `val` to `U`, and performs a volatile write of the whole
+    /// register.
+    fn write(&mut self, val: E) {
+        self.bits.write(val.into());
+    }
+}
+
+impl<'a, E, U: Copy, P> From<&'a Volatile<U, P>> for &'a VolatileEnum<E, U, P> {
+    fn from(vol: &'a Volatile<U, P>) -> Self {
+        VolatileEnum::from_volatile(vol)
+    }
+}
+
+impl<'a, E, U: Copy, P> From<&'a mut Volatile<U, P>> for &'a mut VolatileEnum<E, U, P> {
+    fn from(vol: &'a mut Volatile<U, P>) -> Self {
+        VolatileEnum::from_volatile_mut(vol)
+    }
+}
+
+impl<E, U: Copy, P> fmt::Debug for VolatileEnum<E, U, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(type_name::<Self>())
+    }
+}
@@ -0,0 +1,128 @@
+use core::{error, fmt, str::Utf8Error};
+
+/// An error returned when a pointer, slice, or offset cannot be converted into
+/// a reference to a [`Volatile`](crate::Volatile), or when a slice does not
+/// have the length required to copy to or from volatile memory.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VolatileError {
+    /// The pointer is NULL.
+    Null,
+    /// The pointer is not properly aligned for the type.
+    Misaligned {
+        /// The address of the pointer.
+        addr: usize,
+        /// The required alignment of the type.
+        align: usize,
+    },
+    /// The slice does not have the required length.
+    LengthMismatch {
+        /// The required length.
+        expected: usize,
+        /// The length of the slice.
+        actual: usize,
+    },
+    /// The access is not entirely within the region.
+    ///
+    /// For a region of bytes, such as a [`PciBar`](crate::PciBar), the fields
+    /// are in bytes. For a slice of volatile elements, they are in elements.
+    OutOfBounds {
+        /// The offset of the access from the start of the region.
+        offset: usize,
+        /// The size of the access.
+        len: usize,
+        /// The size of the region.
+        size: usize,
+    },
+    /// Two segments of a scatter-gather copy into a region overlap.
+    Overlap {
+        /// The offset of the later of the two segments.
+        offset: usize,
+        /// The length of the later of the two segments.
+        len: usize,
+    },
+}
+
+impl fmt::Display for VolatileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            VolatileError::Null => f.write_str("pointer is null"),
+            VolatileError::Misaligned { addr, align } => {
+                write!(f, "pointer {:#x} is not aligned to {} bytes", addr, align)
+            }
+            VolatileError::LengthMismatch { expected, actual } => write!(
+                f,
+                "slice has length {}, but length {} is required",
+                actual, expected
+            ),
+            VolatileError::OutOfBounds { offset, len, size } => write!(
+                f,
+                "access of length {} at offset {:#x} is outside of a region of size {:#x}",
+                len, offset, size
+            ),
+            VolatileError::Overlap { offset, len } => write!(
+                f,
+                "segment of length {} at offset {:#x} overlaps another segment",
+                len, offset
+            ),
+        }
+    }
+}
+
+impl error::Error for VolatileError {}
+
+/// An error returned when a NUL-terminated string cannot be read from volatile
+/// memory.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CStrError {
+    /// No NUL byte was found before the end of the memory or of the buffer.
+    Unterminated {
+        /// The number of bytes read.
+        len: usize,
+    },
+    /// The bytes before the NUL byte are not valid UTF-8.
+    Utf8(Utf8Error),
+}
+
+impl fmt::Display for CStrError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            CStrError::Unterminated { len } => {
+                write!(f, "no NUL byte found in the first {} bytes", len)
+            }
+            CStrError::Utf8(err) => write!(f, "string is not valid UTF-8: {}", err),
+        }
+    }
+}
+
+impl error::Error for CStrError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            CStrError::Utf8(err) => Some(err),
+            CStrError::Unterminated { .. } => None,
+        }
+    }
+}
+
+impl From<Utf8Error> for CStrError {
+    fn from(err: Utf8Error) -> Self {
+        CStrError::Utf8(err)
+    }
+}
+
+/// An error returned when a register holds a value which does not correspond
+/// to any value of the type it is read as, such as a reserved encoding of an
+/// enum.
+///
+/// This contains the raw value which was read.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidValue<U>(pub U);
+
+impl<U: fmt::LowerHex> fmt::Display for InvalidValue<U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "register holds invalid value {:#x}", self.0)
+    }
+}
+
+impl<U: fmt::Debug + fmt::LowerHex> error::Error for InvalidValue<U> {}
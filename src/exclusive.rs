@@ -0,0 +1,79 @@
+use core::arch::asm;
+
+use crate::{
+    volatile::{Read, Write},
+    Volatile,
+};
+
+macro_rules! impl_exclusive {
+    ($($ty:ty: $ldrex:literal, $strex:literal),*) => {
+        $(
+            impl<P: Read + Write> Volatile<$ty, P> {
+                /// Atomically updates `self` with the result of `f`, using a
+                /// load-exclusive and store-exclusive loop, returning the
+                /// previous value.
+                ///
+                /// The value is loaded with
+                #[doc = concat!("`", $ldrex, "`")]
+                /// and passed to `f`, and the result is stored with
+                #[doc = concat!("`", $strex, "`,")]
+                /// which fails if the exclusive monitor was cleared in the
+                /// meantime, such as by a write from another core or by an
+                /// exception.
+                /// In that case, the value is loaded again and `f` is called
+                /// again, so `f` may be called any number of times. It should
+                /// be short and should not access memory, since some
+                /// implementations clear the exclusive monitor on other memory
+                /// accesses, which could then prevent the loop from completing.
+                ///
+                /// This is intended for variables shared between cores in
+                /// shareable normal memory. Exclusive accesses to device memory
+                /// are generally not supported. The update is not ordered with
+                /// respect to other memory accesses, so a
+                /// [`fence`](core::sync::atomic::fence) is required if it
+                /// guards other data.
+                pub fn modify_exclusive(&mut self, mut f: impl FnMut($ty) -> $ty) -> $ty {
+                    let ptr = self.as_mut_ptr();
+                    loop {
+                        let old: $ty;
+                        // SAFETY: `ptr` comes from a mutable reference, so it
+                        // is valid for reads and is properly aligned.
+                        unsafe {
+                            asm!(
+                                concat!($ldrex, " {old}, [{ptr}]"),
+                                old = out(reg) old,
+                                ptr = in(reg) ptr,
+                                options(nostack, preserves_flags),
+                            );
+                        }
+                        let new = f(old);
+                        let failed: u32;
+                        // SAFETY: `ptr` comes from a mutable reference, so it
+                        // is valid for writes and is properly aligned.
+                        unsafe {
+                            asm!(
+                                concat!($strex, " {failed}, {new}, [{ptr}]"),
+                                failed = out(reg) failed,
+                                new = in(reg) new,
+                                ptr = in(reg) ptr,
+                                options(nostack, preserves_flags),
+                            );
+                        }
+                        if failed == 0 {
+                            return old;
+                        }
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_exclusive!(
+    u8: "ldrexb", "strexb",
+    u16: "ldrexh", "strexh",
+    u32: "ldrex", "strex",
+    i8: "ldrexb", "strexb",
+    i16: "ldrexh", "strexh",
+    i32: "ldrex", "strex"
+);
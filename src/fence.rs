@@ -0,0 +1,189 @@
+//! Fence policies, which add barriers to every access through a
+//! [`VolatileFenced`] handle.
+//!
+//! Rather than calling [`fence`] around each access to a register, a driver can
+//! select a [`FencePolicy`] once, as a type parameter of [`VolatileFenced`].
+use core::{
+    any::type_name,
+    fmt,
+    marker::PhantomData,
+    sync::atomic::{compiler_fence, fence, Ordering},
+};
+
+use crate::{
+    barrier,
+    volatile::{Read, ReadWrite, Write},
+    Volatile, VolatileData, VolatileRead, VolatileWrite,
+};
+
+/// The barriers to be added before and after each access through a
+/// [`VolatileFenced`].
+///
+/// Each method does nothing by default.
+pub trait FencePolicy {
+    /// Called before each volatile read.
+    fn before_read() {}
+    /// Called after each volatile read.
+    fn after_read() {}
+    /// Called before each volatile write.
+    fn before_write() {}
+    /// Called after each volatile write.
+    fn after_write() {}
+}
+
+/// A fence policy which adds no barriers.
+#[derive(Debug)]
+pub struct NoFence;
+
+impl FencePolicy for NoFence {}
+
+/// A fence policy which adds a sequentially consistent [`compiler_fence`]
+/// before and after each access.
+///
+/// This prevents the compiler from reordering other memory accesses around
+/// each access, but does not prevent the hardware from doing so.
+#[derive(Debug)]
+pub struct CompilerFence;
+
+impl FencePolicy for CompilerFence {
+    fn before_read() {
+        compiler_fence(Ordering::SeqCst);
+    }
+
+    fn after_read() {
+        compiler_fence(Ordering::SeqCst);
+    }
+
+    fn before_write() {
+        compiler_fence(Ordering::SeqCst);
+    }
+
+    fn after_write() {
+        compiler_fence(Ordering::SeqCst);
+    }
+}
+
+/// A fence policy which adds a sequentially consistent [`fence`] before and
+/// after each access.
+#[derive(Debug)]
+pub struct FullFence;
+
+impl FencePolicy for FullFence {
+    fn before_read() {
+        fence(Ordering::SeqCst);
+    }
+
+    fn after_read() {
+        fence(Ordering::SeqCst);
+    }
+
+    fn before_write() {
+        fence(Ordering::SeqCst);
+    }
+
+    fn after_write() {
+        fence(Ordering::SeqCst);
+    }
+}
+
+/// A fence policy which adds the target architecture's device barriers, for
+/// memory-mapped I/O which is coordinated with other bus masters.
+///
+/// A device read barrier is added after each read, so that memory accesses
+/// after the read, such as of data written by a DMA engine, cannot be performed
+/// before it. A device write barrier is added before each write, so that memory
+/// accesses before the write, such as of a DMA descriptor, complete before it.
+#[derive(Debug)]
+pub struct DeviceFence;
+
+impl FencePolicy for DeviceFence {
+    fn after_read() {
+        barrier::device_read_barrier();
+    }
+
+    fn before_write() {
+        barrier::device_write_barrier();
+    }
+}
+
+/// Volatile data or memory, with the barriers of the fence policy `F` added to
+/// every access.
+///
+/// `VolatileFenced` has the same layout as [`Volatile`], so it can be used as a
+/// field of a `#[repr(C)]` register struct.
+#[repr(transparent)]
+pub struct VolatileFenced<T: Copy, F, Permission = ReadWrite> {
+    vol: Volatile<T, Permission>,
+    _fence: PhantomData<F>,
+}
+
+impl<T: Copy, F, P> VolatileFenced<T, F, P> {
+    /// Converts a reference to a [`Volatile`] into a reference to a
+    /// `VolatileFenced`.
+    pub const fn from_volatile(vol: &Volatile<T, P>) -> &Self {
+        // SAFETY: It is safe to cast to `*const Self` because `Self` is
+        // transparent.
+        unsafe { &*(vol as *const Volatile<T, P> as *const Self) }
+    }
+
+    /// Converts a mutable reference to a [`Volatile`] into a mutable reference
+    /// to a `VolatileFenced`.
+    pub const fn from_volatile_mut(vol: &mut Volatile<T, P>) -> &mut Self {
+        // SAFETY: It is safe to cast to `*mut Self` because `Self` is
+        // transparent.
+        unsafe { &mut *(vol as *mut Volatile<T, P> as *mut Self) }
+    }
+
+    /// Returns a reference to the underlying [`Volatile`], through which
+    /// accesses have no barriers.
+    pub const fn as_unfenced(&self) -> &Volatile<T, P> {
+        &self.vol
+    }
+
+    /// Returns a mutable reference to the underlying [`Volatile`], through
+    /// which accesses have no barriers.
+    pub const fn as_unfenced_mut(&mut self) -> &mut Volatile<T, P> {
+        &mut self.vol
+    }
+}
+
+impl<T: Copy, F, P> VolatileData<T> for VolatileFenced<T, F, P> {}
+
+impl<T: Copy, F: FencePolicy, P: Read> VolatileRead<T> for VolatileFenced<T, F, P> {
+    /// Performs a volatile read of the value in `self`, with the barriers of
+    /// the fence policy. This leaves the memory in `self` unchanged.
+    fn read(&self) -> T {
+        F::before_read();
+        let value = self.vol.read();
+        F::after_read();
+        value
+    }
+}
+
+impl<T: Copy, F: FencePolicy, P: Write> VolatileWrite<T> for VolatileFenced<T, F, P> {
+    /// Performs a volatile write of `self` with the given value, with the
+    /// barriers of the fence policy, without reading the old value.
+    fn write(&mut self, val: T) {
+        F::before_write();
+        self.vol.write(val);
+        F::after_write();
+    }
+}
+
+impl<'a, T: Copy, F, P> From<&'a Volatile<T, P>> for &'a VolatileFenced<T, F, P> {
+    fn from(vol: &'a Volatile<T, P>) -> Self {
+        VolatileFenced::from_volatile(vol)
+    }
+}
+
+impl<'a, T: Copy, F, P> From<&'a mut Volatile<T, P>> for &'a mut VolatileFenced<T, F, P> {
+    fn from(vol: &'a mut Volatile<T, P>) -> Self {
+        VolatileFenced::from_volatile_mut(vol)
+    }
+}
+
+impl<T: Copy, F, P> fmt::Debug for VolatileFenced<T, F, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(type_name::<Self>())
+    }
+}
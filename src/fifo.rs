@@ -0,0 +1,195 @@
+use core::{any::type_name, fmt, hint};
+
+use crate::{
+    volatile::{Read, ReadWrite, Write, WriteOnly},
+    Volatile, VolatileData, VolatileRead, VolatileWrite,
+};
+
+/// A data register through which a FIFO is accessed, such as the data register
+/// of a UART or SPI controller.
+///
+/// Unlike the slice methods of [`VolatileReadSlice`](crate::VolatileReadSlice)
+/// and [`VolatileWriteSlice`](crate::VolatileWriteSlice), which access each
+/// element of a slice of volatile memory, the methods of `VolatileFifo` access
+/// the same register repeatedly, once for each element of a slice of ordinary
+/// memory.
+///
+/// `VolatileFifo` has the same layout as [`Volatile`], so it can be used as a
+/// field of a `#[repr(C)]` register struct.
+///
+/// # Example
+/// ```
+/// use volatile_mem::{Volatile, VolatileFifo};
+///
+/// let mut mem = 0u8;
+/// let fifo: &mut VolatileFifo<u8> = Volatile::<_>::from_mut(&mut mem).into();
+/// fifo.write_all(b"abc");
+///
+/// let mut buf = [0; 2];
+/// fifo.read_n_into(&mut buf);
+/// assert_eq!(buf, *b"cc");
+/// ```
+#[repr(transparent)]
+pub struct VolatileFifo<T: Copy, Permission = ReadWrite> {
+    vol: Volatile<T, Permission>,
+}
+
+impl<T: Copy, P> VolatileFifo<T, P> {
+    /// Converts a reference to a [`Volatile`] into a reference to a
+    /// `VolatileFifo`.
+    pub const fn from_volatile(vol: &Volatile<T, P>) -> &Self {
+        // SAFETY: It is safe to cast to `*const Self` because `Self` is
+        // transparent.
+        unsafe { &*(vol as *const Volatile<T, P> as *const Self) }
+    }
+
+    /// Converts a mutable reference to a [`Volatile`] into a mutable reference
+    /// to a `VolatileFifo`.
+    pub const fn from_volatile_mut(vol: &mut Volatile<T, P>) -> &mut Self {
+        // SAFETY: It is safe to cast to `*mut Self` because `Self` is
+        // transparent.
+        unsafe { &mut *(vol as *mut Volatile<T, P> as *mut Self) }
+    }
+
+    /// Returns a reference to the underlying [`Volatile`].
+    pub const fn as_volatile(&self) -> &Volatile<T, P> {
+        &self.vol
+    }
+
+    /// Returns a mutable reference to the underlying [`Volatile`].
+    pub const fn as_volatile_mut(&mut self) -> &mut Volatile<T, P> {
+        &mut self.vol
+    }
+}
+
+impl<T: Copy, P: Read> VolatileFifo<T, P> {
+    /// Performs `dst.len()` volatile reads of the register, storing the values
+    /// in `dst` in the order they were read.
+    ///
+    /// This is the same as [`read_repeated`](Self::read_repeated).
+    pub fn read_n_into(&self, dst: &mut [T]) {
+        self.read_repeated(dst);
+    }
+
+    /// Performs `dst.len()` volatile reads of the register, storing the values
+    /// in `dst` in the order they were read, such as to drain a receive FIFO.
+    pub fn read_repeated(&self, dst: &mut [T]) {
+        for elem in dst {
+            *elem = self.vol.read();
+        }
+    }
+}
+
+impl<T: Copy, P: Write> VolatileFifo<T, P> {
+    /// Performs a volatile write of the register with each element of `src`,
+    /// in order.
+    pub fn write_all(&mut self, src: &[T]) {
+        for &elem in src {
+            self.vol.write(elem);
+        }
+    }
+
+    /// Performs a volatile write of the register with each item of `iter`, in
+    /// order, returning the number of items written.
+    pub fn write_from_iter(&mut self, iter: impl IntoIterator<Item = T>) -> usize {
+        let mut count = 0;
+        for elem in iter {
+            self.vol.write(elem);
+            count += 1;
+        }
+        count
+    }
+}
+
+impl<T: Copy, P> VolatileData<T> for VolatileFifo<T, P> {}
+
+impl<T: Copy, P: Read> VolatileRead<T> for VolatileFifo<T, P> {
+    /// Performs a single volatile read of the register.
+    fn read(&self) -> T {
+        self.vol.read()
+    }
+}
+
+impl<T: Copy, P: Write> VolatileWrite<T> for VolatileFifo<T, P> {
+    /// Performs a single volatile write of the register.
+    fn write(&mut self, val: T) {
+        self.vol.write(val);
+    }
+}
+
+impl<'a, T: Copy, P> From<&'a Volatile<T, P>> for &'a VolatileFifo<T, P> {
+    fn from(vol: &'a Volatile<T, P>) -> Self {
+        VolatileFifo::from_volatile(vol)
+    }
+}
+
+impl<'a, T: Copy, P> From<&'a mut Volatile<T, P>> for &'a mut VolatileFifo<T, P> {
+    fn from(vol: &'a mut Volatile<T, P>) -> Self {
+        VolatileFifo::from_volatile_mut(vol)
+    }
+}
+
+impl<T: Copy, P> fmt::Debug for VolatileFifo<T, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(type_name::<Self>())
+    }
+}
+
+/// An adapter implementing [`fmt::Write`] over a byte data register, such as
+/// the transmit register of a UART, so that it can be used with [`write!`].
+///
+/// Each byte of the formatted output is written to the register in turn. If
+/// the adapter is created with [`with_ready`](Self::with_ready), it waits
+/// before each byte until the given closure, such as one checking a "transmit
+/// ready" status bit, returns `true`.
+///
+/// # Example
+/// ```
+/// use core::fmt::Write;
+/// use volatile_mem::{FmtWriter, VolatileWriteOnly};
+///
+/// let mut mem = 0u8;
+/// let tx: &mut VolatileWriteOnly<u8> = VolatileWriteOnly::from_mut(&mut mem);
+/// write!(FmtWriter::new(tx), "{}", 42).unwrap();
+/// assert_eq!(mem, b'2');
+/// ```
+pub struct FmtWriter<'a, P = WriteOnly, F = fn() -> bool> {
+    vol: &'a mut Volatile<u8, P>,
+    ready: F,
+}
+
+impl<'a, P: Write> FmtWriter<'a, P> {
+    /// Creates an adapter which writes each byte to `vol` without waiting.
+    pub fn new(vol: &'a mut Volatile<u8, P>) -> Self {
+        FmtWriter {
+            vol,
+            ready: || true,
+        }
+    }
+}
+
+impl<'a, P: Write, F: FnMut() -> bool> FmtWriter<'a, P, F> {
+    /// Creates an adapter which writes each byte to `vol` once `ready` returns
+    /// `true`.
+    pub fn with_ready(vol: &'a mut Volatile<u8, P>, ready: F) -> Self {
+        FmtWriter { vol, ready }
+    }
+}
+
+impl<P: Write, F: FnMut() -> bool> fmt::Write for FmtWriter<'_, P, F> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for byte in s.bytes() {
+            while !(self.ready)() {
+                hint::spin_loop();
+            }
+            self.vol.write(byte);
+        }
+        Ok(())
+    }
+}
+
+impl<P, F> fmt::Debug for FmtWriter<'_, P, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(type_name::<Self>())
+    }
+}
@@ -0,0 +1,50 @@
+use core::{future::Future, task::Poll, task::Waker};
+
+use crate::{volatile::Read, Volatile, VolatileRead};
+
+impl<T: Copy, P: Read> Volatile<T, P> {
+    /// Returns a future which completes with the value of `self` once `pred`
+    /// returns `true` for it.
+    ///
+    /// Each time the future is polled, it first passes the task's [`Waker`] to
+    /// `register`, then performs a volatile read of `self`, completing if the
+    /// value satisfies `pred`. `register` should arrange for the waker to be
+    /// woken when the value may have changed, such as by storing it for an
+    /// interrupt handler, so that the executor does not need to poll the
+    /// future repeatedly. Since the waker is registered before the read, a
+    /// change which occurs between the two is not missed.
+    ///
+    /// # Example
+    /// ```
+    /// use volatile_mem::Volatile;
+    ///
+    /// # fn block_on<F: core::future::Future>(f: F) -> F::Output {
+    /// #     use core::task::{Context, Poll, Waker};
+    /// #     let mut f = core::pin::pin!(f);
+    /// #     let mut cx = Context::from_waker(Waker::noop());
+    /// #     loop {
+    /// #         if let Poll::Ready(value) = f.as_mut().poll(&mut cx) {
+    /// #             return value;
+    /// #         }
+    /// #     }
+    /// # }
+    /// let status: &Volatile<u32> = Volatile::from_ref(&0x1);
+    /// let value = block_on(status.wait_for(|status| status & 0x1 != 0, |_waker| {}));
+    /// assert_eq!(value, 0x1);
+    /// ```
+    pub fn wait_for<'a>(
+        &'a self,
+        mut pred: impl FnMut(T) -> bool + 'a,
+        mut register: impl FnMut(&Waker) + 'a,
+    ) -> impl Future<Output = T> + 'a {
+        core::future::poll_fn(move |cx| {
+            register(cx.waker());
+            let value = self.read();
+            if pred(value) {
+                Poll::Ready(value)
+            } else {
+                Poll::Pending
+            }
+        })
+    }
+}
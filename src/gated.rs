@@ -0,0 +1,160 @@
+use core::{any::type_name, fmt, hint};
+
+use crate::{
+    poll::PollTimer,
+    volatile::{Read, ReadOnly, ReadWrite, Write},
+    Timeout, Volatile, VolatileRead, VolatileWrite,
+};
+
+/// A data register paired with a status register which indicates whether the
+/// data register is ready to be read or written, such as those of a UART.
+///
+/// The status register is read, and the value checked with the `read_ready`
+/// or `write_ready` predicate, before each access to the data register. Each
+/// access may be blocking, waiting until the data register is ready;
+/// non-blocking, failing if it is not ready; or bounded by a
+/// [`PollTimer`], failing with [`Timeout`] if it is not ready in time.
+///
+/// # Example
+/// ```
+/// use volatile_mem::{StatusGated, Volatile};
+///
+/// let mut data = 0u8;
+/// let status = 0b10u8;
+/// let mut uart = StatusGated::new(
+///     Volatile::<_>::from_mut(&mut data),
+///     Volatile::<_>::from_ref(&status),
+///     |status| status & 0b01 != 0,
+///     |status| status & 0b10 != 0,
+/// );
+/// assert!(!uart.is_read_ready());
+/// assert_eq!(uart.read_if_ready(), None);
+/// uart.write_blocking(b'a');
+/// ```
+pub struct StatusGated<'a, T: Copy, S: Copy, PD = ReadWrite, PS = ReadOnly> {
+    data: &'a mut Volatile<T, PD>,
+    status: &'a Volatile<S, PS>,
+    read_ready: fn(S) -> bool,
+    write_ready: fn(S) -> bool,
+}
+
+impl<'a, T: Copy, S: Copy, PD, PS: Read> StatusGated<'a, T, S, PD, PS> {
+    /// Pairs the data register `data` with the status register `status`.
+    ///
+    /// The data register is ready to be read when `read_ready` returns `true`
+    /// for the value of the status register, and ready to be written when
+    /// `write_ready` does. If the data register is only read or only written,
+    /// the unused predicate may be `|_| false`.
+    pub fn new(
+        data: &'a mut Volatile<T, PD>,
+        status: &'a Volatile<S, PS>,
+        read_ready: fn(S) -> bool,
+        write_ready: fn(S) -> bool,
+    ) -> Self {
+        StatusGated {
+            data,
+            status,
+            read_ready,
+            write_ready,
+        }
+    }
+
+    /// Reads the status register and returns `true` if the data register is
+    /// ready to be read.
+    pub fn is_read_ready(&self) -> bool {
+        (self.read_ready)(self.status.read())
+    }
+
+    /// Reads the status register and returns `true` if the data register is
+    /// ready to be written.
+    pub fn is_write_ready(&self) -> bool {
+        (self.write_ready)(self.status.read())
+    }
+
+    /// Returns the data register, through which accesses are not gated.
+    pub fn data(&mut self) -> &mut Volatile<T, PD> {
+        self.data
+    }
+
+    /// Returns the status register.
+    pub fn status(&self) -> &'a Volatile<S, PS> {
+        self.status
+    }
+}
+
+impl<T: Copy, S: Copy, PD: Read, PS: Read> StatusGated<'_, T, S, PD, PS> {
+    /// Waits until the data register is ready to be read, then reads it.
+    ///
+    /// This spins until the status register indicates that the data register
+    /// is ready, which may be forever.
+    pub fn read_blocking(&mut self) -> T {
+        while !self.is_read_ready() {
+            hint::spin_loop();
+        }
+        self.data.read()
+    }
+
+    /// Reads the data register if it is ready to be read, or returns `None`
+    /// without reading it if not.
+    pub fn read_if_ready(&mut self) -> Option<T> {
+        if !self.is_read_ready() {
+            return None;
+        }
+        Some(self.data.read())
+    }
+
+    /// Waits until the data register is ready to be read, then reads it,
+    /// unless `timer` expires first.
+    ///
+    /// # Errors
+    /// Returns [`Timeout`] without reading the data register if `timer`
+    /// expires before it is ready.
+    pub fn read_timeout(&mut self, timer: impl PollTimer) -> Result<T, Timeout> {
+        self.status.wait_until_timeout(self.read_ready, timer)?;
+        Ok(self.data.read())
+    }
+}
+
+impl<T: Copy, S: Copy, PD: Write, PS: Read> StatusGated<'_, T, S, PD, PS> {
+    /// Waits until the data register is ready to be written, then writes `val`
+    /// to it.
+    ///
+    /// This spins until the status register indicates that the data register
+    /// is ready, which may be forever.
+    pub fn write_blocking(&mut self, val: T) {
+        while !self.is_write_ready() {
+            hint::spin_loop();
+        }
+        self.data.write(val);
+    }
+
+    /// Writes `val` to the data register if it is ready to be written.
+    ///
+    /// # Errors
+    /// Returns `val` without writing the data register if it is not ready.
+    pub fn write_if_ready(&mut self, val: T) -> Result<(), T> {
+        if !self.is_write_ready() {
+            return Err(val);
+        }
+        self.data.write(val);
+        Ok(())
+    }
+
+    /// Waits until the data register is ready to be written, then writes `val`
+    /// to it, unless `timer` expires first.
+    ///
+    /// # Errors
+    /// Returns [`Timeout`] without writing the data register if `timer`
+    /// expires before it is ready.
+    pub fn write_timeout(&mut self, val: T, timer: impl PollTimer) -> Result<(), Timeout> {
+        self.status.wait_until_timeout(self.write_ready, timer)?;
+        self.data.write(val);
+        Ok(())
+    }
+}
+
+impl<T: Copy, S: Copy, PD, PS> fmt::Debug for StatusGated<'_, T, S, PD, PS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(type_name::<Self>())
+    }
+}
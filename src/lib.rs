@@ -8,17 +8,108 @@
 //! parameter of type [`Volatile`]. You would typically use some kind of pointer
 //! or reference to the [`Volatile`] object instead.
 //!
-//! Besides [`Volatile`], the crate provides two additional volatile types. They
-//! are [`VolatileReadOnly`], and [`VolatileWriteOnly`]. These are technically
-//! just type definitions which alias read-only and write-only variants of
-//! [`Volatile`], respectively.  However, those variants are only available
-//! through these aliases. The default variant for [`Volatile`] allows both
-//! reads and writes.
+//! Besides [`Volatile`], the crate provides five additional volatile types.
+//! They are [`VolatileReadOnly`], [`VolatileWriteOnly`], [`VolatileReadClear`],
+//! [`VolatileWriteOnce`], and [`Reserved`]. These are technically just type
+//! definitions which alias read-only, write-only, read-to-clear, write-once,
+//! and inaccessible variants of [`Volatile`], respectively.
+//! However, those variants are only available through these aliases. The
+//! default variant for [`Volatile`] allows both reads and writes.
 //!
 //! [`Volatile`] is meant for reading from or writing to memory used for
 //! communication with some process external to the program. A common use case
 //! would be memory-mapped I/O.
 //!
+//! # Features
+//! - `derive`: Enables `VolatileFields`, a derive macro generating per-field
+//!   volatile accessors for structs, and `VolatileSnapshot`, a derive macro
+//!   generating functions which read or write a whole struct one field at a
+//!   time.
+//! - `tock-registers`: Enables the `tock` module, for interoperability with the
+//!   `tock-registers` crate.
+//! - `vcell`: Enables conversions between [`Volatile`] and `vcell::VolatileCell`.
+//! - `volatile-register`: Enables conversions between [`Volatile`] and the
+//!   register types of the `volatile-register` crate.
+//! - `voladdress`: Enables conversions between [`Volatile`] and the
+//!   `VolAddress` and `VolBlock` types of the `voladdress` crate.
+//! - `bitflags`: Enables flag manipulation of [`Volatile`] values whose type is
+//!   generated by the `bitflags` crate. Such a type should be declared
+//!   `#[repr(transparent)]`, so that it has the layout of its underlying
+//!   integer.
+//! - `arbitrary-int`: Allows the types of the `arbitrary-int` crate, such as
+//!   `u3`, to be used as the values of register fields.
+//! - `critical-section`: Enables `VolatileShared::modify_cs`, which performs a
+//!   read-modify-write within a critical section.
+//! - `cortex-m`: Enables `VolatileShared::modify_irq_free`, which performs a
+//!   read-modify-write with interrupts disabled on Cortex-M, and implements
+//!   `cache::CacheOps` for the Cortex-M7 data cache.
+//! - `embedded-dma`: Implements `embedded_dma::ReadTarget` and
+//!   `embedded_dma::WriteTarget` for [`Volatile`] values whose type implements
+//!   them, such as `Volatile<[u8; N]>`, so that a `&'static mut Volatile<[u8; N]>`
+//!   can be used as a DMA buffer.
+//! - `embedded-io`: Implements the `Read`, `Write`, `ReadReady`, and
+//!   `WriteReady` traits of `embedded_io` for [`StatusGated`] byte registers.
+//! - `nb`: Enables `StatusGated::try_read` and `StatusGated::try_write`, which
+//!   return `nb::Error::WouldBlock` if the data register is not ready.
+//! - `embedded-hal`: Enables `poll::DelayTimeout`, a poll timer using an
+//!   `embedded_hal::delay::DelayNs` implementation.
+//! - `async`: Enables `Volatile::wait_for`, which returns a future completing
+//!   once a register satisfies a predicate.
+//! - `embassy-time`: Enables `Volatile::poll_until`, `Volatile::poll_for_set`,
+//!   and `Volatile::poll_for_clear`, which poll a register with an
+//!   `embassy_time::Timer` between reads, and which can be combined with
+//!   `embassy_time::WithTimeout`.
+//! - `alloc`: Enables `VolatileBox`, heap-allocated volatile memory with a
+//!   guaranteed alignment, and `VolatileBuf`, a heap-allocated buffer accessed
+//!   as a volatile slice, for unit tests.
+//! - `std`: Enables `VolatileMmap`, a memory mapping of a file, a named shared
+//!   memory object, or anonymous memory accessed as volatile bytes, on Unix and
+//!   Windows. This implies `alloc`.
+//! - `x86_64`: Enables `Volatile::from_virt_addr` and
+//!   `Volatile::from_phys_addr`, which accept the `VirtAddr` and `PhysAddr`
+//!   types of the `x86_64` crate, as well as `Volatile::virt_addr`.
+//! - `port-io`: Enables `PortVolatile`, an x86 I/O port accessed with the `in`
+//!   and `out` instructions, with the same permissions as [`Volatile`]. This
+//!   has no effect on other architectures.
+//! - `bit-band`: Enables `Volatile::bit` and `Volatile::bit_mut`, which
+//!   return the word in the bit-band alias region of the Cortex-M3 and
+//!   Cortex-M4 for a single bit, so it can be set or cleared without a
+//!   read-modify-write. This has no effect on targets other than bare-metal
+//!   ARM.
+//! - `portable-atomic`: Enables `atomic_fetch_or`, `atomic_fetch_and`, and
+//!   `atomic_swap` for integers in a [`Volatile`], and through shared
+//!   references for integers in a [`VolatileShared`], for control words in
+//!   normal shared memory. These use the `portable-atomic` crate, which
+//!   provides 128-bit atomics where the target has them natively, such as on
+//!   AArch64 and on x86-64 with `cmpxchg16b`, and atomics on single-core
+//!   targets without atomic read-modify-write instructions, such as ARMv6-M,
+//!   with its `unsafe-assume-single-core` or `critical-section` features.
+//!   The latter disable interrupts rather than using atomic instructions, so
+//!   they do not exclude other bus masters. Its lock-based `fallback` feature
+//!   is not enabled, since locks do not exclude other bus masters either.
+//! - `arm-exclusive`: Enables `Volatile::modify_exclusive` on ARM targets with
+//!   32-bit atomics, such as ARMv7 and ARMv7-M, which updates an integer of up
+//!   to 32 bits with a load-exclusive and store-exclusive loop, for variables
+//!   shared between cores. This has no effect on other architectures, or on
+//!   ARMv6-M, which lacks exclusive accesses.
+//! - `armv8-cache`: Enables `cache::Armv8Cache`, which implements
+//!   `cache::CacheOps` on AArch64 with the `dc` instructions, for code running
+//!   at EL1 or higher. This has no effect on other architectures.
+//! - `region-registry`: Enables the `registry` module, which records live
+//!   volatile regions in a global table and panics when two of them overlap,
+//!   for catching double-mapped MMIO blocks in debug builds and tests. The
+//!   regions owned by the types of this crate, such as `VolatileMmap` and
+//!   [`PciBar`], are recorded automatically.
+//! - `host-test`: Replaces the volatile reads and writes of the crate with
+//!   ordinary reads and writes, and the inline assembly of the device barriers
+//!   with sequentially consistent fences, so that driver logic can be tested with
+//!   `cargo test` or Miri on memory which stands in for the device. The
+//!   compiler may then merge or elide accesses, so this must not be enabled
+//!   for builds which access real devices.
+//! - `allow-zst`: Permits creating a [`Volatile`] of a zero-sized type, whose
+//!   reads and writes are no-ops, for generic code which may be instantiated
+//!   with such types.
+//!
 //! # Safety
 //! Typically, [`Volatile`] would be created from a raw pointer, which carries
 //! with it the typical [pointer safety concerns](core::ptr#safety). In
@@ -30,11 +121,35 @@
 //! - The memory must be properly aligned.
 //!
 //! - The memory must point to a properly initialized for the data type, unless
-//! the [`Volatile`] is [write-only](VolatileWriteOnly).
+//!   the [`Volatile`] is [write-only](VolatileWriteOnly).
 //!
 //! Note that even if the data has size zero, the pointer must be non-NULL and
 //! properly aligned.
 //!
+//! Volatile reads and writes of zero-sized types are no-ops, so code which
+//! appears to access a device through a zero-sized register type would silently
+//! do nothing. Creating a [`Volatile`] of a zero-sized type is therefore a
+//! compile-time error, unless the `allow-zst` feature is enabled.
+//!
+#![cfg_attr(not(feature = "allow-zst"), doc = "```compile_fail")]
+#![cfg_attr(feature = "allow-zst", doc = "```")]
+//! use volatile_mem::Volatile;
+//!
+//! let vol: Volatile<()> = Volatile::new(());
+//! ```
+//!
+//! This includes views of zero-sized parts of other memory, such as an empty
+//! array, or a zero-sized projection.
+//!
+#![cfg_attr(not(feature = "allow-zst"), doc = "```compile_fail")]
+#![cfg_attr(feature = "allow-zst", doc = "```")]
+//! use core::convert::TryFrom;
+//! use volatile_mem::Volatile;
+//!
+//! let mem = [0u32; 4];
+//! let vol = <&Volatile<[u32; 0]>>::try_from(&mem[..0]);
+//! ```
+//!
 //! Do not forget that even creating a reference to uninitialized data (even if
 //! that data is never used) is immediate undefined behavior. As such, do not at
 //! any point create a reference directly to uninitialized data (as opposed to a
@@ -69,10 +184,249 @@
 #![warn(clippy::todo)]
 #![warn(clippy::unimplemented)]
 #![warn(clippy::unwrap_used)]
-#![deny(safe_packed_borrows)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+mod access;
+pub mod barrier;
+#[cfg(all(feature = "bit-band", target_arch = "arm", target_os = "none"))]
+mod bitband;
+pub mod bitfield;
+#[cfg(feature = "bitflags")]
+mod bitflags;
+mod boolean;
+#[cfg(feature = "alloc")]
+mod boxed;
+#[cfg(feature = "alloc")]
+mod buf;
+pub mod cache;
+mod checksum;
+mod claim;
+pub mod compat;
+#[cfg(feature = "cortex-m")]
+mod cortex_m;
+mod counter;
+#[cfg(feature = "critical-section")]
+mod critical_section;
+#[cfg(feature = "embassy-time")]
+mod embassy_time;
+#[cfg(feature = "embedded-dma")]
+mod embedded_dma;
+#[cfg(feature = "embedded-io")]
+mod embedded_io;
+mod enum_reg;
+mod error;
+#[cfg(all(
+    feature = "arm-exclusive",
+    target_arch = "arm",
+    target_has_atomic = "32"
+))]
+mod exclusive;
+pub mod fence;
+mod fifo;
+#[cfg(feature = "async")]
+mod future;
+mod gated;
+mod lockable;
+mod macros;
+mod mailbox;
+pub mod memtest;
+#[cfg(all(feature = "std", any(unix, windows)))]
+mod mmap;
+#[cfg(feature = "nb")]
+mod nb;
+mod packed;
+mod pci;
+pub mod poll;
+#[cfg(all(feature = "port-io", any(target_arch = "x86", target_arch = "x86_64")))]
+mod port;
+#[cfg(feature = "portable-atomic")]
+mod portable_atomic;
+mod raw;
+mod region;
+#[cfg(all(feature = "region-registry", target_has_atomic = "8"))]
+pub mod registry;
+mod reset;
+pub mod ring;
+mod seqlock;
+mod shared;
+mod static_volatile;
+mod strided;
+mod sync;
+pub mod table;
+#[cfg(feature = "tock-registers")]
+pub mod tock;
+#[cfg(all(feature = "std", target_os = "linux"))]
+mod uio;
+#[cfg(feature = "vcell")]
+mod vcell;
+#[cfg(feature = "voladdress")]
+mod voladdress;
 mod volatile;
-pub use volatile::{Volatile, VolatileReadOnly, VolatileWriteOnly};
+#[cfg(feature = "volatile-register")]
+mod volatile_register;
+#[cfg(target_arch = "wasm32")]
+mod wasm;
+mod watch;
+#[cfg(all(feature = "x86_64", target_pointer_width = "64"))]
+mod x86_64;
+pub use boolean::VolatileBool;
+#[cfg(feature = "alloc")]
+pub use boxed::VolatileBox;
+#[cfg(feature = "alloc")]
+pub use buf::VolatileBuf;
+pub use checksum::VolatileChecksum;
+pub use counter::SplitCounter;
+pub use enum_reg::VolatileEnum;
+pub use error::{CStrError, InvalidValue, VolatileError};
+pub use fence::VolatileFenced;
+pub use fifo::{FmtWriter, VolatileFifo};
+pub use gated::StatusGated;
+pub use lockable::{Locked, VolatileLockable};
+pub use mailbox::Mailbox;
+#[cfg(all(feature = "std", any(unix, windows)))]
+pub use mmap::VolatileMmap;
+pub use packed::VolatilePacked;
+pub use pci::PciBar;
+pub use poll::Timeout;
+#[cfg(all(feature = "port-io", any(target_arch = "x86", target_arch = "x86_64")))]
+pub use port::{PortData, PortReadOnly, PortVolatile, PortWriteOnly};
+pub use raw::RawRepr;
+pub use region::VolatileRegion;
+pub use reset::ResetMismatch;
+pub use ring::DescriptorRing;
+pub use seqlock::SeqlockReader;
+pub use shared::VolatileShared;
+pub use static_volatile::StaticVolatile;
+pub use strided::VolatileStridedArray;
+pub use sync::SyncVolatile;
+#[cfg(all(feature = "std", target_os = "linux"))]
+pub use uio::{UioDevice, UioMapInfo};
+pub use volatile::{
+    Reserved, Volatile, VolatileReadClear, VolatileReadOnly, VolatileWriteOnce, VolatileWriteOnly,
+    WriteOnceCapability,
+};
+#[cfg(target_arch = "wasm32")]
+pub use wasm::WasmSharedRegion;
+pub use watch::{Edges, VolatileWatch};
+
+/// Derives per-field volatile accessors. The attributes used in the examples
+/// are described after them.
+///
+/// # Example
+/// ```
+/// use volatile_mem::{Volatile, VolatileFields, VolatileRead, VolatileWrite};
+///
+/// #[derive(Clone, Copy, VolatileFields)]
+/// #[repr(C)]
+/// struct Regs {
+///     /// The control register.
+///     #[volatile(reset = 0x10)]
+///     ctrl: u32,
+///     #[volatile(read_only, reset = 0)]
+///     status: u32,
+///     #[volatile(write_only)]
+///     data: u32,
+///     #[volatile(skip)]
+///     _reserved: u32,
+///     r#type: u32,
+/// }
+///
+/// let mut mem = Regs {
+///     ctrl: 0,
+///     status: 3,
+///     data: 0,
+///     _reserved: 0,
+///     r#type: 0,
+/// };
+/// let regs: &mut Volatile<Regs> = Volatile::from_mut(&mut mem);
+///
+/// regs.ctrl_mut().write(1);
+/// assert_eq!(regs.ctrl().read(), 1);
+/// assert_eq!(regs.status().read(), 3);
+/// regs.data_mut().write(0xab);
+/// regs.type_mut().write(2);
+/// assert_eq!(regs.r#type().read(), 2);
+///
+/// regs.reset_to_default();
+/// assert_eq!(regs.ctrl().read(), Regs::ctrl);
+/// assert_eq!(Regs::status, 0);
+/// assert_eq!(mem.data, 0xab);
+/// assert_eq!(mem.ctrl, 0x10);
+/// ```
+///
+/// A read-only field has no mutable accessor.
+/// ```compile_fail
+/// use volatile_mem::{Volatile, VolatileFields, VolatileWrite};
+///
+/// #[derive(Clone, Copy, VolatileFields)]
+/// #[repr(C)]
+/// struct Regs {
+///     #[volatile(read_only)]
+///     status: u32,
+/// }
+///
+/// let mut mem = Regs { status: 0 };
+/// let regs: &mut Volatile<Regs> = Volatile::from_mut(&mut mem);
+/// regs.status_mut().write(1);
+/// ```
+///
+/// Unknown attributes are rejected.
+/// ```compile_fail
+/// use volatile_mem::VolatileFields;
+///
+/// #[derive(Clone, Copy, VolatileFields)]
+/// #[repr(C)]
+/// struct Regs {
+///     #[volatile(read_write)]
+///     ctrl: u32,
+/// }
+/// ```
+#[cfg(feature = "derive")]
+pub use volatile_mem_derive::VolatileFields;
+/// Derives functions which read or write a whole struct one field at a time.
+/// The attributes used in the example are described after it.
+///
+/// # Example
+/// ```
+/// use volatile_mem::{Volatile, VolatileSnapshot};
+///
+/// #[derive(Clone, Copy, Debug, PartialEq, VolatileSnapshot)]
+/// #[repr(C)]
+/// struct Frame {
+///     id: u16,
+///     #[volatile(write_only)]
+///     cmd: u16,
+///     #[volatile(read_only)]
+///     status: u16,
+///     #[volatile(skip)]
+///     pad: u16,
+///     len: u32,
+/// }
+///
+/// let mut mem = Frame { id: 0, cmd: 0, status: 5, pad: 7, len: 0 };
+/// let vol: &mut Volatile<Frame> = Volatile::from_mut(&mut mem);
+///
+/// Frame::write_all(vol, &Frame { id: 1, cmd: 2, status: 3, pad: 4, len: 6 });
+/// // Write-only and skipped fields are not read, and are set to their default.
+/// assert_eq!(
+///     Frame::read_all(vol),
+///     Frame { id: 1, cmd: 0, status: 5, pad: 0, len: 6 }
+/// );
+/// // Read-only and skipped fields are not written.
+/// assert_eq!(mem, Frame { id: 1, cmd: 2, status: 5, pad: 7, len: 6 });
+/// ```
+#[cfg(feature = "derive")]
+pub use volatile_mem_derive::VolatileSnapshot;
+
+// Not public API. Used by macro-generated code.
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::volatile::{project, project_mut, Read, Write, WriteOnly};
+}
 
 /// A marker trait for volatile types.
 ///
@@ -98,6 +452,25 @@ where
     /// that regard. In particular, a race between a read operation any write
     /// operation to the same location is undefined behavior.
     fn read(&self) -> T;
+
+    /// Performs a volatile read of `self` and discards the value.
+    ///
+    /// This is for registers which are read only for the side effects of the
+    /// read, such as acknowledging an interrupt or advancing a FIFO, and makes
+    /// that intent explicit.
+    ///
+    /// # Example
+    /// ```
+    /// use volatile_mem::{Volatile, VolatileRead};
+    ///
+    /// let mut mem = 5u32;
+    /// let status = Volatile::<u32>::from_mut(&mut mem);
+    /// status.read_discard();
+    /// ```
+    #[inline]
+    fn read_discard(&self) {
+        let _ = self.read();
+    }
 }
 
 /// Volatile data which can be written.
@@ -161,6 +534,222 @@ where
             dst[i] = this[i].read();
         }
     }
+
+    /// Performs a volatile read of each element of `self` into the
+    /// uninitialized buffer `dst`, returning `dst` as an initialized slice.
+    ///
+    /// This avoids initializing a large buffer, such as a receive buffer, only
+    /// for it to be overwritten with the data from `self`.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the two slices have different lengths.
+    ///
+    /// # Example
+    /// ```
+    /// use core::mem::MaybeUninit;
+    /// use volatile_mem::{Volatile, VolatileReadSlice};
+    ///
+    /// let mut mem = [1u8, 2, 3];
+    /// let vol = Volatile::<[u8; 3]>::from_mut(&mut mem);
+    /// let mut buf = [MaybeUninit::uninit(); 3];
+    /// assert_eq!(vol.read_slice_uninit(&mut buf), [1, 2, 3]);
+    /// ```
+    fn read_slice_uninit<'b>(&self, dst: &'b mut [core::mem::MaybeUninit<U>]) -> &'b mut [U] {
+        let this = self.as_ref();
+        assert!(
+            this.len() == dst.len(),
+            "source slice length ({}) does not match destination slice length ({})",
+            this.len(),
+            dst.len()
+        );
+
+        for (src, dst) in this.iter().zip(dst.iter_mut()) {
+            dst.write(src.read());
+        }
+        // SAFETY: Every element of `dst` has been initialized, and
+        // `MaybeUninit<U>` has the same layout as `U`.
+        unsafe { &mut *(dst as *mut [core::mem::MaybeUninit<U>] as *mut [U]) }
+    }
+
+    /// Performs a volatile read of each element of `self` copying the data to
+    /// `dst`, like [`read_slice_volatile`](Self::read_slice_volatile), but
+    /// returns an error instead of panicking if the lengths differ.
+    ///
+    /// # Errors
+    /// Returns [`VolatileError::LengthMismatch`] if `dst` does not have the
+    /// same length as `self`, in which case nothing is read.
+    ///
+    /// # Example
+    /// ```
+    /// use volatile_mem::{Volatile, VolatileError, VolatileReadSlice};
+    ///
+    /// let mut mem = [1u8, 2, 3];
+    /// let vol = Volatile::<[u8; 3]>::from_mut(&mut mem);
+    /// let mut buf = [0; 4];
+    /// assert_eq!(
+    ///     vol.try_read_slice_volatile(&mut buf),
+    ///     Err(VolatileError::LengthMismatch { expected: 3, actual: 4 })
+    /// );
+    /// assert_eq!(vol.try_read_slice_volatile(&mut buf[..3]), Ok(()));
+    /// ```
+    fn try_read_slice_volatile(&self, dst: &mut [U]) -> Result<(), VolatileError> {
+        let this = self.as_ref();
+        if this.len() != dst.len() {
+            return Err(VolatileError::LengthMismatch {
+                expected: this.len(),
+                actual: dst.len(),
+            });
+        }
+        for (src, dst) in this.iter().zip(dst) {
+            *dst = src.read();
+        }
+        Ok(())
+    }
+
+    /// Performs a volatile read of the elements of `self` into each segment,
+    /// where each segment is a buffer and the offset in `self` of the elements
+    /// to copy into it.
+    ///
+    /// All segments are validated before any elements are read. The segments
+    /// may overlap in `self`.
+    ///
+    /// # Errors
+    /// Returns [`VolatileError::OutOfBounds`] if a segment is not entirely
+    /// within `self`, in which case nothing is read.
+    ///
+    /// # Example
+    /// ```
+    /// use volatile_mem::{Volatile, VolatileReadSlice};
+    ///
+    /// let mut mem = *b"HDRpayload";
+    /// let vol = Volatile::<[u8; 10]>::from_mut(&mut mem);
+    /// let (mut header, mut payload) = ([0; 3], [0; 7]);
+    /// vol.read_scatter(&mut [(0, &mut header[..]), (3, &mut payload[..])])?;
+    /// assert_eq!(&header, b"HDR");
+    /// assert_eq!(&payload, b"payload");
+    /// # Ok::<(), volatile_mem::VolatileError>(())
+    /// ```
+    fn read_scatter(&self, segments: &mut [(usize, &mut [U])]) -> Result<(), VolatileError> {
+        let this = self.as_ref();
+        check_segments(
+            this.len(),
+            segments.iter().map(|(offset, buf)| (*offset, buf.len())),
+            false,
+        )?;
+        for (offset, buf) in segments.iter_mut() {
+            (&this[*offset..*offset + buf.len()]).read_slice_volatile(buf);
+        }
+        Ok(())
+    }
+
+    /// Returns the index of the first element of `self` for which `pred`
+    /// returns `true`, or `None` if there is none.
+    ///
+    /// Each element is read with a volatile read, in order, until `pred`
+    /// returns `true`. No further elements are read.
+    ///
+    /// # Example
+    /// ```
+    /// use volatile_mem::{Volatile, VolatileReadSlice};
+    ///
+    /// let mut mem = [1u8, 3, 6, 7];
+    /// let vol = Volatile::<[u8; 4]>::from_mut(&mut mem);
+    /// assert_eq!(vol.position_volatile(|x| x % 2 == 0), Some(2));
+    /// assert!(vol.contains_volatile(&7));
+    /// assert!(vol.eq_slice_volatile(&[1, 3, 6, 7]));
+    /// ```
+    fn position_volatile(&self, mut pred: impl FnMut(U) -> bool) -> Option<usize> {
+        self.as_ref().iter().position(|elem| pred(elem.read()))
+    }
+
+    /// Returns `true` if an element of `self` equals `x`.
+    ///
+    /// Each element is read with a volatile read, in order, until one equal to
+    /// `x` is found.
+    fn contains_volatile(&self, x: &U) -> bool
+    where
+        U: PartialEq,
+    {
+        self.position_volatile(|elem| elem == *x).is_some()
+    }
+
+    /// Returns `true` if `self` has the same length as `other`, and each
+    /// element of `self` equals the corresponding element of `other`.
+    ///
+    /// Each element is read with a volatile read, in order, until one differs.
+    /// No elements are read if the lengths differ.
+    fn eq_slice_volatile(&self, other: &[U]) -> bool
+    where
+        U: PartialEq,
+    {
+        let this = self.as_ref();
+        this.len() == other.len() && this.iter().zip(other).all(|(elem, x)| elem.read() == *x)
+    }
+
+    /// Returns the index of the first occurrence of `needle` in `self`, or
+    /// `None` if it does not occur.
+    ///
+    /// This is intended for locating signatures, such as `b"RSD PTR "` or
+    /// `b"_SM_"`, in mapped firmware memory. Elements are read with volatile
+    /// reads, and an element may be read more than once, so this must not be
+    /// used on memory whose reads have side effects. An empty `needle` occurs
+    /// at index zero.
+    ///
+    /// # Example
+    /// ```
+    /// use volatile_mem::{Volatile, VolatileReadSlice};
+    ///
+    /// let mut mem = *b"....RSD PTR ....";
+    /// let vol = Volatile::<[u8; 16]>::from_mut(&mut mem);
+    /// assert_eq!(vol.find_pattern(b"RSD PTR "), Some(4));
+    /// assert_eq!(vol.find_pattern(b"_SM_"), None);
+    /// ```
+    fn find_pattern(&self, needle: &[U]) -> Option<usize>
+    where
+        U: PartialEq,
+    {
+        let this = self.as_ref();
+        let last = this.len().checked_sub(needle.len())?;
+        (0..=last).find(|&start| (&this[start..start + needle.len()]).eq_slice_volatile(needle))
+    }
+
+    /// Reads a NUL-terminated string from the start of `self` into `buf`,
+    /// returning the string without the NUL byte.
+    ///
+    /// Bytes are read with volatile reads, in order, up to and including the
+    /// first NUL byte, but not past the end of `self` or the length of `buf`.
+    /// This is intended for device banners, firmware version strings, and
+    /// SMBIOS strings.
+    ///
+    /// # Errors
+    /// Returns [`CStrError::Unterminated`] if no NUL byte is found before the
+    /// end of `self` or `buf`, or [`CStrError::Utf8`] if the string is not
+    /// valid UTF-8.
+    ///
+    /// # Example
+    /// ```
+    /// use volatile_mem::{Volatile, VolatileReadSlice};
+    ///
+    /// let mut mem = *b"v1.2\0junk";
+    /// let vol = Volatile::<[u8; 9]>::from_mut(&mut mem);
+    /// let mut buf = [0; 16];
+    /// assert_eq!(vol.read_cstr_into(&mut buf), Ok("v1.2"));
+    /// ```
+    fn read_cstr_into<'b>(&self, buf: &'b mut [u8]) -> Result<&'b str, CStrError>
+    where
+        T: VolatileRead<u8>,
+    {
+        let mut len = 0;
+        for (src, dst) in self.as_ref().iter().zip(buf.iter_mut()) {
+            match VolatileRead::<u8>::read(src) {
+                0 => return Ok(core::str::from_utf8(&buf[..len])?),
+                byte => *dst = byte,
+            }
+            len += 1;
+        }
+        Err(CStrError::Unterminated { len })
+    }
 }
 
 impl<S, T, U> VolatileReadSlice<T, U> for S
@@ -229,6 +818,86 @@ where
             this[i].write(src[i]);
         }
     }
+
+    /// Performs a volatile write of each element of `self`, copying the data
+    /// from `src`, like [`write_slice_volatile`](Self::write_slice_volatile),
+    /// but returns an error instead of panicking if the lengths differ.
+    ///
+    /// # Errors
+    /// Returns [`VolatileError::LengthMismatch`] if `src` does not have the
+    /// same length as `self`, in which case nothing is written.
+    fn try_write_slice_volatile(&mut self, src: &[U]) -> Result<(), VolatileError> {
+        let this = self.as_mut();
+        if this.len() != src.len() {
+            return Err(VolatileError::LengthMismatch {
+                expected: this.len(),
+                actual: src.len(),
+            });
+        }
+        for (dst, src) in this.iter_mut().zip(src) {
+            dst.write(*src);
+        }
+        Ok(())
+    }
+
+    /// Performs a volatile write of the elements of each segment into `self`,
+    /// where each segment is a buffer and the offset in `self` at which to
+    /// write it, such as to gather a packet from a header and a payload.
+    ///
+    /// All segments are validated before any elements are written, and are
+    /// written in order.
+    ///
+    /// # Errors
+    /// Returns [`VolatileError::OutOfBounds`] if a segment is not entirely
+    /// within `self`, or [`VolatileError::Overlap`] if two segments overlap in
+    /// `self`, in which case nothing is written.
+    ///
+    /// # Example
+    /// ```
+    /// use volatile_mem::{Volatile, VolatileRead, VolatileWriteSlice};
+    ///
+    /// let mut mem = [0; 10];
+    /// let vol = Volatile::<[u8; 10]>::from_mut(&mut mem);
+    /// vol.write_gather(&[(0, b"HDR"), (3, b"payload")])?;
+    /// assert_eq!(&vol.read(), b"HDRpayload");
+    /// # Ok::<(), volatile_mem::VolatileError>(())
+    /// ```
+    fn write_gather(&mut self, segments: &[(usize, &[U])]) -> Result<(), VolatileError> {
+        let this = self.as_mut();
+        check_segments(
+            this.len(),
+            segments.iter().map(|(offset, buf)| (*offset, buf.len())),
+            true,
+        )?;
+        for (offset, buf) in segments {
+            (&mut this[*offset..*offset + buf.len()]).write_slice_volatile(buf);
+        }
+        Ok(())
+    }
+
+    /// Performs a volatile write of each element of `self`, in order, with the
+    /// items of `iter`, without reading the old data from `self`.
+    ///
+    /// This stops when either `self` is full or `iter` is exhausted, and
+    /// returns the number of elements written. Any remaining items of `iter`
+    /// are not consumed.
+    ///
+    /// # Safety
+    /// Just like in C, whether an operation is volatile has no bearing
+    /// whatsoever on questions involving concurrent access from multiple
+    /// threads. Volatile accesses behave exactly like non-atomic accesses in
+    /// that regard. In particular, a race between a write operation any other
+    /// operation (reading or writing) to the same location is undefined
+    /// behavior.
+    fn write_from_iter(&mut self, iter: impl IntoIterator<Item = U>) -> usize {
+        let this = self.as_mut();
+        let mut count = 0;
+        for (elem, val) in this.iter_mut().zip(iter) {
+            elem.write(val);
+            count += 1;
+        }
+        count
+    }
 }
 
 impl<S, T, U> VolatileWriteSlice<T, U> for S
@@ -238,3 +907,25 @@ where
     U: Copy,
 {
 }
+
+/// Checks that each segment, as an offset and a length, is within a slice of
+/// length `size`, and optionally that no two segments overlap.
+fn check_segments(
+    size: usize,
+    segments: impl Iterator<Item = (usize, usize)> + Clone,
+    check_overlap: bool,
+) -> Result<(), VolatileError> {
+    for (i, (offset, len)) in segments.clone().enumerate() {
+        if offset > size || len > size - offset {
+            return Err(VolatileError::OutOfBounds { offset, len, size });
+        }
+        let overlaps = check_overlap
+            && segments.clone().take(i).any(|(other, other_len)| {
+                len != 0 && other_len != 0 && offset < other + other_len && other < offset + len
+            });
+        if overlaps {
+            return Err(VolatileError::Overlap { offset, len });
+        }
+    }
+    Ok(())
+}
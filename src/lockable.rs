@@ -0,0 +1,97 @@
+use core::{any::type_name, error, fmt};
+
+use crate::{bitfield::UInt, Volatile, VolatileRead, VolatileReadOnly, VolatileWrite};
+
+/// A register which can be locked against further writes, such as a clock
+/// configuration or flash option register.
+///
+/// The register may have lock bits, which are set by hardware or software to
+/// indicate that the register is locked. Writes through
+/// [`try_write`](Self::try_write) and [`try_modify`](Self::try_modify) check
+/// them first, and fail if any is set. Calling [`lock`](Self::lock) sets the
+/// lock bits and consumes the wrapper, returning a
+/// [read-only](VolatileReadOnly) view, so further writes are prevented at the
+/// type level.
+pub struct VolatileLockable<'a, T: UInt> {
+    vol: &'a mut Volatile<T>,
+    lock_mask: T,
+}
+
+impl<'a, T: UInt> VolatileLockable<'a, T> {
+    /// Wraps `vol`, which is locked when any of the bits in `lock_mask` is
+    /// set.
+    ///
+    /// If the register has no lock bits, `lock_mask` should be zero, in which
+    /// case it is only locked by calling [`lock`](Self::lock).
+    pub fn new(vol: &'a mut Volatile<T>, lock_mask: T) -> Self {
+        VolatileLockable { vol, lock_mask }
+    }
+
+    /// Performs a volatile read of the register.
+    pub fn read(&self) -> T {
+        self.vol.read()
+    }
+
+    /// Performs a volatile read of the register and returns `true` if any of
+    /// its lock bits is set.
+    pub fn is_locked(&self) -> bool {
+        self.read() & self.lock_mask != T::ZERO
+    }
+
+    /// Performs a volatile write of the register, unless it is locked.
+    ///
+    /// # Errors
+    /// Returns [`Locked`] without writing if any of the lock bits is set.
+    pub fn try_write(&mut self, val: T) -> Result<(), Locked> {
+        if self.is_locked() {
+            return Err(Locked);
+        }
+        self.vol.write(val);
+        Ok(())
+    }
+
+    /// Performs a volatile read of the register, passes the value to `f`, and
+    /// performs a volatile write of the result, unless it is locked.
+    ///
+    /// This is not atomic.
+    ///
+    /// # Errors
+    /// Returns [`Locked`] without writing if any of the lock bits is set.
+    pub fn try_modify(&mut self, f: impl FnOnce(T) -> T) -> Result<(), Locked> {
+        let value = self.read();
+        if value & self.lock_mask != T::ZERO {
+            return Err(Locked);
+        }
+        self.vol.write(f(value));
+        Ok(())
+    }
+
+    /// Locks the register by setting its lock bits, returning a read-only
+    /// view of it.
+    ///
+    /// If the register has no lock bits, nothing is written.
+    pub fn lock(self) -> &'a VolatileReadOnly<T> {
+        if self.lock_mask != T::ZERO {
+            self.vol.set_bits(self.lock_mask);
+        }
+        self.vol.as_read_only()
+    }
+}
+
+impl<T: UInt> fmt::Debug for VolatileLockable<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(type_name::<Self>())
+    }
+}
+
+/// An error indicating that a [`VolatileLockable`] register is locked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Locked;
+
+impl fmt::Display for Locked {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("register is locked")
+    }
+}
+
+impl error::Error for Locked {}
@@ -0,0 +1,541 @@
+/// Declares a `#[repr(C)]` register block of volatile fields.
+///
+/// Each field is given as `(offset => name: type, permission)`, where the
+/// permission is one of `rw`, `ro`, `wo`, `rc`, or `once`, generating a field of
+/// type [`Volatile`](crate::Volatile), [`VolatileReadOnly`](crate::VolatileReadOnly),
+/// [`VolatileWriteOnly`](crate::VolatileWriteOnly),
+/// [`VolatileReadClear`](crate::VolatileReadClear), or
+/// [`VolatileWriteOnce`](crate::VolatileWriteOnce), respectively. Fields
+/// may have attributes, including doc comments, and a visibility.
+///
+/// A register field may also be given a reset value, as
+/// `(offset => name: type, permission, reset = value)`. This generates an
+/// associated constant of the struct, with the same name and visibility as the
+/// field, holding the reset value. The generated `reset_to_default` method
+/// writes the reset value of each `rw` or `wo` field which has one, and the
+/// generated `compare_to_reset` method reads each `rw`, `ro`, or `once` field
+/// which has one, reporting each field which differs from its reset value as a
+/// [`ResetMismatch`](crate::ResetMismatch). The types of fields with reset
+/// values must implement `PartialEq` and `Debug`.
+///
+/// A field given as just `(offset => name)` is [`Reserved`](crate::Reserved),
+/// extending up to the offset of the next field. The last entry must be
+/// `(offset => @END)`, giving the total size of the struct.
+///
+/// The offset of every field, as well as the total size, is checked at compile
+/// time, so a struct which does not match its declaration fails to compile.
+///
+/// # Example
+/// ```
+/// volatile_mem::mmio_struct! {
+///     /// UART registers.
+///     pub struct Uart {
+///         /// Data register.
+///         (0x00 => pub data: u32, rw),
+///         /// Status register.
+///         (0x04 => pub status: u32, ro, reset = 0x60),
+///         (0x08 => _reserved0),
+///         /// Control register.
+///         (0x10 => pub control: u32, wo, reset = 0),
+///         (0x14 => @END),
+///     }
+/// }
+///
+/// assert_eq!(Uart::status, 0x60);
+/// ```
+#[macro_export]
+macro_rules! mmio_struct {
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $name:ident {
+            $($fields:tt)*
+        }
+    ) => {
+        $crate::mmio_struct!(@fields [$(#[$attr])* $vis struct $name] [] [] [] $($fields)*);
+    };
+
+    // Final entry: emit the struct, its reset values, and the layout checks.
+    (
+        @fields [$(#[$attr:meta])* $vis:vis struct $name:ident]
+        [$($field:tt)*]
+        [$(($foff:expr, $fname:ident))*]
+        [$(($rvis:vis $rname:ident: $rty:ty, $rperm:ident, $reset:expr))*]
+        ($end:expr => @END) $(,)?
+    ) => {
+        $(#[$attr])*
+        #[repr(C)]
+        $vis struct $name {
+            $($field)*
+        }
+
+        impl $name {
+            $(
+                #[doc = concat!("The reset value of `", stringify!($rname), "`.")]
+                #[allow(non_upper_case_globals)]
+                $rvis const $rname: $rty = $reset;
+            )*
+
+            /// Writes the reset value of each field which has one and which can
+            /// be written.
+            pub fn reset_to_default(&mut self) {
+                $($crate::mmio_struct!(@reset $rperm self.$rname, Self::$rname);)*
+            }
+
+            /// Reads each field which has a reset value and which can be read,
+            /// and calls `report` for each one whose value differs from its
+            /// reset value.
+            ///
+            /// Read-to-clear fields are not read.
+            #[allow(unused_mut, unused_variables)]
+            pub fn compare_to_reset(&self, mut report: impl FnMut($crate::ResetMismatch<'_>)) {
+                $($crate::mmio_struct!(@compare $rperm $name, $rname, self.$rname, report);)*
+            }
+        }
+
+        const _: () = {
+            $(
+                assert!(
+                    ::core::mem::offset_of!($name, $fname) == $foff,
+                    concat!(
+                        "offset of `",
+                        stringify!($name),
+                        "::",
+                        stringify!($fname),
+                        "` does not match its declaration"
+                    ),
+                );
+            )*
+            assert!(
+                ::core::mem::size_of::<$name>() == $end,
+                concat!("size of `", stringify!($name), "` does not match its declaration"),
+            );
+        };
+    };
+
+    // Reserved entry, extending to the offset of the next entry.
+    (
+        @fields $header:tt
+        [$($field:tt)*]
+        [$($check:tt)*]
+        $resets:tt
+        $(#[$fattr:meta])*
+        ($off:expr => $fname:ident),
+        $(#[$nattr:meta])*
+        ($next:expr => $($next_field:tt)*)
+        $($rest:tt)*
+    ) => {
+        $crate::mmio_struct!(
+            @fields $header
+            [$($field)* $(#[$fattr])* $fname: $crate::Reserved<{ $next - $off }>,]
+            [$($check)* ($off, $fname)]
+            $resets
+            $(#[$nattr])*
+            ($next => $($next_field)*)
+            $($rest)*
+        );
+    };
+
+    // Register entry.
+    (
+        @fields $header:tt
+        [$($field:tt)*]
+        [$($check:tt)*]
+        [$($reset:tt)*]
+        $(#[$fattr:meta])*
+        ($off:expr => $fvis:vis $fname:ident: $fty:ty, $perm:ident $(, reset = $rval:expr)?),
+        $($rest:tt)*
+    ) => {
+        $crate::mmio_struct!(
+            @fields $header
+            [$($field)* $(#[$fattr])* $fvis $fname: $crate::mmio_struct!(@perm $perm $fty),]
+            [$($check)* ($off, $fname)]
+            [$($reset)* $(($fvis $fname: $fty, $perm, $rval))?]
+            $($rest)*
+        );
+    };
+
+    (@perm rw $ty:ty) => { $crate::Volatile<$ty> };
+    (@perm ro $ty:ty) => { $crate::VolatileReadOnly<$ty> };
+    (@perm wo $ty:ty) => { $crate::VolatileWriteOnly<$ty> };
+    (@perm rc $ty:ty) => { $crate::VolatileReadClear<$ty> };
+    (@perm once $ty:ty) => { $crate::VolatileWriteOnce<$ty> };
+
+    (@reset rw $field:expr, $val:expr) => { $crate::VolatileWrite::write(&mut $field, $val) };
+    (@reset wo $field:expr, $val:expr) => { $crate::VolatileWrite::write(&mut $field, $val) };
+    (@reset $perm:ident $field:expr, $val:expr) => {};
+
+    (@compare rw $($args:tt)*) => { $crate::mmio_struct!(@compare_read $($args)*) };
+    (@compare ro $($args:tt)*) => { $crate::mmio_struct!(@compare_read $($args)*) };
+    (@compare once $($args:tt)*) => { $crate::mmio_struct!(@compare_read $($args)*) };
+    (@compare $perm:ident $($args:tt)*) => {};
+    (@compare_read $name:ident, $fname:ident, $field:expr, $report:ident) => {{
+        let value = $crate::VolatileRead::read(&$field);
+        if value != $name::$fname {
+            $report($crate::ResetMismatch {
+                name: stringify!($fname),
+                offset: ::core::mem::offset_of!($name, $fname),
+                value: &value,
+                reset: &$name::$fname,
+            });
+        }
+    }};
+}
+
+/// Asserts at compile time that the fields of a register struct have the given
+/// offsets, and optionally that the struct has the given size.
+///
+/// This is for structs declared without [`mmio_struct!`], which checks its
+/// own layout. The offsets are typically taken from a datasheet, so that a
+/// struct which is edited such that its layout no longer matches the hardware
+/// fails to compile, instead of silently accessing the wrong registers.
+///
+/// # Example
+/// ```
+/// use volatile_mem::assert_volatile_layout;
+///
+/// #[derive(Clone, Copy)]
+/// #[repr(C)]
+/// struct Uart {
+///     data: u32,
+///     status: u32,
+///     _reserved: [u8; 8],
+///     control: u32,
+/// }
+///
+/// assert_volatile_layout!(Uart, size = 0x14, {
+///     data: 0x00,
+///     status: 0x04,
+///     control: 0x10,
+/// });
+/// ```
+///
+/// A mismatch is a compile error:
+///
+/// ```compile_fail
+/// # use volatile_mem::assert_volatile_layout;
+/// #[repr(C)]
+/// struct Uart {
+///     data: u32,
+///     control: u32,
+/// }
+///
+/// assert_volatile_layout!(Uart, { control: 0x10 });
+/// ```
+#[macro_export]
+macro_rules! assert_volatile_layout {
+    ($ty:ty $(, size = $size:expr)?, { $($field:ident: $off:expr),* $(,)? } $(,)?) => {
+        const _: () = {
+            $(
+                assert!(
+                    ::core::mem::offset_of!($ty, $field) == $off,
+                    concat!(
+                        "offset of `",
+                        stringify!($ty),
+                        "::",
+                        stringify!($field),
+                        "` does not match the expected offset `",
+                        stringify!($off),
+                        "`"
+                    ),
+                );
+            )*
+            $(
+                assert!(
+                    ::core::mem::size_of::<$ty>() == $size,
+                    concat!(
+                        "size of `",
+                        stringify!($ty),
+                        "` does not match the expected size `",
+                        stringify!($size),
+                        "`"
+                    ),
+                );
+            )?
+        };
+    };
+}
+
+/// Projects a reference to a [`Volatile`](crate::Volatile) struct to one of its
+/// fields, without accessing the rest of the struct.
+///
+/// The first argument is a reference to a `Volatile<T, P>`, and the second is a
+/// path to a field of `T`, such as `.config.mode` or `.0`. The result is a
+/// `&Volatile<U, P>`, where `U` is the type of the field. If the first argument
+/// begins with `mut`, it must be a mutable reference, and the result is a
+/// `&mut Volatile<U, P>`.
+///
+/// The path must only name fields which are directly contained in `T`, and not
+/// fields reached through a reference. Fields of packed structs are not
+/// supported.
+///
+/// # Panics
+///
+/// The projection will panic if the field is not contained in `T`, such as
+/// when the path passes through a reference.
+///
+/// # Example
+/// ```
+/// use volatile_mem::{volatile_project, Volatile, VolatileRead, VolatileWrite};
+///
+/// #[derive(Clone, Copy)]
+/// struct Regs {
+///     data: u32,
+///     config: (u8, u8),
+/// }
+///
+/// let mut regs = Regs { data: 0, config: (1, 2) };
+/// let vol: &mut Volatile<Regs> = Volatile::from_mut(&mut regs);
+/// volatile_project!(mut vol, .data).write(3);
+/// assert_eq!(volatile_project!(vol, .config.1).read(), 2);
+/// ```
+#[macro_export]
+macro_rules! volatile_project {
+    (mut $vol:expr, $(. $field:tt)+) => {
+        match &mut *$vol {
+            // SAFETY: The closure returns the address of a field of its argument.
+            vol => unsafe {
+                $crate::__private::project_mut(vol, |ptr| {
+                    // Never executed, but ensures the field is properly aligned.
+                    if false {
+                        let _ = &(*ptr) $(. $field)+;
+                    }
+                    ::core::ptr::addr_of_mut!((*ptr) $(. $field)+)
+                })
+            },
+        }
+    };
+    ($vol:expr, $(. $field:tt)+) => {
+        match &*$vol {
+            // SAFETY: The closure returns the address of a field of its argument.
+            vol => unsafe {
+                $crate::__private::project(vol, |ptr| {
+                    // Never executed, but ensures the field is properly aligned.
+                    if false {
+                        let _ = &(*ptr) $(. $field)+;
+                    }
+                    ::core::ptr::addr_of!((*ptr) $(. $field)+)
+                })
+            },
+        }
+    };
+}
+
+/// Declares the bit fields of one or more integer registers.
+///
+/// The first argument is the integer type of the registers. Each register is
+/// then given as `name [fields]`, generating a module of that name. Each field
+/// is given as `NAME OFFSET(offset) NUMBITS(width) [values]`, generating a
+/// [`Field`](crate::bitfield::Field) constant, along with a module of
+/// [`FieldValue`](crate::bitfield::FieldValue) constants. The module contains
+/// `SET` and `CLEAR`, which set or clear every bit of the field, as well as a
+/// constant for each enumerated value given as `Name = value`.
+///
+/// By default, the value of a field has the type of the register. A different
+/// [`FieldType`](crate::bitfield::FieldType) may be given as `TYPE(type)`, after
+/// `NUMBITS`, in which case its size must match the width of the field. With
+/// the `arbitrary-int` feature, this allows types such as `u3` to be used,
+/// guaranteeing that values written to the field fit in it.
+///
+/// Registers, fields, and values may have attributes, including doc comments,
+/// and registers may have a visibility. The fields and values are used with
+/// [`Volatile::read_field`](crate::Volatile::read_field),
+/// [`Volatile::write_field`](crate::Volatile::write_field), and
+/// [`Volatile::modify_fields`](crate::Volatile::modify_fields).
+///
+/// Each field is checked at compile time, so a field which is empty or which
+/// does not fit in the register fails to compile.
+///
+/// # Example
+/// ```
+/// use volatile_mem::{register_bitfields, Volatile, VolatileRead};
+///
+/// register_bitfields! {
+///     u32,
+///     /// Control register.
+///     pub CONTROL [
+///         /// Enables the device.
+///         ENABLE OFFSET(0) NUMBITS(1) [],
+///         /// Operating mode.
+///         MODE OFFSET(4) NUMBITS(2) [
+///             Idle = 0,
+///             Run = 1,
+///             Sleep = 2,
+///         ],
+///     ],
+/// }
+///
+/// let mut reg = 0x100;
+/// let vol: &mut Volatile<u32> = Volatile::from_mut(&mut reg);
+/// vol.modify_fields(CONTROL::ENABLE::SET + CONTROL::MODE::Run);
+/// assert_eq!(vol.read(), 0x111);
+/// assert_eq!(vol.read_field(CONTROL::MODE), 1);
+/// assert!(vol.matches_fields(CONTROL::MODE::Run));
+/// ```
+#[macro_export]
+macro_rules! register_bitfields {
+    (
+        $ty:ty,
+        $(
+            $(#[$attr:meta])*
+            $vis:vis $reg:ident [
+                $(
+                    $(#[$fattr:meta])*
+                    $field:ident OFFSET($off:expr) NUMBITS($bits:expr) $(TYPE($vty:ty))? [
+                        $(
+                            $(#[$vattr:meta])*
+                            $value:ident = $val:expr
+                        ),* $(,)?
+                    ]
+                ),* $(,)?
+            ]
+        ),* $(,)?
+    ) => {
+        $(
+            $(#[$attr])*
+            #[allow(non_snake_case)]
+            $vis mod $reg {
+                #[allow(unused_imports)]
+                use super::*;
+
+                /// Identifies the fields of the register.
+                #[derive(Debug, Clone, Copy)]
+                pub struct Register;
+
+                $(
+                    $(#[$fattr])*
+                    pub const $field: $crate::bitfield::Field<
+                        $ty,
+                        Register,
+                        $crate::register_bitfields!(@type $ty $(, $vty)?),
+                    > = $crate::bitfield::Field::new(
+                        $crate::register_bitfields!(@mask $ty, $off, $bits),
+                        $off,
+                    );
+
+                    $(
+                        const _: () = assert!(
+                            <$vty as $crate::bitfield::FieldType<$ty>>::BITS == $bits,
+                            concat!(
+                                "the type of `",
+                                stringify!($field),
+                                "` does not match its width"
+                            ),
+                        );
+                    )?
+
+                    #[doc = concat!("Values of the `", stringify!($field), "` field.")]
+                    #[allow(non_upper_case_globals)]
+                    pub mod $field {
+                        #[allow(unused_imports)]
+                        use super::*;
+
+                        /// Sets every bit of the field.
+                        pub const SET: $crate::bitfield::FieldValue<$ty, super::Register> =
+                            $crate::bitfield::FieldValue::new(
+                                $crate::register_bitfields!(@mask $ty, $off, $bits) << $off,
+                                $crate::register_bitfields!(@mask $ty, $off, $bits) << $off,
+                            );
+
+                        /// Clears every bit of the field.
+                        pub const CLEAR: $crate::bitfield::FieldValue<$ty, super::Register> =
+                            $crate::bitfield::FieldValue::new(
+                                $crate::register_bitfields!(@mask $ty, $off, $bits) << $off,
+                                0,
+                            );
+
+                        $(
+                            $(#[$vattr])*
+                            pub const $value: $crate::bitfield::FieldValue<$ty, super::Register> =
+                                $crate::bitfield::FieldValue::new(
+                                    $crate::register_bitfields!(@mask $ty, $off, $bits) << $off,
+                                    ($val & $crate::register_bitfields!(@mask $ty, $off, $bits))
+                                        << $off,
+                                );
+                        )*
+                    }
+                )*
+            }
+        )*
+    };
+
+    (@type $ty:ty) => { $ty };
+    (@type $ty:ty, $vty:ty) => { $vty };
+
+    // The unshifted mask of a field, checking that it fits in the register.
+    (@mask $ty:ty, $off:expr, $bits:expr) => {{
+        const {
+            assert!($bits > 0, "a field must have at least one bit");
+            assert!(
+                $off < <$ty>::BITS && $bits <= <$ty>::BITS - $off,
+                "a field must fit in its register"
+            );
+        }
+        !(0 as $ty) >> (<$ty>::BITS - $bits)
+    }};
+}
+
+/// Declares statics of volatile memory, such as memory reserved in a special
+/// linker section, each of which hands out a single mutable reference to its
+/// memory.
+///
+/// Each static is given as `static NAME: Volatile<T> = value;`, which creates
+/// memory initialized to `value`, or as
+/// `static NAME: VolatileWriteOnly<T> = uninit;`, which creates uninitialized
+/// memory which can only be written. The static has type
+/// [`StaticVolatile`](crate::StaticVolatile), whose
+/// [`take`](crate::StaticVolatile::take) method returns the mutable reference
+/// the first time it is called.
+///
+/// Statics may have attributes, including doc comments and `#[link_section]`,
+/// and a visibility.
+///
+/// # Example
+/// ```
+/// use volatile_mem::{static_volatile, VolatileRead, VolatileWrite};
+///
+/// #[derive(Clone, Copy)]
+/// #[repr(C)]
+/// struct Mailbox {
+///     command: u32,
+///     response: u32,
+/// }
+///
+/// static_volatile! {
+///     /// Memory shared with another core.
+///     #[cfg_attr(target_os = "none", link_section = ".shared_mem")]
+///     pub static MAILBOX: Volatile<Mailbox> = Mailbox { command: 0, response: 0 };
+///
+///     /// Buffers filled by a DMA engine.
+///     #[cfg_attr(target_os = "none", link_section = ".dma_buffers")]
+///     static DMA_BUFFER: VolatileWriteOnly<[u8; 512]> = uninit;
+/// }
+///
+/// let dma_buffer = DMA_BUFFER.take().unwrap();
+/// dma_buffer.write([0; 512]);
+/// assert_eq!(MAILBOX.take().unwrap().read().response, 0);
+/// ```
+#[macro_export]
+macro_rules! static_volatile {
+    () => {};
+    (
+        $(#[$attr:meta])*
+        $vis:vis static $name:ident: VolatileWriteOnly<$t:ty> = uninit;
+        $($rest:tt)*
+    ) => {
+        $(#[$attr])*
+        $vis static $name: $crate::StaticVolatile<$t, $crate::__private::WriteOnly> =
+            $crate::StaticVolatile::uninit();
+
+        $crate::static_volatile!($($rest)*);
+    };
+    (
+        $(#[$attr:meta])*
+        $vis:vis static $name:ident: Volatile<$t:ty> = $init:expr;
+        $($rest:tt)*
+    ) => {
+        $(#[$attr])*
+        $vis static $name: $crate::StaticVolatile<$t> = $crate::StaticVolatile::new($init);
+
+        $crate::static_volatile!($($rest)*);
+    };
+}
@@ -0,0 +1,114 @@
+use core::{any::type_name, fmt, hint};
+
+use crate::{
+    barrier,
+    volatile::{Read, Write},
+    Volatile, VolatileRead, VolatileReadOnly, VolatileWrite, VolatileWriteOnly,
+};
+
+/// A request/response mailbox in volatile memory, such as for communicating
+/// with firmware running on another processor.
+///
+/// A request is made by writing a command of type `C`, then ringing the
+/// doorbell by writing a value of type `D` to the doorbell register. When the
+/// status register, of type `S`, indicates that the request is complete, the
+/// response of type `R` is read.
+///
+/// A [device write barrier](barrier::device_write_barrier) is issued between
+/// writing the command and ringing the doorbell, and a
+/// [device read barrier](barrier::device_read_barrier) is issued between
+/// reading the status and reading the response.
+///
+/// # Example
+/// ```
+/// use volatile_mem::{Mailbox, Volatile};
+///
+/// let mut command = 0u32;
+/// let mut doorbell = 0u8;
+/// let status = 1u8;
+/// let response = 42u32;
+///
+/// let mut mailbox: Mailbox<u32, u32, u8, u8> = Mailbox::new(
+///     Volatile::<_>::from_mut(&mut command),
+///     Volatile::<_>::from_mut(&mut doorbell),
+///     1,
+///     Volatile::<_>::from_ref(&status),
+///     |status| status & 1 != 0,
+///     Volatile::<_>::from_ref(&response),
+/// );
+/// assert_eq!(mailbox.call(7), 42);
+/// ```
+pub struct Mailbox<'a, C: Copy, R: Copy, D: Copy, S: Copy> {
+    command: &'a mut VolatileWriteOnly<C>,
+    doorbell: &'a mut VolatileWriteOnly<D>,
+    ring: D,
+    status: &'a VolatileReadOnly<S>,
+    is_done: fn(S) -> bool,
+    response: &'a VolatileReadOnly<R>,
+}
+
+impl<'a, C: Copy, R: Copy, D: Copy, S: Copy> Mailbox<'a, C, R, D, S> {
+    /// Creates a mailbox from its registers.
+    ///
+    /// The doorbell is rung by writing `ring` to `doorbell`, and a request is
+    /// complete when `is_done` returns `true` for the value read from
+    /// `status`.
+    pub fn new<PC: Write, PD: Write, PS: Read, PR: Read>(
+        command: &'a mut Volatile<C, PC>,
+        doorbell: &'a mut Volatile<D, PD>,
+        ring: D,
+        status: &'a Volatile<S, PS>,
+        is_done: fn(S) -> bool,
+        response: &'a Volatile<R, PR>,
+    ) -> Self {
+        Mailbox {
+            command: command.as_write_only(),
+            doorbell: doorbell.as_write_only(),
+            ring,
+            status: status.as_read_only(),
+            is_done,
+            response: response.as_read_only(),
+        }
+    }
+
+    /// Writes `cmd` to the command register, then rings the doorbell, without
+    /// waiting for the request to complete.
+    pub fn submit(&mut self, cmd: C) {
+        self.command.write(cmd);
+        barrier::device_write_barrier();
+        self.doorbell.write(self.ring);
+    }
+
+    /// Reads the status register, and if the request is complete, reads the
+    /// response.
+    ///
+    /// Returns `None` if the request is not complete.
+    pub fn poll(&self) -> Option<R> {
+        if !(self.is_done)(self.status.read()) {
+            return None;
+        }
+        barrier::device_read_barrier();
+        Some(self.response.read())
+    }
+
+    /// Submits `cmd`, then waits for the request to complete and returns the
+    /// response.
+    ///
+    /// This spins until the status register indicates that the request is
+    /// complete, which may be forever.
+    pub fn call(&mut self, cmd: C) -> R {
+        self.submit(cmd);
+        loop {
+            if let Some(response) = self.poll() {
+                return response;
+            }
+            hint::spin_loop();
+        }
+    }
+}
+
+impl<C: Copy, R: Copy, D: Copy, S: Copy> fmt::Debug for Mailbox<'_, C, R, D, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(type_name::<Self>())
+    }
+}
@@ -0,0 +1,206 @@
+//! Standard tests of RAM, for bootloaders and production-test firmware.
+//!
+//! Each test writes patterns to a slice of volatile words and reads them back,
+//! stopping at the first word which does not hold the expected value, which is
+//! returned as a [`MemTestFailure`] giving its address and the lowest bit which
+//! differs.
+//!
+//! - [`walking_ones`] and [`walking_zeros`] write each single-bit pattern, or
+//!   its inverse, to each word in turn, detecting stuck and shorted data bits.
+//! - [`checkerboard`] fills the memory with alternating bits, inverted from
+//!   one word to the next, and then with the inverse, detecting shorts between
+//!   adjacent cells.
+//! - [`march_c_minus`] performs the March C- algorithm, which detects stuck-at,
+//!   transition, address decoder, and most coupling faults in linear time.
+//!
+//! All of the tests overwrite the previous contents of the memory. They should
+//! not be run on memory in use, such as the stack, and interrupts which access
+//! the memory should be disabled.
+//!
+//! # Example
+//! ```
+//! use volatile_mem::{memtest, Volatile};
+//!
+//! let mut mem = [0u32; 64];
+//! let vol = Volatile::<u32>::from_slice_mut(&mut mem);
+//! memtest::walking_ones(vol)?;
+//! memtest::checkerboard(vol)?;
+//! memtest::march_c_minus(vol)?;
+//! # Ok::<(), memtest::MemTestFailure<u32>>(())
+//! ```
+use core::{error, fmt};
+
+use crate::{
+    bitfield::UInt,
+    volatile::{Read, Write},
+    Volatile, VolatileRead, VolatileWrite,
+};
+
+/// The first word found not to hold the expected value by a memory test.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MemTestFailure<T> {
+    /// The address of the word.
+    pub addr: usize,
+    /// The index of the word in the tested slice.
+    pub index: usize,
+    /// The lowest bit of the word which differs from the expected value.
+    pub bit: u32,
+    /// The value expected to be read.
+    pub expected: T,
+    /// The value actually read.
+    pub actual: T,
+}
+
+impl<T: UInt> fmt::Display for MemTestFailure<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "memory test failed at {:#x}, bit {}: expected {:?}, read {:?}",
+            self.addr, self.bit, self.expected, self.actual
+        )
+    }
+}
+
+impl<T: UInt> error::Error for MemTestFailure<T> {}
+
+/// Writes a single set bit to each word in turn, for each bit of the word, and
+/// checks that it reads back unchanged.
+///
+/// # Errors
+/// Returns a [`MemTestFailure`] for the first word which reads back a different
+/// value.
+pub fn walking_ones<T: UInt, P: Read + Write>(
+    mem: &mut [Volatile<T, P>],
+) -> Result<(), MemTestFailure<T>> {
+    walk(mem, |pattern| pattern)
+}
+
+/// Writes a single clear bit to each word in turn, for each bit of the word,
+/// and checks that it reads back unchanged.
+///
+/// # Errors
+/// Returns a [`MemTestFailure`] for the first word which reads back a different
+/// value.
+pub fn walking_zeros<T: UInt, P: Read + Write>(
+    mem: &mut [Volatile<T, P>],
+) -> Result<(), MemTestFailure<T>> {
+    walk(mem, |pattern| !pattern)
+}
+
+/// Fills the memory with alternating bits, inverted from one word to the next,
+/// checks it, and then does the same with the inverse pattern.
+///
+/// # Errors
+/// Returns a [`MemTestFailure`] for the first word which reads back a different
+/// value.
+pub fn checkerboard<T: UInt, P: Read + Write>(
+    mem: &mut [Volatile<T, P>],
+) -> Result<(), MemTestFailure<T>> {
+    let mut alternating = T::ZERO;
+    for bit in (0..T::BITS).step_by(2) {
+        alternating = alternating | one::<T>() << bit;
+    }
+    for &first in &[alternating, !alternating] {
+        let pattern = |index: usize| {
+            if index.is_multiple_of(2) {
+                first
+            } else {
+                !first
+            }
+        };
+        for (index, word) in mem.iter_mut().enumerate() {
+            word.write(pattern(index));
+        }
+        for (index, word) in mem.iter().enumerate() {
+            check(word, index, pattern(index))?;
+        }
+    }
+    Ok(())
+}
+
+/// Performs the March C- algorithm, using words of all zeros and all ones.
+///
+/// The algorithm consists of the following six march elements, where `⇑`
+/// visits the words in ascending order, `⇓` visits them in descending order,
+/// and each read checks the value of the word.
+///
+/// ```text
+/// ⇑(w0); ⇑(r0, w1); ⇑(r1, w0); ⇓(r0, w1); ⇓(r1, w0); ⇑(r0)
+/// ```
+///
+/// # Errors
+/// Returns a [`MemTestFailure`] for the first word which reads back a different
+/// value.
+pub fn march_c_minus<T: UInt, P: Read + Write>(
+    mem: &mut [Volatile<T, P>],
+) -> Result<(), MemTestFailure<T>> {
+    let zero = T::ZERO;
+    let ones = !T::ZERO;
+    for word in mem.iter_mut() {
+        word.write(zero);
+    }
+    for (index, word) in mem.iter_mut().enumerate() {
+        check(word, index, zero)?;
+        word.write(ones);
+    }
+    for (index, word) in mem.iter_mut().enumerate() {
+        check(word, index, ones)?;
+        word.write(zero);
+    }
+    for (index, word) in mem.iter_mut().enumerate().rev() {
+        check(word, index, zero)?;
+        word.write(ones);
+    }
+    for (index, word) in mem.iter_mut().enumerate().rev() {
+        check(word, index, ones)?;
+        word.write(zero);
+    }
+    for (index, word) in mem.iter().enumerate() {
+        check(word, index, zero)?;
+    }
+    Ok(())
+}
+
+/// Writes each single-bit pattern, transformed by `f`, to each word, and checks
+/// that it reads back unchanged.
+fn walk<T: UInt, P: Read + Write>(
+    mem: &mut [Volatile<T, P>],
+    f: impl Fn(T) -> T,
+) -> Result<(), MemTestFailure<T>> {
+    for (index, word) in mem.iter_mut().enumerate() {
+        for bit in 0..T::BITS {
+            let pattern = f(one::<T>() << bit);
+            word.write(pattern);
+            check(word, index, pattern)?;
+        }
+    }
+    Ok(())
+}
+
+/// Reads `word`, returning a [`MemTestFailure`] if it is not `expected`.
+fn check<T: UInt, P: Read>(
+    word: &Volatile<T, P>,
+    index: usize,
+    expected: T,
+) -> Result<(), MemTestFailure<T>> {
+    let actual = word.read();
+    if actual == expected {
+        return Ok(());
+    }
+    let diff = actual ^ expected;
+    let bit = (0..T::BITS)
+        .find(|&bit| diff >> bit & one::<T>() != T::ZERO)
+        .unwrap_or(0);
+    Err(MemTestFailure {
+        addr: word.addr(),
+        index,
+        bit,
+        expected,
+        actual,
+    })
+}
+
+/// Returns the value one.
+fn one<T: UInt>() -> T {
+    !(!T::ZERO << 1)
+}
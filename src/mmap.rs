@@ -0,0 +1,155 @@
+use core::{
+    any::type_name,
+    convert::TryFrom,
+    fmt,
+    ops::{Deref, DerefMut},
+    ptr::NonNull,
+    slice,
+};
+use std::io;
+
+use crate::{claim::Claim, Volatile};
+
+#[cfg(unix)]
+mod unix;
+#[cfg(windows)]
+mod windows;
+
+#[cfg(unix)]
+use unix as sys;
+#[cfg(windows)]
+use windows as sys;
+
+/// A shared memory mapping of a file, a named shared memory object, or
+/// anonymous memory, accessed as a slice of volatile bytes.
+///
+/// On Unix, named objects are POSIX shared memory objects, and on Windows, they
+/// are named file mapping objects. The constructors have the same names and
+/// signatures on both.
+///
+/// This allows host tools to access memory shared with another process, or a
+/// device exposed through a file, with the same code used by firmware to
+/// access the memory directly. The mapping dereferences to a slice of
+/// [`Volatile<u8>`](Volatile), and fixed-size views of it can be obtained with
+/// [`array`](Self::array) and [`array_mut`](Self::array_mut), or with
+/// [`project_at`](Volatile::project_at). The memory is unmapped when the
+/// mapping is dropped.
+///
+/// # Example
+/// ```
+/// use volatile_mem::{VolatileMmap, VolatileRead, VolatileWrite};
+///
+/// let mut map = VolatileMmap::anonymous(4096)?;
+/// map.array_mut::<4>(8).unwrap().write(*b"ping");
+/// assert_eq!(map[9].read(), b'i');
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub struct VolatileMmap {
+    ptr: NonNull<u8>,
+    len: usize,
+    // The offset at which the accessible bytes start, for mappings of memory
+    // which does not start on a page boundary.
+    offset: usize,
+    claim: Claim,
+}
+
+impl VolatileMmap {
+    /// Takes ownership of the `len` bytes mapped at `ptr`, and claims them.
+    ///
+    /// If they cannot be claimed, they are unmapped.
+    fn from_raw(ptr: NonNull<u8>, len: usize) -> io::Result<Self> {
+        let mut map = VolatileMmap {
+            ptr,
+            len,
+            offset: 0,
+            claim: Claim::new(0, 0),
+        };
+        map.claim = Claim::try_new(ptr.as_ptr().addr(), len)?;
+        Ok(map)
+    }
+
+    /// Makes the first `offset` bytes of the mapping inaccessible, while still
+    /// unmapping them when the mapping is dropped.
+    #[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+    pub(crate) fn skip(mut self, offset: usize) -> Self {
+        assert!(offset <= self.len());
+        self.offset += offset;
+        self
+    }
+
+    /// Returns the length of the mapping in bytes.
+    pub fn len(&self) -> usize {
+        self.len - self.offset
+    }
+
+    /// Returns `true` if the mapping has a length of zero.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns a pointer to the start of the mapping.
+    pub fn as_ptr(&self) -> *mut u8 {
+        self.ptr.as_ptr().wrapping_add(self.offset)
+    }
+
+    /// Returns a reference to the `N` bytes starting `offset` bytes into the
+    /// mapping, or `None` if they are not all within the mapping.
+    pub fn array<const N: usize>(&self, offset: usize) -> Option<&Volatile<[u8; N]>> {
+        let bytes = self.get(offset..offset.checked_add(N)?)?;
+        <&Volatile<[u8; N]>>::try_from(bytes).ok()
+    }
+
+    /// Returns a mutable reference to the `N` bytes starting `offset` bytes
+    /// into the mapping, or `None` if they are not all within the mapping.
+    pub fn array_mut<const N: usize>(&mut self, offset: usize) -> Option<&mut Volatile<[u8; N]>> {
+        let bytes = self.get_mut(offset..offset.checked_add(N)?)?;
+        <&mut Volatile<[u8; N]>>::try_from(bytes).ok()
+    }
+}
+
+impl Deref for VolatileMmap {
+    type Target = [Volatile<u8>];
+
+    fn deref(&self) -> &[Volatile<u8>] {
+        // SAFETY: The memory is mapped for reading and writing, and is owned by
+        // `self`. Every byte of a mapping is initialized.
+        unsafe { slice::from_raw_parts(self.as_ptr() as *const Volatile<u8>, self.len()) }
+    }
+}
+
+impl DerefMut for VolatileMmap {
+    fn deref_mut(&mut self) -> &mut [Volatile<u8>] {
+        // SAFETY: The memory is mapped for reading and writing, and is owned by
+        // `self`. Every byte of a mapping is initialized.
+        unsafe { slice::from_raw_parts_mut(self.as_ptr() as *mut Volatile<u8>, self.len()) }
+    }
+}
+
+impl Drop for VolatileMmap {
+    fn drop(&mut self) {
+        self.claim.release();
+        if self.len != 0 {
+            // SAFETY: The memory was mapped by `self`, and is no longer
+            // borrowed.
+            unsafe { sys::unmap(self.ptr, self.len) };
+        }
+    }
+}
+
+// SAFETY: The mapping is owned by `VolatileMmap`, and its bytes may be sent
+// between threads.
+unsafe impl Send for VolatileMmap {}
+// SAFETY: Shared access only permits reads of the bytes.
+unsafe impl Sync for VolatileMmap {}
+
+impl fmt::Debug for VolatileMmap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {{ {:p}, {:#x} }}",
+            type_name::<Self>(),
+            self.as_ptr(),
+            self.len()
+        )
+    }
+}
@@ -0,0 +1,159 @@
+use core::{
+    convert::TryFrom,
+    ffi::c_void,
+    ptr::{self, NonNull},
+};
+use std::{
+    ffi::CString,
+    fs::File,
+    io,
+    os::unix::io::{AsRawFd, FromRawFd},
+};
+
+use super::VolatileMmap;
+
+use libc::{
+    c_int, c_uint, off_t, MAP_ANONYMOUS, MAP_SHARED, O_CREAT, O_RDWR, PROT_READ, PROT_WRITE,
+};
+
+impl VolatileMmap {
+    /// Maps `len` bytes of anonymous memory, initialized to zero, which is
+    /// shared with child processes.
+    ///
+    /// # Errors
+    /// Returns the error reported by the operating system if the memory cannot
+    /// be mapped.
+    pub fn anonymous(len: usize) -> io::Result<Self> {
+        // SAFETY: Anonymous memory is not shared with anything else which
+        // could violate Rust's aliasing rules.
+        unsafe { Self::map(-1, MAP_SHARED | MAP_ANONYMOUS, 0, len) }
+    }
+
+    /// Maps `len` bytes of `file`, starting `offset` bytes into the file, such
+    /// that writes are visible to other processes mapping the same file.
+    ///
+    /// `offset` must be a multiple of the page size, and `file` must be open
+    /// for reading and writing.
+    ///
+    /// # Errors
+    /// Returns the error reported by the operating system if the file cannot
+    /// be mapped.
+    ///
+    /// # Safety
+    /// The mapped part of the file must not be truncated while the mapping
+    /// exists, since accessing the memory would then raise a signal. The file
+    /// may otherwise be modified by other processes, since the memory is only
+    /// accessed volatilely.
+    pub unsafe fn map_file(file: &File, offset: u64, len: usize) -> io::Result<Self> {
+        let offset = off_t::try_from(offset)
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "offset is too large"))?;
+        // SAFETY: The caller must ensure the file is not truncated.
+        unsafe { Self::map(file.as_raw_fd(), MAP_SHARED, offset, len) }
+    }
+
+    /// Opens the POSIX shared memory object called `name`, creating it if it
+    /// does not exist, and maps its first `len` bytes, such that writes are
+    /// visible to other processes mapping the same object.
+    ///
+    /// `name` should begin with a slash, such as `"/my-device"`. If the object
+    /// is smaller than `len` bytes, it is extended with zeros, so the peer
+    /// which opens it first determines its initial contents. A new object may
+    /// be read and written by the current user only.
+    ///
+    /// # Errors
+    /// Returns an error if `name` contains a NUL byte, or the error reported by
+    /// the operating system if the object cannot be opened, extended, or
+    /// mapped.
+    ///
+    /// # Safety
+    /// The object must not be truncated by another process while the mapping
+    /// exists, since accessing the memory would then raise a signal.
+    ///
+    /// # Example
+    /// ```
+    /// use volatile_mem::{VolatileMmap, VolatileRead, VolatileWrite};
+    ///
+    /// let name = "/volatile-mem-doctest";
+    /// let mut writer = unsafe { VolatileMmap::shm_open(name, 64)? };
+    /// let reader = unsafe { VolatileMmap::shm_open(name, 64)? };
+    /// writer[0].write(42);
+    /// assert_eq!(reader[0].read(), 42);
+    /// VolatileMmap::shm_unlink(name)?;
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub unsafe fn shm_open(name: &str, len: usize) -> io::Result<Self> {
+        let name = shm_name(name)?;
+        // SAFETY: `name` is a valid C string, and the mode is passed as an
+        // `unsigned int`, as required for variadic arguments.
+        let fd = unsafe { libc::shm_open(name.as_ptr(), O_RDWR | O_CREAT, 0o600 as c_uint) };
+        if fd < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        // SAFETY: `fd` is a newly opened file descriptor, which is owned by
+        // `file` and closed when it is dropped.
+        let file = unsafe { File::from_raw_fd(fd) };
+        let len64 = u64::try_from(len)
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "length is too large"))?;
+        if file.metadata()?.len() < len64 {
+            file.set_len(len64)?;
+        }
+        // SAFETY: The caller must ensure the object is not truncated.
+        unsafe { Self::map_file(&file, 0, len) }
+    }
+
+    /// Removes the name of the POSIX shared memory object called `name`.
+    ///
+    /// Existing mappings of the object remain valid, and it is destroyed once
+    /// they have all been dropped.
+    ///
+    /// # Errors
+    /// Returns an error if `name` contains a NUL byte, or the error reported by
+    /// the operating system if the name cannot be removed.
+    pub fn shm_unlink(name: &str) -> io::Result<()> {
+        let name = shm_name(name)?;
+        // SAFETY: `name` is a valid C string.
+        if unsafe { libc::shm_unlink(name.as_ptr()) } < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(())
+    }
+
+    unsafe fn map(fd: c_int, flags: c_int, offset: off_t, len: usize) -> io::Result<Self> {
+        if len == 0 {
+            return VolatileMmap::from_raw(NonNull::dangling(), len);
+        }
+        // SAFETY: This creates a new mapping, which does not replace any
+        // existing memory, since no address is requested.
+        let ptr = unsafe {
+            libc::mmap(
+                ptr::null_mut(),
+                len,
+                PROT_READ | PROT_WRITE,
+                flags,
+                fd,
+                offset,
+            )
+        };
+        if ptr == libc::MAP_FAILED {
+            return Err(io::Error::last_os_error());
+        }
+        match NonNull::new(ptr as *mut u8) {
+            Some(ptr) => VolatileMmap::from_raw(ptr, len),
+            None => Err(io::Error::other("memory was mapped at NULL")),
+        }
+    }
+}
+
+fn shm_name(name: &str) -> io::Result<CString> {
+    CString::new(name).map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "name contains NUL"))
+}
+
+/// Unmaps the `len` bytes of memory at `ptr`.
+///
+/// # Safety
+/// The memory must have been mapped by [`VolatileMmap`], and must not be used
+/// afterwards.
+pub(super) unsafe fn unmap(ptr: NonNull<u8>, len: usize) {
+    // SAFETY: The caller must ensure the memory was mapped and is unused.
+    unsafe { libc::munmap(ptr.as_ptr() as *mut c_void, len) };
+}
@@ -0,0 +1,172 @@
+use core::{
+    ffi::c_void,
+    ptr::{self, NonNull},
+};
+use std::{fs::File, io, os::windows::io::AsRawHandle, vec::Vec};
+
+use super::VolatileMmap;
+
+type Handle = *mut c_void;
+
+const INVALID_HANDLE_VALUE: Handle = -1isize as Handle;
+const PAGE_READWRITE: u32 = 0x04;
+const FILE_MAP_WRITE: u32 = 0x02;
+const FILE_MAP_READ: u32 = 0x04;
+
+#[link(name = "kernel32")]
+extern "system" {
+    fn CreateFileMappingW(
+        file: Handle,
+        attributes: *mut c_void,
+        protect: u32,
+        maximum_size_high: u32,
+        maximum_size_low: u32,
+        name: *const u16,
+    ) -> Handle;
+    fn MapViewOfFile(
+        mapping: Handle,
+        desired_access: u32,
+        file_offset_high: u32,
+        file_offset_low: u32,
+        number_of_bytes_to_map: usize,
+    ) -> *mut c_void;
+    fn UnmapViewOfFile(base_address: *const c_void) -> i32;
+    fn CloseHandle(object: Handle) -> i32;
+}
+
+impl VolatileMmap {
+    /// Maps `len` bytes of anonymous memory, backed by the paging file and
+    /// initialized to zero.
+    ///
+    /// # Errors
+    /// Returns the error reported by the operating system if the memory cannot
+    /// be mapped.
+    pub fn anonymous(len: usize) -> io::Result<Self> {
+        // SAFETY: Anonymous memory is not shared with anything else which
+        // could violate Rust's aliasing rules.
+        unsafe { Self::map(INVALID_HANDLE_VALUE, len as u64, ptr::null(), 0, len) }
+    }
+
+    /// Maps `len` bytes of `file`, starting `offset` bytes into the file, such
+    /// that writes are visible to other processes mapping the same file.
+    ///
+    /// `offset` must be a multiple of the allocation granularity, which is
+    /// typically 64 KiB, and `file` must be open for reading and writing. The
+    /// file is extended if it is shorter than `offset + len` bytes.
+    ///
+    /// # Errors
+    /// Returns the error reported by the operating system if the file cannot
+    /// be mapped.
+    ///
+    /// # Safety
+    /// The mapped part of the file must not be truncated while the mapping
+    /// exists. The file may otherwise be modified by other processes, since
+    /// the memory is only accessed volatilely.
+    pub unsafe fn map_file(file: &File, offset: u64, len: usize) -> io::Result<Self> {
+        let size = offset
+            .checked_add(len as u64)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "offset is too large"))?;
+        // SAFETY: The caller must ensure the file is not truncated.
+        unsafe { Self::map(file.as_raw_handle(), size, ptr::null(), offset, len) }
+    }
+
+    /// Opens the named file mapping object called `name`, creating it if it
+    /// does not exist, and maps its first `len` bytes, such that writes are
+    /// visible to other processes mapping the same object.
+    ///
+    /// This is the Windows counterpart of the Unix method of the same name.
+    /// The object is backed by the paging file, and a new object is
+    /// initialized to zero. `name` may have a `Local\` or `Global\` prefix,
+    /// and may otherwise not contain a backslash.
+    ///
+    /// # Errors
+    /// Returns an error if `name` contains a NUL character, or the error
+    /// reported by the operating system if the object cannot be opened or
+    /// mapped.
+    ///
+    /// # Safety
+    /// If the object already exists, it must be at least `len` bytes long.
+    pub unsafe fn shm_open(name: &str, len: usize) -> io::Result<Self> {
+        let name = mapping_name(name)?;
+        // SAFETY: `name` is a valid wide string, and the caller must ensure an
+        // existing object is large enough.
+        unsafe { Self::map(INVALID_HANDLE_VALUE, len as u64, name.as_ptr(), 0, len) }
+    }
+
+    /// Does nothing, since a named file mapping object is destroyed once all
+    /// handles to it, including its mappings, are closed.
+    ///
+    /// This exists so that code calling the Unix method of the same name
+    /// builds on Windows.
+    ///
+    /// # Errors
+    /// Returns an error if `name` contains a NUL character.
+    pub fn shm_unlink(name: &str) -> io::Result<()> {
+        mapping_name(name).map(drop)
+    }
+
+    unsafe fn map(
+        file: Handle,
+        size: u64,
+        name: *const u16,
+        offset: u64,
+        len: usize,
+    ) -> io::Result<Self> {
+        if len == 0 {
+            return VolatileMmap::from_raw(NonNull::dangling(), len);
+        }
+        // SAFETY: `file` is a valid handle or `INVALID_HANDLE_VALUE`, and
+        // `name` is NULL or a valid wide string.
+        let mapping = unsafe {
+            CreateFileMappingW(
+                file,
+                ptr::null_mut(),
+                PAGE_READWRITE,
+                (size >> 32) as u32,
+                size as u32,
+                name,
+            )
+        };
+        if mapping.is_null() {
+            return Err(io::Error::last_os_error());
+        }
+        // SAFETY: `mapping` is a valid file mapping handle. The view keeps the
+        // mapping object alive, so the handle can be closed afterwards.
+        let view = unsafe {
+            MapViewOfFile(
+                mapping,
+                FILE_MAP_READ | FILE_MAP_WRITE,
+                (offset >> 32) as u32,
+                offset as u32,
+                len,
+            )
+        };
+        let result = match NonNull::new(view as *mut u8) {
+            Some(ptr) => VolatileMmap::from_raw(ptr, len),
+            None => Err(io::Error::last_os_error()),
+        };
+        // SAFETY: `mapping` is a valid handle which is no longer used.
+        unsafe { CloseHandle(mapping) };
+        result
+    }
+}
+
+fn mapping_name(name: &str) -> io::Result<Vec<u16>> {
+    if name.contains('\0') {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "name contains NUL",
+        ));
+    }
+    Ok(name.encode_utf16().chain(Some(0)).collect())
+}
+
+/// Unmaps the view of a file mapping at `ptr`.
+///
+/// # Safety
+/// The memory must have been mapped by [`VolatileMmap`], and must not be used
+/// afterwards.
+pub(super) unsafe fn unmap(ptr: NonNull<u8>, _len: usize) {
+    // SAFETY: The caller must ensure the memory was mapped and is unused.
+    unsafe { UnmapViewOfFile(ptr.as_ptr() as *const c_void) };
+}
@@ -0,0 +1,28 @@
+use core::convert::Infallible;
+
+use crate::{
+    volatile::{Read, Write},
+    StatusGated,
+};
+
+impl<T: Copy, S: Copy, PD: Read, PS: Read> StatusGated<'_, T, S, PD, PS> {
+    /// Reads the data register if it is ready to be read.
+    ///
+    /// # Errors
+    /// Returns [`nb::Error::WouldBlock`] without reading the data register if
+    /// it is not ready.
+    pub fn try_read(&mut self) -> nb::Result<T, Infallible> {
+        self.read_if_ready().ok_or(nb::Error::WouldBlock)
+    }
+}
+
+impl<T: Copy, S: Copy, PD: Write, PS: Read> StatusGated<'_, T, S, PD, PS> {
+    /// Writes `val` to the data register if it is ready to be written.
+    ///
+    /// # Errors
+    /// Returns [`nb::Error::WouldBlock`] without writing the data register if
+    /// it is not ready.
+    pub fn try_write(&mut self, val: T) -> nb::Result<(), Infallible> {
+        self.write_if_ready(val).map_err(|_| nb::Error::WouldBlock)
+    }
+}
@@ -0,0 +1,95 @@
+use core::{any::type_name, fmt, marker::PhantomData};
+
+use crate::{
+    volatile::{Read, ReadWrite, Write},
+    Volatile, VolatileData, VolatileRead, VolatileWrite,
+};
+
+/// A register holding a packed bit-field struct of type `T`, which is stored as
+/// an integer of type `U`.
+///
+/// This is intended for types generated by crates such as `modular-bitfield`,
+/// which convert to and from an integer. Every read and write accesses the
+/// whole register as a `U`, so the access width is always that of `U`,
+/// regardless of how `T` is laid out.
+///
+/// `T` is read using [`From<U>`](From), and written using [`Into<U>`](Into).
+#[repr(transparent)]
+pub struct VolatilePacked<T, U: Copy, Permission = ReadWrite> {
+    bits: Volatile<U, Permission>,
+    _packed: PhantomData<T>,
+}
+
+impl<T, U: Copy, P> VolatilePacked<T, U, P> {
+    /// Converts a reference to a [`Volatile`] integer into a reference to a
+    /// `VolatilePacked`.
+    pub const fn from_volatile(vol: &Volatile<U, P>) -> &Self {
+        // SAFETY: It is safe to cast to `*const Self` because `Self` is
+        // transparent.
+        unsafe { &*(vol as *const Volatile<U, P> as *const Self) }
+    }
+
+    /// Converts a mutable reference to a [`Volatile`] integer into a mutable
+    /// reference to a `VolatilePacked`.
+    pub const fn from_volatile_mut(vol: &mut Volatile<U, P>) -> &mut Self {
+        // SAFETY: It is safe to cast to `*mut Self` because `Self` is
+        // transparent.
+        unsafe { &mut *(vol as *mut Volatile<U, P> as *mut Self) }
+    }
+
+    /// Returns a reference to the underlying [`Volatile`] integer.
+    pub const fn bits(&self) -> &Volatile<U, P> {
+        &self.bits
+    }
+
+    /// Returns a mutable reference to the underlying [`Volatile`] integer.
+    pub const fn bits_mut(&mut self) -> &mut Volatile<U, P> {
+        &mut self.bits
+    }
+}
+
+impl<T: Copy + From<U> + Into<U>, U: Copy, P: Read + Write> VolatilePacked<T, U, P> {
+    /// Performs a volatile read of `self`, passes the value to `f`, and
+    /// performs a volatile write of the result.
+    ///
+    /// This is not atomic.
+    pub fn modify(&mut self, f: impl FnOnce(T) -> T) {
+        let value = f(self.read());
+        self.write(value);
+    }
+}
+
+impl<T: Copy, U: Copy, P> VolatileData<T> for VolatilePacked<T, U, P> {}
+
+impl<T: Copy + From<U>, U: Copy, P: Read> VolatileRead<T> for VolatilePacked<T, U, P> {
+    /// Performs a volatile read of the whole register, and converts it to `T`.
+    fn read(&self) -> T {
+        T::from(self.bits.read())
+    }
+}
+
+impl<T: Copy + Into<U>, U: Copy, P: Write> VolatileWrite<T> for VolatilePacked<T, U, P> {
+    /// Converts `val` to `U`, and performs a volatile write of the whole
+    /// register.
+    fn write(&mut self, val: T) {
+        self.bits.write(val.into());
+    }
+}
+
+impl<'a, T, U: Copy, P> From<&'a Volatile<U, P>> for &'a VolatilePacked<T, U, P> {
+    fn from(vol: &'a Volatile<U, P>) -> Self {
+        VolatilePacked::from_volatile(vol)
+    }
+}
+
+impl<'a, T, U: Copy, P> From<&'a mut Volatile<U, P>> for &'a mut VolatilePacked<T, U, P> {
+    fn from(vol: &'a mut Volatile<U, P>) -> Self {
+        VolatilePacked::from_volatile_mut(vol)
+    }
+}
+
+impl<T, U: Copy, P> fmt::Debug for VolatilePacked<T, U, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(type_name::<Self>())
+    }
+}
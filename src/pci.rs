@@ -0,0 +1,196 @@
+#[cfg(all(feature = "std", target_os = "linux"))]
+use core::convert::TryFrom;
+use core::{any::type_name, fmt, mem, ptr::NonNull};
+#[cfg(all(feature = "std", target_os = "linux"))]
+use std::{format, fs, io};
+
+#[cfg(all(feature = "std", target_os = "linux"))]
+use crate::VolatileMmap;
+use crate::{claim::Claim, Volatile, VolatileError};
+
+/// The memory of a PCI base address register (BAR), with a base address and
+/// size known only at runtime.
+///
+/// Like [`VolatileRegion`](crate::VolatileRegion), this provides access to
+/// volatile data at offsets into the BAR, but the offsets are checked at
+/// runtime, so an access which is out of bounds or misaligned fails with a
+/// [`VolatileError`].
+///
+/// With the `std` feature on Linux, a BAR can be mapped from the `resource`
+/// files of a device in sysfs with `PciBar::open`.
+///
+/// # Example
+/// ```
+/// use volatile_mem::{PciBar, VolatileError, VolatileRead};
+///
+/// let mut mem = [0u32; 4];
+/// mem[1] = 0x1234;
+/// let bar = unsafe { PciBar::new(mem.as_mut_ptr() as *mut u8, 16) };
+/// assert_eq!(bar.at::<u32>(4)?.read(), 0x1234);
+/// assert!(bar.at::<u32>(16).is_err());
+/// # Ok::<(), VolatileError>(())
+/// ```
+pub struct PciBar {
+    ptr: NonNull<u8>,
+    size: usize,
+    // The claim of a BAR created with `new`. A BAR which is opened is claimed
+    // by its mapping instead.
+    _claim: Claim,
+    #[cfg(all(feature = "std", target_os = "linux"))]
+    _map: Option<VolatileMmap>,
+}
+
+impl PciBar {
+    /// Creates a handle to the `size` bytes of volatile memory at `base`, such
+    /// as a BAR mapped by firmware or a kernel.
+    ///
+    /// # Safety
+    /// Behavior is undefined if any of the following conditions are violated:
+    ///
+    /// - The entire BAR must be [valid](core::ptr#safety) for reads and writes
+    ///   for as long as the handle exists.
+    ///
+    /// - Any data accessed through the BAR must be properly initialized.
+    ///
+    /// - No other references to the BAR may be used while the handle exists.
+    ///
+    /// # Panics
+    /// Panics if `base` is NULL, or, with the `region-registry` feature, if the
+    /// BAR overlaps a live region.
+    pub unsafe fn new(base: *mut u8, size: usize) -> Self {
+        let ptr = NonNull::new(base).expect("the base address of a BAR must be non-NULL");
+        PciBar {
+            ptr,
+            size,
+            _claim: Claim::new(ptr.as_ptr().addr(), size),
+            #[cfg(all(feature = "std", target_os = "linux"))]
+            _map: None,
+        }
+    }
+
+    /// Maps BAR number `bar` of the PCI device `device`, such as
+    /// `"0000:01:00.0"`, from `/sys/bus/pci/devices/{device}/resource{bar}`.
+    ///
+    /// The size of the BAR is read from `/sys/bus/pci/devices/{device}/resource`.
+    /// The BAR must be a memory BAR, rather than an I/O port BAR.
+    ///
+    /// # Errors
+    /// Returns an error if the BAR is not in use, or if the files describing or
+    /// mapping it cannot be read, parsed, or mapped.
+    ///
+    /// # Safety
+    /// Any data accessed through the BAR must be properly initialized, and no
+    /// other driver may access the BAR while the handle exists.
+    #[cfg(all(feature = "std", target_os = "linux"))]
+    pub unsafe fn open(device: &str, bar: usize) -> io::Result<Self> {
+        let dir = format!("/sys/bus/pci/devices/{}", device);
+        let resources = fs::read_to_string(format!("{}/resource", dir))?;
+        let size = resources
+            .lines()
+            .nth(bar)
+            .and_then(resource_size)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "BAR is not in use"))?;
+        let file = fs::OpenOptions::new()
+            .read(true)
+            .write(true)
+            .open(format!("{}/resource{}", dir, bar))?;
+        // SAFETY: The memory of a device cannot be truncated.
+        let map = unsafe { VolatileMmap::map_file(&file, 0, size)? };
+        let ptr = NonNull::new(map.as_ptr()).unwrap_or(NonNull::dangling());
+        Ok(PciBar {
+            ptr,
+            size,
+            _claim: Claim::new(0, 0),
+            _map: Some(map),
+        })
+    }
+
+    /// Returns a reference to the `Volatile<T>` at `offset` bytes from the
+    /// start of the BAR.
+    ///
+    /// # Errors
+    /// Returns [`VolatileError::OutOfBounds`] if `T` does not fit in the BAR at
+    /// `offset`, or [`VolatileError::Misaligned`] if the resulting address is
+    /// not properly aligned for `T`.
+    pub fn at<T: Copy>(&self, offset: usize) -> Result<&Volatile<T>, VolatileError> {
+        let ptr = self.check::<T>(offset)?;
+        // SAFETY: The address is in bounds and properly aligned. The creator
+        // of the BAR must ensure it is safe to use.
+        Ok(unsafe { Volatile::from_ptr(ptr) })
+    }
+
+    /// Returns a mutable reference to the `Volatile<T>` at `offset` bytes from
+    /// the start of the BAR.
+    ///
+    /// # Errors
+    /// Returns [`VolatileError::OutOfBounds`] if `T` does not fit in the BAR at
+    /// `offset`, or [`VolatileError::Misaligned`] if the resulting address is
+    /// not properly aligned for `T`.
+    pub fn at_mut<T: Copy>(&mut self, offset: usize) -> Result<&mut Volatile<T>, VolatileError> {
+        let ptr = self.check::<T>(offset)?;
+        // SAFETY: The address is in bounds and properly aligned. The creator
+        // of the BAR must ensure it is safe to use.
+        Ok(unsafe { Volatile::from_mut_ptr(ptr) })
+    }
+
+    /// Returns a pointer to the start of the BAR.
+    pub fn as_ptr(&self) -> *mut u8 {
+        self.ptr.as_ptr()
+    }
+
+    /// Returns the size of the BAR in bytes.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    fn check<T>(&self, offset: usize) -> Result<*mut T, VolatileError> {
+        let len = mem::size_of::<T>();
+        if offset > self.size || len > self.size - offset {
+            return Err(VolatileError::OutOfBounds {
+                offset,
+                len,
+                size: self.size,
+            });
+        }
+        let ptr = self.ptr.as_ptr().wrapping_add(offset) as *mut T;
+        let align = mem::align_of::<T>();
+        if !ptr.addr().is_multiple_of(align) {
+            return Err(VolatileError::Misaligned {
+                addr: ptr.addr(),
+                align,
+            });
+        }
+        Ok(ptr)
+    }
+}
+
+// SAFETY: The creator of the BAR must ensure no other references to it are
+// used, so it may be sent between threads.
+unsafe impl Send for PciBar {}
+
+impl fmt::Debug for PciBar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {{ {:p}, {:#x} }}",
+            type_name::<Self>(),
+            self.ptr,
+            self.size
+        )
+    }
+}
+
+/// Parses a line of a sysfs `resource` file, as `start end flags`, returning
+/// the size of the resource, or `None` if it is not in use.
+#[cfg(all(feature = "std", target_os = "linux"))]
+fn resource_size(line: &str) -> Option<usize> {
+    let mut fields = line
+        .split_whitespace()
+        .map(|field| u64::from_str_radix(field.trim_start_matches("0x"), 16));
+    let start = fields.next()?.ok()?;
+    let end = fields.next()?.ok()?;
+    if start == 0 && end == 0 {
+        return None;
+    }
+    usize::try_from(end.checked_sub(start)?.checked_add(1)?).ok()
+}
@@ -0,0 +1,318 @@
+//! Polling registers until they reach an expected state, with optional
+//! timeouts.
+//!
+//! [`Volatile`] provides [`wait_until`](Volatile::wait_until),
+//! [`wait_for_set`](Volatile::wait_for_set), and
+//! [`wait_for_clear`](Volatile::wait_for_clear), which poll a register for as
+//! long as it takes, as well as variants with a `_timeout` suffix, which give
+//! up with [`Timeout`] once a [`PollTimer`] expires. A [`PollLimit`] limits the
+//! number of polls, and a [`CycleTimeout`] limits the time taken, as measured by
+//! a cycle counter. With the `embedded-hal` feature, a `DelayTimeout` limits the
+//! time taken using an `embedded_hal::delay::DelayNs` implementation.
+//!
+//! [`wait_any`] and [`wait_any_timeout`] poll several conditions, such as on
+//! different registers, until any of them holds.
+use core::{error, fmt, hint};
+
+use crate::{bitfield::UInt, volatile::Read, Volatile, VolatileRead};
+
+/// A timer limiting how long a register is polled.
+pub trait PollTimer {
+    /// Called after each poll which does not find the expected state. Returns
+    /// `true` if the timer has expired, in which case polling stops.
+    fn expired(&mut self) -> bool;
+}
+
+impl<T: PollTimer + ?Sized> PollTimer for &mut T {
+    fn expired(&mut self) -> bool {
+        (**self).expired()
+    }
+}
+
+/// A timer which expires after a number of polls.
+///
+/// The register is always polled at least once, even if the limit is zero.
+#[derive(Debug, Clone, Copy)]
+pub struct PollLimit {
+    remaining: usize,
+}
+
+impl PollLimit {
+    /// Creates a timer which expires after `polls` polls.
+    pub const fn new(polls: usize) -> Self {
+        PollLimit { remaining: polls }
+    }
+}
+
+impl PollTimer for PollLimit {
+    fn expired(&mut self) -> bool {
+        self.remaining = self.remaining.saturating_sub(1);
+        self.remaining == 0
+    }
+}
+
+/// A timer which expires once a free-running cycle counter, such as the
+/// Cortex-M `DWT` cycle counter, has advanced by a number of cycles.
+///
+/// The counter is read with a closure, and may wrap around.
+#[derive(Debug, Clone, Copy)]
+pub struct CycleTimeout<F> {
+    now: F,
+    start: u32,
+    cycles: u32,
+}
+
+impl<F: FnMut() -> u32> CycleTimeout<F> {
+    /// Creates a timer which expires `cycles` cycles from now, as returned by
+    /// `now`.
+    pub fn new(mut now: F, cycles: u32) -> Self {
+        let start = now();
+        CycleTimeout { now, start, cycles }
+    }
+}
+
+impl<F: FnMut() -> u32> PollTimer for CycleTimeout<F> {
+    fn expired(&mut self) -> bool {
+        (self.now)().wrapping_sub(self.start) >= self.cycles
+    }
+}
+
+/// A timer which delays between polls, and expires once the total delay reaches
+/// a limit.
+#[cfg(feature = "embedded-hal")]
+#[derive(Debug)]
+pub struct DelayTimeout<D> {
+    delay: D,
+    interval_us: u32,
+    remaining_us: u32,
+}
+
+#[cfg(feature = "embedded-hal")]
+impl<D: embedded_hal::delay::DelayNs> DelayTimeout<D> {
+    /// Creates a timer which delays for `interval_us` microseconds between
+    /// polls, and expires after a total of `timeout_us` microseconds.
+    ///
+    /// An `interval_us` of zero is treated as one, so that the timer still
+    /// expires.
+    pub fn new(delay: D, interval_us: u32, timeout_us: u32) -> Self {
+        DelayTimeout {
+            delay,
+            interval_us: interval_us.max(1),
+            remaining_us: timeout_us,
+        }
+    }
+
+    /// Returns the delay provider.
+    pub fn into_inner(self) -> D {
+        self.delay
+    }
+}
+
+#[cfg(feature = "embedded-hal")]
+impl<D: embedded_hal::delay::DelayNs> PollTimer for DelayTimeout<D> {
+    fn expired(&mut self) -> bool {
+        if self.remaining_us == 0 {
+            return true;
+        }
+        let us = self.interval_us.min(self.remaining_us);
+        self.delay.delay_us(us);
+        self.remaining_us -= us;
+        false
+    }
+}
+
+impl<T: Copy, P: Read> Volatile<T, P> {
+    /// Performs volatile reads of `self` until `pred` returns `true` for the
+    /// value read, and returns that value.
+    ///
+    /// [`spin_loop`](hint::spin_loop) is called between reads. This may spin
+    /// forever if the value never satisfies `pred`.
+    ///
+    /// # Example
+    /// ```
+    /// use volatile_mem::Volatile;
+    ///
+    /// let status: &Volatile<u32> = Volatile::from_ref(&0x5);
+    /// assert_eq!(status.wait_until(|status| status & 0x4 != 0), 0x5);
+    /// assert_eq!(status.wait_for_set(0x1), 0x5);
+    /// assert_eq!(status.wait_for_clear(0x2), 0x5);
+    /// ```
+    pub fn wait_until(&self, mut pred: impl FnMut(T) -> bool) -> T {
+        loop {
+            let value = self.read();
+            if pred(value) {
+                return value;
+            }
+            hint::spin_loop();
+        }
+    }
+
+    /// Performs volatile reads of `self` until `pred` returns `true` for the
+    /// value read, and returns that value, unless `timer` expires first.
+    ///
+    /// [`spin_loop`](hint::spin_loop) is called between reads.
+    ///
+    /// # Errors
+    /// Returns [`Timeout`] if `timer` expires before the value satisfies
+    /// `pred`.
+    ///
+    /// # Example
+    /// ```
+    /// use volatile_mem::{poll::PollLimit, Timeout, Volatile};
+    ///
+    /// let status: &Volatile<u32> = Volatile::from_ref(&0x5);
+    /// assert_eq!(status.wait_for_set_timeout(0x4, PollLimit::new(10)), Ok(0x5));
+    /// assert_eq!(status.wait_for_set_timeout(0x2, PollLimit::new(10)), Err(Timeout));
+    /// ```
+    pub fn wait_until_timeout(
+        &self,
+        mut pred: impl FnMut(T) -> bool,
+        mut timer: impl PollTimer,
+    ) -> Result<T, Timeout> {
+        loop {
+            let value = self.read();
+            if pred(value) {
+                return Ok(value);
+            }
+            if timer.expired() {
+                return Err(Timeout);
+            }
+            hint::spin_loop();
+        }
+    }
+}
+
+impl<T: Copy + PartialEq, P: Read> Volatile<T, P> {
+    /// Performs volatile reads of `self` until two consecutive reads return the
+    /// same value, and returns that value.
+    ///
+    /// This is useful for registers which are updated by hardware without
+    /// synchronization, such as counters in another clock domain or latching
+    /// ADC result registers. After the first two reads, up to `max_retries`
+    /// further reads are performed, each compared with the previous one.
+    ///
+    /// Returns `None` if no two consecutive reads agree.
+    ///
+    /// # Example
+    /// ```
+    /// use volatile_mem::Volatile;
+    ///
+    /// let counter: &Volatile<u32> = Volatile::from_ref(&42);
+    /// assert_eq!(counter.read_stable(3), Some(42));
+    /// ```
+    pub fn read_stable(&self, max_retries: usize) -> Option<T> {
+        let mut prev = self.read();
+        for _ in 0..=max_retries {
+            let value = self.read();
+            if value == prev {
+                return Some(value);
+            }
+            prev = value;
+        }
+        None
+    }
+}
+
+impl<T: UInt, P: Read> Volatile<T, P> {
+    /// Performs volatile reads of `self` until all of the bits in `mask` are
+    /// set, and returns the value read.
+    ///
+    /// This may spin forever if the bits are never set.
+    pub fn wait_for_set(&self, mask: T) -> T {
+        self.wait_until(|value| value & mask == mask)
+    }
+
+    /// Performs volatile reads of `self` until all of the bits in `mask` are
+    /// clear, and returns the value read.
+    ///
+    /// This may spin forever if the bits are never cleared.
+    pub fn wait_for_clear(&self, mask: T) -> T {
+        self.wait_until(|value| value & mask == T::ZERO)
+    }
+
+    /// Performs volatile reads of `self` until all of the bits in `mask` are
+    /// set, and returns the value read, unless `timer` expires first.
+    ///
+    /// # Errors
+    /// Returns [`Timeout`] if `timer` expires before the bits are set.
+    pub fn wait_for_set_timeout(&self, mask: T, timer: impl PollTimer) -> Result<T, Timeout> {
+        self.wait_until_timeout(|value| value & mask == mask, timer)
+    }
+
+    /// Performs volatile reads of `self` until all of the bits in `mask` are
+    /// clear, and returns the value read, unless `timer` expires first.
+    ///
+    /// # Errors
+    /// Returns [`Timeout`] if `timer` expires before the bits are cleared.
+    pub fn wait_for_clear_timeout(&self, mask: T, timer: impl PollTimer) -> Result<T, Timeout> {
+        self.wait_until_timeout(|value| value & mask == T::ZERO, timer)
+    }
+}
+
+/// Evaluates each of `conditions` in turn until one returns `true`, and
+/// returns its index.
+///
+/// Each condition typically performs a volatile read of a register and checks
+/// its value, such as to wait for either an error flag or a done flag.
+/// [`spin_loop`](hint::spin_loop) is called after each round in which no
+/// condition holds. This may spin forever if no condition ever holds.
+///
+/// # Example
+/// ```
+/// use volatile_mem::{poll, Volatile};
+///
+/// let error: &Volatile<u32> = Volatile::from_ref(&0);
+/// let status: &Volatile<u8> = Volatile::from_ref(&0x80);
+/// let index = poll::wait_any(&mut [
+///     &mut || error.test_bits(0x1),
+///     &mut || status.test_bits(0x80),
+/// ]);
+/// assert_eq!(index, 1);
+/// ```
+pub fn wait_any(conditions: &mut [&mut dyn FnMut() -> bool]) -> usize {
+    loop {
+        if let Some(index) = find_any(conditions) {
+            return index;
+        }
+        hint::spin_loop();
+    }
+}
+
+/// Evaluates each of `conditions` in turn until one returns `true`, and
+/// returns its index, unless `timer` expires first.
+///
+/// See [`wait_any`].
+///
+/// # Errors
+/// Returns [`Timeout`] if `timer` expires before any condition holds.
+pub fn wait_any_timeout(
+    conditions: &mut [&mut dyn FnMut() -> bool],
+    mut timer: impl PollTimer,
+) -> Result<usize, Timeout> {
+    loop {
+        if let Some(index) = find_any(conditions) {
+            return Ok(index);
+        }
+        if timer.expired() {
+            return Err(Timeout);
+        }
+        hint::spin_loop();
+    }
+}
+
+fn find_any(conditions: &mut [&mut dyn FnMut() -> bool]) -> Option<usize> {
+    conditions.iter_mut().position(|condition| condition())
+}
+
+/// An error indicating that a register did not reach the expected state before
+/// a timeout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timeout;
+
+impl fmt::Display for Timeout {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("timed out waiting for register")
+    }
+}
+
+impl error::Error for Timeout {}
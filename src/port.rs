@@ -0,0 +1,154 @@
+use core::{any::type_name, arch::asm, fmt, marker::PhantomData};
+
+use crate::{
+    volatile::{Read, ReadOnly, ReadWrite, Write, WriteOnly},
+    VolatileData, VolatileRead, VolatileWrite,
+};
+
+/// A type which can be transferred to or from an x86 I/O port with the `in`
+/// and `out` instructions.
+///
+/// This is implemented for [`u8`], [`u16`], and [`u32`]. The compiler does not
+/// reorder memory accesses across the port accesses, so memory such as a DMA
+/// buffer which is written before an `out` is written before the device sees
+/// it, as with volatile accesses to memory-mapped I/O.
+pub trait PortData: Copy {
+    /// Reads a value from `port` with the `in` instruction.
+    ///
+    /// # Safety
+    /// Reading from an I/O port may have side effects, so the caller must
+    /// ensure reading `port` is safe.
+    unsafe fn port_in(port: u16) -> Self;
+
+    /// Writes `val` to `port` with the `out` instruction.
+    ///
+    /// # Safety
+    /// Writing to an I/O port may have side effects, so the caller must ensure
+    /// writing `port` is safe.
+    unsafe fn port_out(port: u16, val: Self);
+}
+
+macro_rules! impl_port_data {
+    ($($ty:ty => $reg:tt),*) => {
+        $(
+            impl PortData for $ty {
+                #[inline]
+                unsafe fn port_in(port: u16) -> Self {
+                    let val: $ty;
+                    // SAFETY: The caller must ensure reading the port is safe.
+                    unsafe {
+                        asm!(
+                            concat!("in ", $reg, ", dx"),
+                            out($reg) val,
+                            in("dx") port,
+                            options(nostack, preserves_flags),
+                        );
+                    }
+                    val
+                }
+
+                #[inline]
+                unsafe fn port_out(port: u16, val: Self) {
+                    // SAFETY: The caller must ensure writing the port is safe.
+                    unsafe {
+                        asm!(
+                            concat!("out dx, ", $reg),
+                            in("dx") port,
+                            in($reg) val,
+                            options(nostack, preserves_flags),
+                        );
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_port_data!(u8 => "al", u16 => "ax", u32 => "eax");
+
+/// An x86 I/O port, accessed with the same permission model as [`Volatile`].
+///
+/// Where [`Volatile`] performs memory accesses, `PortVolatile` performs the
+/// `in` and `out` instructions on the port, so a legacy port-mapped device can
+/// be described with the same types and traits as a memory-mapped one. Reads
+/// are performed through [`VolatileRead`] and writes through
+/// [`VolatileWrite`], which requires a mutable reference.
+///
+/// See [`PortReadOnly`] and [`PortWriteOnly`] for ports with restricted
+/// permissions.
+///
+/// [`Volatile`]: crate::Volatile
+///
+/// # Example
+/// ```no_run
+/// use volatile_mem::{PortVolatile, PortReadOnly, VolatileRead, VolatileWrite};
+///
+/// // The data and line status ports of the first serial port.
+/// let mut data = unsafe { PortVolatile::<u8>::new(0x3f8) };
+/// let status = unsafe { PortReadOnly::<u8>::new(0x3fd) };
+/// while status.read() & 0x20 == 0 {}
+/// data.write(b'!');
+/// ```
+pub struct PortVolatile<T: PortData, Permission = ReadWrite> {
+    port: u16,
+    _perm: PhantomData<(T, Permission)>,
+}
+
+/// A read-only x86 I/O port.
+///
+/// See [`PortVolatile`] for details.
+pub type PortReadOnly<T> = PortVolatile<T, ReadOnly>;
+
+/// A write-only x86 I/O port.
+///
+/// See [`PortVolatile`] for details.
+pub type PortWriteOnly<T> = PortVolatile<T, WriteOnly>;
+
+impl<T: PortData, P> PortVolatile<T, P> {
+    /// Creates a handle to the I/O port `port`.
+    ///
+    /// # Safety
+    /// Accessing the port, as allowed by the permission, must not violate
+    /// memory safety, such as by starting DMA to memory in use. No other
+    /// handle to the port may be used to write to it while the handle exists,
+    /// except through a shared reference to a read-only handle.
+    pub const unsafe fn new(port: u16) -> Self {
+        PortVolatile {
+            port,
+            _perm: PhantomData,
+        }
+    }
+
+    /// Returns the number of the port.
+    pub const fn port(&self) -> u16 {
+        self.port
+    }
+}
+
+impl<T: PortData, P> VolatileData<T> for PortVolatile<T, P> {}
+
+impl<T: PortData, P: Read> VolatileRead<T> for PortVolatile<T, P> {
+    /// Reads the port with the `in` instruction.
+    #[inline]
+    fn read(&self) -> T {
+        // SAFETY: The creator of the handle must ensure reading the port is
+        // safe.
+        unsafe { T::port_in(self.port) }
+    }
+}
+
+impl<T: PortData, P: Write> VolatileWrite<T> for PortVolatile<T, P> {
+    /// Writes the port with the `out` instruction.
+    #[inline]
+    fn write(&mut self, val: T) {
+        // SAFETY: The creator of the handle must ensure writing the port is
+        // safe.
+        unsafe { T::port_out(self.port, val) }
+    }
+}
+
+impl<T: PortData, P> fmt::Debug for PortVolatile<T, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {{ {:#x} }}", type_name::<Self>(), self.port)
+    }
+}
@@ -0,0 +1,176 @@
+use core::{mem, sync::atomic::Ordering};
+
+use crate::{
+    volatile::{Read, Write},
+    Volatile, VolatileShared,
+};
+
+/// Returns `ptr` as a reference to an atomic type.
+///
+/// # Panics
+/// Panics if `ptr` is not aligned to the alignment of `A`, which may be greater
+/// than that of `T` on some targets, such as for 64-bit integers on 32-bit x86.
+///
+/// # Safety
+/// `ptr` must be valid for reads and writes while the returned reference
+/// exists, and must not be accessed non-atomically during that time.
+unsafe fn atomic<'a, T, A>(ptr: *mut T, from_ptr: unsafe fn(*mut T) -> &'a A) -> &'a A {
+    let align = mem::align_of::<A>();
+    assert!(
+        ptr.addr().is_multiple_of(align),
+        "atomic access requires an alignment of {} bytes",
+        align
+    );
+    // SAFETY: The caller must ensure `ptr` is valid and not accessed
+    // non-atomically, and it is properly aligned for the atomic type.
+    unsafe { from_ptr(ptr) }
+}
+
+macro_rules! impl_atomic {
+    ($($has_atomic:ident => $($ty:ty: $atomic:ident),*;)*) => {
+        $($(
+            portable_atomic::$has_atomic! {
+                impl<P: Read + Write> Volatile<$ty, P> {
+                    /// Performs an atomic bitwise OR of `self` with `val`,
+                    /// returning the previous value.
+                    ///
+                    /// The update cannot race with other processors or devices
+                    /// which also update the data atomically. Many
+                    /// architectures do not support atomic instructions on
+                    /// device memory, so this is intended for control words in
+                    /// normal shared memory. Unlike
+                    /// [`fetch_or`](Self::fetch_or), the read and write are a
+                    /// single atomic operation. For a control word which is
+                    /// updated through shared references, use
+                    #[doc = concat!("[`VolatileShared::atomic_fetch_or`](VolatileShared::<", stringify!($ty), ">::atomic_fetch_or).")]
+                    ///
+                    /// # Panics
+                    /// Panics if `self` is not aligned to the alignment of the
+                    /// atomic type, which may be greater than that of the
+                    /// integer on some targets, such as for 64-bit integers on
+                    /// 32-bit x86.
+                    ///
+                    /// # Example
+                    /// ```
+                    /// use core::sync::atomic::Ordering;
+                    /// use volatile_mem::{Volatile, VolatileRead};
+                    ///
+                    #[doc = concat!("let mut mem: ", stringify!($ty), " = 0b01;")]
+                    #[doc = concat!("let vol = Volatile::<", stringify!($ty), ">::from_mut(&mut mem);")]
+                    /// assert_eq!(vol.atomic_fetch_or(0b10, Ordering::SeqCst), 0b01);
+                    /// assert_eq!(vol.read(), 0b11);
+                    /// ```
+                    pub fn atomic_fetch_or(&mut self, val: $ty, order: Ordering) -> $ty {
+                        self.atomic().fetch_or(val, order)
+                    }
+
+                    /// Performs an atomic bitwise AND of `self` with `val`,
+                    /// returning the previous value, like
+                    /// [`atomic_fetch_or`](Self::atomic_fetch_or).
+                    ///
+                    /// # Panics
+                    /// Panics if `self` is not aligned to the alignment of the
+                    /// atomic type.
+                    pub fn atomic_fetch_and(&mut self, val: $ty, order: Ordering) -> $ty {
+                        self.atomic().fetch_and(val, order)
+                    }
+
+                    /// Atomically replaces the value of `self` with `val`,
+                    /// returning the previous value, like
+                    /// [`atomic_fetch_or`](Self::atomic_fetch_or).
+                    ///
+                    /// # Panics
+                    /// Panics if `self` is not aligned to the alignment of the
+                    /// atomic type.
+                    pub fn atomic_swap(&mut self, val: $ty, order: Ordering) -> $ty {
+                        self.atomic().swap(val, order)
+                    }
+
+                    fn atomic(&mut self) -> &portable_atomic::$atomic {
+                        // SAFETY: `self` is valid for reads and writes. It is
+                        // borrowed mutably, so it is not accessed
+                        // non-atomically by Rust while the atomic reference
+                        // exists.
+                        unsafe { atomic(self.as_mut_ptr(), portable_atomic::$atomic::from_ptr) }
+                    }
+                }
+
+                impl<P: Read + Write> VolatileShared<$ty, P> {
+                    /// Performs an atomic bitwise OR of `self` with `val`,
+                    /// returning the previous value, like
+                    #[doc = concat!("[`Volatile::atomic_fetch_or`](Volatile::<", stringify!($ty), ">::atomic_fetch_or),")]
+                    /// but through a shared reference.
+                    ///
+                    /// # Panics
+                    /// Panics if `self` is not aligned to the alignment of the
+                    /// atomic type.
+                    ///
+                    /// # Example
+                    /// ```
+                    /// use core::sync::atomic::Ordering;
+                    /// use volatile_mem::{VolatileRead, VolatileShared};
+                    ///
+                    #[doc = concat!("let control = VolatileShared::<", stringify!($ty), ">::new(0b01);")]
+                    /// let (a, b) = (&control, &control);
+                    /// a.atomic_fetch_or(0b10, Ordering::SeqCst);
+                    /// assert_eq!(b.atomic_swap(0b100, Ordering::SeqCst), 0b11);
+                    /// assert_eq!(control.read(), 0b100);
+                    /// ```
+                    pub fn atomic_fetch_or(&self, val: $ty, order: Ordering) -> $ty {
+                        self.atomic().fetch_or(val, order)
+                    }
+
+                    /// Performs an atomic bitwise AND of `self` with `val`,
+                    /// returning the previous value, like
+                    /// [`atomic_fetch_or`](Self::atomic_fetch_or).
+                    ///
+                    /// # Panics
+                    /// Panics if `self` is not aligned to the alignment of the
+                    /// atomic type.
+                    pub fn atomic_fetch_and(&self, val: $ty, order: Ordering) -> $ty {
+                        self.atomic().fetch_and(val, order)
+                    }
+
+                    /// Atomically replaces the value of `self` with `val`,
+                    /// returning the previous value, like
+                    /// [`atomic_fetch_or`](Self::atomic_fetch_or).
+                    ///
+                    /// # Panics
+                    /// Panics if `self` is not aligned to the alignment of the
+                    /// atomic type.
+                    pub fn atomic_swap(&self, val: $ty, order: Ordering) -> $ty {
+                        self.atomic().swap(val, order)
+                    }
+
+                    fn atomic(&self) -> &portable_atomic::$atomic {
+                        // SAFETY: `self` is valid for reads and writes, and its
+                        // data is contained in an `UnsafeCell`, so it may be
+                        // written through a shared reference. `VolatileShared`
+                        // is not `Sync`, and a `SyncVolatile` requires accesses
+                        // from other threads to be synchronized, so the data is
+                        // not accessed non-atomically by Rust while the atomic
+                        // reference, which does not outlive the calling method,
+                        // exists.
+                        unsafe {
+                            atomic(
+                                self as *const Self as *mut $ty,
+                                portable_atomic::$atomic::from_ptr,
+                            )
+                        }
+                    }
+                }
+            }
+        )*)*
+    };
+}
+
+portable_atomic::cfg_has_atomic_cas! {
+    impl_atomic! {
+        cfg_has_atomic_8 => u8: AtomicU8, i8: AtomicI8;
+        cfg_has_atomic_16 => u16: AtomicU16, i16: AtomicI16;
+        cfg_has_atomic_32 => u32: AtomicU32, i32: AtomicI32;
+        cfg_has_atomic_64 => u64: AtomicU64, i64: AtomicI64;
+        cfg_has_atomic_128 => u128: AtomicU128, i128: AtomicI128;
+        cfg_has_atomic_ptr => usize: AtomicUsize, isize: AtomicIsize;
+    }
+}
@@ -0,0 +1,94 @@
+use crate::{
+    access,
+    table::Plain,
+    volatile::{Read, Write},
+    Volatile,
+};
+
+/// A type with a defined bit representation as an integer, or another
+/// [`Plain`] type.
+///
+/// This allows the raw bits of a [`Volatile<T>`](Volatile) to be accessed with
+/// [`read_raw`](Volatile::read_raw) and [`write_raw`](Volatile::write_raw),
+/// without constructing a `T`. It is implemented for integers, [`bool`], and
+/// [`char`], and may be implemented for field-less enums with a primitive
+/// representation, such as `#[repr(u8)]`.
+///
+/// # Safety
+/// `Raw` must have the same size as `Self`, and an alignment no greater than
+/// that of `Self`, and every value of `Self` must be represented by a value of
+/// `Raw`. In particular, `Self` must not contain padding.
+///
+/// # Example
+/// ```
+/// use volatile_mem::{RawRepr, Volatile};
+///
+/// #[derive(Clone, Copy)]
+/// #[repr(u8)]
+/// enum Mode {
+///     Off = 0,
+///     On = 1,
+/// }
+///
+/// // SAFETY: `Mode` is represented by a `u8`.
+/// unsafe impl RawRepr for Mode {
+///     type Raw = u8;
+/// }
+///
+/// let mut mem = Mode::On;
+/// let reg = Volatile::<Mode>::from_mut(&mut mem);
+/// assert_eq!(reg.read_raw(), 1);
+/// ```
+pub unsafe trait RawRepr: Copy {
+    /// The type of the raw bits.
+    type Raw: Plain;
+}
+
+macro_rules! impl_raw_repr {
+    ($($ty:ty => $raw:ty),*) => {
+        $(
+            // SAFETY: The types have the same size and alignment, and every
+            // bit pattern is a valid value of the raw type.
+            unsafe impl RawRepr for $ty {
+                type Raw = $raw;
+            }
+        )*
+    };
+}
+
+impl_raw_repr!(
+    u8 => u8, u16 => u16, u32 => u32, u64 => u64, u128 => u128, usize => usize,
+    i8 => i8, i16 => i16, i32 => i32, i64 => i64, i128 => i128, isize => isize,
+    bool => u8, char => u32
+);
+
+impl<T: RawRepr, P: Read> Volatile<T, P> {
+    /// Performs a volatile read of the raw bits of `self`, without
+    /// constructing a `T`.
+    ///
+    /// This is useful for dumping the value of a register, and for types with
+    /// reserved encodings, where the hardware may return a value which is not
+    /// a valid `T`, and reading a `T` would be undefined behavior.
+    pub fn read_raw(&self) -> T::Raw {
+        // SAFETY: `self` is a reference, so it is valid for reads and is
+        // aligned for `T`, and therefore for `T::Raw`, which has the same size.
+        // Every bit pattern is a valid `T::Raw`.
+        unsafe { access::read(self as *const Self as *const T::Raw) }
+    }
+}
+
+impl<T: RawRepr, P: Write> Volatile<T, P> {
+    /// Performs a volatile write of the raw bits of `self`, without
+    /// constructing a `T`.
+    ///
+    /// # Safety
+    /// If `self` is read as a `T` afterwards, such as with
+    /// [`read`](crate::VolatileRead::read), `raw` must represent a valid value
+    /// of `T`. It may always be read with [`read_raw`](Self::read_raw).
+    pub unsafe fn write_raw(&mut self, raw: T::Raw) {
+        // SAFETY: `self` is a mutable reference, so it is valid for writes and
+        // is aligned for `T`, and therefore for `T::Raw`, which has the same
+        // size. The caller must ensure the value is valid if read as a `T`.
+        unsafe { access::write(self as *mut Self as *mut T::Raw, raw) }
+    }
+}
@@ -0,0 +1,105 @@
+use core::{any::type_name, fmt, mem};
+
+use crate::Volatile;
+
+/// A region of volatile memory with a constant base address and size.
+///
+/// This provides access to volatile data at constant offsets into the region,
+/// such as those found in datasheets. Bounds and alignment are checked at
+/// compile time, so an access which is out of bounds or misaligned fails to
+/// compile.
+///
+/// See [crate-level documentation](crate) for details.
+pub struct VolatileRegion<const BASE: usize, const SIZE: usize> {
+    _private: (),
+}
+
+impl<const BASE: usize, const SIZE: usize> VolatileRegion<BASE, SIZE> {
+    /// Creates a handle to the volatile memory from `BASE` to `BASE + SIZE`.
+    ///
+    /// # Safety
+    /// Behavior is undefined if any of the following conditions are violated:
+    ///
+    /// - The entire region must be [valid](core::ptr#safety) for reads and
+    ///   writes.
+    ///
+    /// - Any data accessed through the region must be properly initialized.
+    ///
+    /// - No other references to the region, including through other
+    ///   `VolatileRegion`s, may be used while the handle exists.
+    ///
+    /// Just like in C, whether an operation is volatile has no bearing
+    /// whatsoever on questions involving concurrent access from multiple
+    /// threads. Volatile accesses behave exactly like non-atomic accesses in
+    /// that regard. In particular, a race between a write operation any other
+    /// operation (reading or writing) to the same location is undefined
+    /// behavior.
+    pub const unsafe fn new() -> Self {
+        const {
+            assert!(BASE != 0, "the base address of a region must be non-NULL");
+            assert!(
+                BASE.checked_add(SIZE).is_some(),
+                "a region must not wrap around the address space"
+            );
+        }
+        VolatileRegion { _private: () }
+    }
+
+    /// Returns a reference to the `Volatile<T>` at `OFFSET` bytes from the
+    /// start of the region.
+    ///
+    /// This fails to compile if `T` does not fit in the region at `OFFSET` or
+    /// if the resulting address is not properly aligned for `T`.
+    pub fn at<T: Copy, const OFFSET: usize>(&self) -> &Volatile<T> {
+        // SAFETY: The address is in bounds and properly aligned, as checked by
+        // `addr`. The creator of the region must ensure it is safe to use.
+        unsafe { Volatile::from_ptr(Self::addr::<T, OFFSET>() as *const T) }
+    }
+
+    /// Returns a mutable reference to the `Volatile<T>` at `OFFSET` bytes from
+    /// the start of the region.
+    ///
+    /// This fails to compile if `T` does not fit in the region at `OFFSET` or
+    /// if the resulting address is not properly aligned for `T`.
+    pub fn at_mut<T: Copy, const OFFSET: usize>(&mut self) -> &mut Volatile<T> {
+        // SAFETY: The address is in bounds and properly aligned, as checked by
+        // `addr`. The creator of the region must ensure it is safe to use.
+        unsafe { Volatile::from_mut_ptr(Self::addr::<T, OFFSET>() as *mut T) }
+    }
+
+    /// Returns the base address of the region.
+    pub const fn base(&self) -> usize {
+        BASE
+    }
+
+    /// Returns the size of the region in bytes.
+    pub const fn size(&self) -> usize {
+        SIZE
+    }
+
+    const fn addr<T, const OFFSET: usize>() -> usize {
+        const {
+            assert!(
+                OFFSET <= SIZE && mem::size_of::<T>() <= SIZE - OFFSET,
+                "access is outside of the region"
+            );
+            assert!(
+                (BASE + OFFSET).is_multiple_of(mem::align_of::<T>()),
+                "access is not properly aligned"
+            );
+        }
+        BASE + OFFSET
+    }
+}
+
+impl<const BASE: usize, const SIZE: usize> fmt::Debug for VolatileRegion<BASE, SIZE> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {{ {:#x}..{:#x} }}",
+            type_name::<Self>(),
+            BASE,
+            BASE + SIZE
+        )
+    }
+}
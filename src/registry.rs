@@ -0,0 +1,277 @@
+//! A global registry of live volatile regions, for catching overlapping
+//! mappings while testing.
+//!
+//! Each region is claimed with [`claim`], [`claim_mut`], or [`claim_raw`],
+//! which record its address range in a global table until the returned
+//! [`RegionGuard`] is dropped. Claiming a region which overlaps a live region
+//! panics, unless both are read-only, since overlapping mappings usually mean
+//! two drivers have been handed the same MMIO block.
+//!
+//! The regions owned by the types of this crate are claimed as writable when
+//! they are created, and released when they are dropped, without any calls to
+//! this module. These are [`VolatileBox`](crate::VolatileBox),
+//! `VolatileBuf`, `VolatileMmap` (including the maps of a `UioDevice`), and
+//! [`PciBar`](crate::PciBar). Empty regions are not claimed, and the memory of
+//! a `VolatileBox` or `VolatileBuf` is left untracked if the table is full, so
+//! that tests with many such fixtures alive at once do not fail.
+//!
+//! The table holds up to [`CAPACITY`] regions and is protected by a spin lock,
+//! so this is only available on targets with atomic compare-and-swap. It is
+//! intended for debug builds and tests, rather than production code.
+//!
+//! # Example
+//! ```
+//! use volatile_mem::{registry, Volatile};
+//!
+//! let mut mem = [0u32; 4];
+//! let vol: &mut Volatile<[u32; 4]> = Volatile::from_mut(&mut mem);
+//! let guard = registry::claim_mut(vol);
+//! assert!(registry::try_claim_raw(guard.addr() + 4, 4, false).is_err());
+//! drop(guard);
+//! assert!(registry::try_claim_raw(vol.addr() + 4, 4, false).is_ok());
+//! ```
+//!
+//! A [`PciBar`](crate::PciBar) claims its memory when it is created.
+//! ```
+//! use volatile_mem::{registry, PciBar};
+//!
+//! let mut mem = [0u32; 4];
+//! let bar = unsafe { PciBar::new(mem.as_mut_ptr() as *mut u8, 16) };
+//! assert!(registry::try_claim_raw(bar.as_ptr() as usize, 4, false).is_err());
+//! drop(bar);
+//! assert!(registry::try_claim_raw(mem.as_ptr() as usize, 4, false).is_ok());
+//! ```
+use core::{
+    any::type_name,
+    cell::UnsafeCell,
+    error, fmt, hint, mem,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+use crate::{Volatile, VolatileRegion};
+
+/// The maximum number of regions which may be claimed at once.
+pub const CAPACITY: usize = 32;
+
+#[derive(Clone, Copy)]
+struct Entry {
+    addr: usize,
+    size: usize,
+    writable: bool,
+}
+
+struct Table {
+    locked: AtomicBool,
+    entries: UnsafeCell<[Option<Entry>; CAPACITY]>,
+}
+
+// SAFETY: The entries are only accessed while the lock is held.
+unsafe impl Sync for Table {}
+
+static TABLE: Table = Table {
+    locked: AtomicBool::new(false),
+    entries: UnsafeCell::new([None; CAPACITY]),
+};
+
+impl Table {
+    fn with<R>(&self, f: impl FnOnce(&mut [Option<Entry>; CAPACITY]) -> R) -> R {
+        while self
+            .locked
+            .compare_exchange_weak(false, true, Ordering::Acquire, Ordering::Relaxed)
+            .is_err()
+        {
+            hint::spin_loop();
+        }
+        // SAFETY: The lock is held, so there are no other references to the
+        // entries.
+        let result = f(unsafe { &mut *self.entries.get() });
+        self.locked.store(false, Ordering::Release);
+        result
+    }
+}
+
+/// A claim on a region of memory, which is released when dropped.
+#[must_use = "the region is released as soon as the guard is dropped"]
+pub struct RegionGuard {
+    index: usize,
+    entry: Entry,
+}
+
+impl RegionGuard {
+    /// Returns the start address of the region.
+    pub fn addr(&self) -> usize {
+        self.entry.addr
+    }
+
+    /// Returns the size of the region in bytes.
+    pub fn size(&self) -> usize {
+        self.entry.size
+    }
+
+    /// Returns `true` if the region was claimed as writable.
+    pub fn is_writable(&self) -> bool {
+        self.entry.writable
+    }
+}
+
+impl Drop for RegionGuard {
+    fn drop(&mut self) {
+        TABLE.with(|entries| entries[self.index] = None);
+    }
+}
+
+impl fmt::Debug for RegionGuard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {{ {:#x}..{:#x} }}",
+            type_name::<Self>(),
+            self.entry.addr,
+            self.entry.addr.wrapping_add(self.entry.size)
+        )
+    }
+}
+
+/// An error claiming a region.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClaimError {
+    /// The region overlaps a live region, where at least one of them is
+    /// writable.
+    Overlap(Overlap),
+    /// [`CAPACITY`] regions are already claimed.
+    Full,
+}
+
+impl fmt::Display for ClaimError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClaimError::Overlap(overlap) => overlap.fmt(f),
+            ClaimError::Full => write!(f, "{} regions are already claimed", CAPACITY),
+        }
+    }
+}
+
+impl error::Error for ClaimError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            ClaimError::Overlap(overlap) => Some(overlap),
+            ClaimError::Full => None,
+        }
+    }
+}
+
+impl From<Overlap> for ClaimError {
+    fn from(overlap: Overlap) -> Self {
+        ClaimError::Overlap(overlap)
+    }
+}
+
+/// The live region which a claimed region overlaps, where at least one of
+/// them is writable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Overlap {
+    /// The start address of the live region.
+    pub addr: usize,
+    /// The size of the live region in bytes.
+    pub size: usize,
+}
+
+impl fmt::Display for Overlap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "region overlaps live region {:#x}..{:#x}",
+            self.addr,
+            self.addr.wrapping_add(self.size)
+        )
+    }
+}
+
+impl error::Error for Overlap {}
+
+/// Claims the `size` bytes starting at `addr`, which may be written if
+/// `writable` is `true`.
+///
+/// # Errors
+/// Returns [`ClaimError::Overlap`] if the region overlaps a live region, unless
+/// neither is writable, or [`ClaimError::Full`] if [`CAPACITY`] regions are
+/// already claimed.
+pub fn try_claim_raw(addr: usize, size: usize, writable: bool) -> Result<RegionGuard, ClaimError> {
+    let entry = Entry {
+        addr,
+        size,
+        writable,
+    };
+    let index = TABLE.with(|entries| {
+        for live in entries.iter().flatten() {
+            if (writable || live.writable) && overlaps(live, &entry) {
+                return Err(ClaimError::Overlap(Overlap {
+                    addr: live.addr,
+                    size: live.size,
+                }));
+            }
+        }
+        let index = entries
+            .iter()
+            .position(Option::is_none)
+            .ok_or(ClaimError::Full)?;
+        entries[index] = Some(entry);
+        Ok(index)
+    })?;
+    Ok(RegionGuard { index, entry })
+}
+
+/// Claims the `size` bytes starting at `addr`, like [`try_claim_raw`].
+///
+/// # Panics
+/// Panics if the region overlaps a live region, unless neither is writable, or
+/// if [`CAPACITY`] regions are already claimed.
+#[track_caller]
+pub fn claim_raw(addr: usize, size: usize, writable: bool) -> RegionGuard {
+    match try_claim_raw(addr, size, writable) {
+        Ok(guard) => guard,
+        Err(err) => panic!(
+            "cannot claim {:#x}..{:#x}: {}",
+            addr,
+            addr.wrapping_add(size),
+            err
+        ),
+    }
+}
+
+/// Claims the memory of `vol` as read-only.
+///
+/// # Panics
+/// Panics if the memory overlaps a live writable region, or if [`CAPACITY`]
+/// regions are already claimed.
+#[track_caller]
+pub fn claim<T: Copy, P>(vol: &Volatile<T, P>) -> RegionGuard {
+    claim_raw(vol.addr(), mem::size_of::<T>(), false)
+}
+
+/// Claims the memory of `vol` as writable.
+///
+/// # Panics
+/// Panics if the memory overlaps a live region, or if [`CAPACITY`] regions are
+/// already claimed.
+#[track_caller]
+pub fn claim_mut<T: Copy, P>(vol: &mut Volatile<T, P>) -> RegionGuard {
+    claim_raw(vol.addr(), mem::size_of::<T>(), true)
+}
+
+impl<const BASE: usize, const SIZE: usize> VolatileRegion<BASE, SIZE> {
+    /// Claims the memory of the region as writable.
+    ///
+    /// # Panics
+    /// Panics if the region overlaps a live region, or if [`CAPACITY`] regions
+    /// are already claimed.
+    #[track_caller]
+    pub fn claim(&mut self) -> RegionGuard {
+        claim_raw(BASE, SIZE, true)
+    }
+}
+
+fn overlaps(a: &Entry, b: &Entry) -> bool {
+    a.addr < b.addr.wrapping_add(b.size) && b.addr < a.addr.wrapping_add(a.size)
+}
@@ -0,0 +1,28 @@
+use core::fmt;
+
+/// A register whose value differs from its reset value.
+///
+/// This is reported by the `compare_to_reset` method generated by
+/// [`mmio_struct!`](crate::mmio_struct), and is useful when debugging
+/// partially configured peripherals.
+#[derive(Debug, Clone, Copy)]
+pub struct ResetMismatch<'a> {
+    /// The name of the register.
+    pub name: &'static str,
+    /// The offset of the register from the start of its register block.
+    pub offset: usize,
+    /// The value which was read from the register.
+    pub value: &'a dyn fmt::Debug,
+    /// The reset value of the register.
+    pub reset: &'a dyn fmt::Debug,
+}
+
+impl fmt::Display for ResetMismatch<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "`{}` at offset {:#x} is {:#x?}, but its reset value is {:#x?}",
+            self.name, self.offset, self.value, self.reset
+        )
+    }
+}
@@ -0,0 +1,237 @@
+//! Rings of DMA descriptors shared with a device.
+//!
+//! Many controllers, such as those for network interfaces, SD cards, and USB,
+//! process requests from a ring of descriptors in memory. The driver publishes
+//! descriptors to the device in order, and the device marks them as complete in
+//! the same order. [`DescriptorRing`] tracks which descriptors are owned by the
+//! device, and issues the barriers required when publishing and completing
+//! them.
+//!
+//! The [`virtio`] module provides the ring layout of virtio split virtqueues.
+use core::{any::type_name, fmt};
+
+use crate::{barrier, Volatile, VolatileRead, VolatileWrite};
+
+pub mod virtio;
+
+/// A descriptor in a [`DescriptorRing`].
+pub trait Descriptor: Copy {
+    /// Returns `true` if the device has finished processing the descriptor,
+    /// such as when its ownership bit has been cleared.
+    fn is_complete(&self) -> bool;
+
+    /// Returns `self` without ownership passed to the device, such as with its
+    /// ownership bit clear, or `None` if the device does not poll descriptors
+    /// for ownership.
+    ///
+    /// A descriptor is written with a single volatile write, but the compiler
+    /// may split it into writes of its fields in any order. A device which
+    /// polls an ownership bit could then see the bit before the rest of the
+    /// descriptor, such as its buffer address. If this returns a value,
+    /// [`DescriptorRing::publish`] writes it first, issues a
+    /// [device write barrier](barrier::device_write_barrier), and only then
+    /// writes the descriptor itself, which only differs in its ownership.
+    ///
+    /// The default returns `None`, which is only correct for devices which are
+    /// notified of new descriptors through a tail or doorbell register.
+    fn unpublished(&self) -> Option<Self> {
+        None
+    }
+}
+
+/// A ring of descriptors of type `D` in volatile memory, shared with a device.
+///
+/// Descriptors are published to the device in ring order with
+/// [`publish`](Self::publish), and are reclaimed in the same order once the
+/// device has completed them, with [`complete`](Self::complete) or
+/// [`completed`](Self::completed). Descriptors between the two are in flight,
+/// and owned by the device.
+///
+/// # Example
+/// ```
+/// use volatile_mem::{ring::Descriptor, DescriptorRing, Volatile};
+///
+/// #[derive(Clone, Copy)]
+/// struct Desc {
+///     addr: u32,
+///     flags: u32,
+/// }
+///
+/// impl Descriptor for Desc {
+///     fn is_complete(&self) -> bool {
+///         self.flags & 1 == 0
+///     }
+///
+///     fn unpublished(&self) -> Option<Self> {
+///         Some(Desc {
+///             flags: self.flags & !1,
+///             ..*self
+///         })
+///     }
+/// }
+///
+/// let mut mem = [Desc { addr: 0, flags: 0 }; 4];
+/// let descs: &mut Volatile<[Desc; 4]> = Volatile::from_mut(&mut mem);
+/// let mut ring = DescriptorRing::new(descs);
+///
+/// assert_eq!(ring.publish(Desc { addr: 0x1000, flags: 1 }).ok(), Some(0));
+/// assert!(ring.complete().is_none());
+/// ```
+pub struct DescriptorRing<'a, D: Descriptor> {
+    descs: &'a mut [Volatile<D>],
+    head: usize,
+    tail: usize,
+    in_flight: usize,
+}
+
+impl<'a, D: Descriptor> DescriptorRing<'a, D> {
+    /// Creates a ring over `descs`, with no descriptors in flight.
+    pub fn new(descs: &'a mut [Volatile<D>]) -> Self {
+        DescriptorRing {
+            descs,
+            head: 0,
+            tail: 0,
+            in_flight: 0,
+        }
+    }
+
+    /// Returns the number of descriptors in the ring.
+    pub fn capacity(&self) -> usize {
+        self.descs.len()
+    }
+
+    /// Returns the number of descriptors in flight.
+    pub fn in_flight(&self) -> usize {
+        self.in_flight
+    }
+
+    /// Returns the number of descriptors which can be published.
+    pub fn available(&self) -> usize {
+        self.capacity() - self.in_flight
+    }
+
+    /// Returns `true` if no descriptors are in flight.
+    pub fn is_empty(&self) -> bool {
+        self.in_flight == 0
+    }
+
+    /// Returns `true` if no descriptors can be published.
+    pub fn is_full(&self) -> bool {
+        self.available() == 0
+    }
+
+    /// Returns the index of the next descriptor to be published.
+    ///
+    /// This is typically written to the device's tail or doorbell register
+    /// after publishing descriptors.
+    pub fn head(&self) -> usize {
+        self.head
+    }
+
+    /// Returns the index of the oldest descriptor in flight, which is the next
+    /// to be completed.
+    pub fn tail(&self) -> usize {
+        self.tail
+    }
+
+    /// Writes `desc` to the next descriptor in the ring and passes it to the
+    /// device, returning its index.
+    ///
+    /// A [device write barrier](barrier::device_write_barrier) is issued
+    /// after the write, so the descriptor is visible to the device before any
+    /// subsequent write, such as to a doorbell register. If the descriptor
+    /// has an [unpublished](Descriptor::unpublished) form, that is written
+    /// first, followed by a barrier, so that the device does not see the
+    /// change of ownership before the rest of the descriptor.
+    ///
+    /// # Errors
+    /// Returns `desc` if the ring is full.
+    pub fn publish(&mut self, desc: D) -> Result<usize, D> {
+        if self.is_full() {
+            return Err(desc);
+        }
+        let index = self.head;
+        if let Some(body) = desc.unpublished() {
+            self.descs[index].write(body);
+            barrier::device_write_barrier();
+        }
+        self.descs[index].write(desc);
+        barrier::device_write_barrier();
+        self.head = self.next(index);
+        self.in_flight += 1;
+        Ok(index)
+    }
+
+    /// Reads the oldest descriptor in flight, and if the device has completed
+    /// it, reclaims it and returns its index and value.
+    ///
+    /// A [device read barrier](barrier::device_read_barrier) is issued after a
+    /// completed descriptor is read, so that data written by the device, such
+    /// as a received packet, is not read before the descriptor.
+    ///
+    /// Returns `None` if no descriptors are in flight or the oldest is not
+    /// complete.
+    pub fn complete(&mut self) -> Option<(usize, D)> {
+        if self.is_empty() {
+            return None;
+        }
+        let index = self.tail;
+        let desc = self.descs[index].read();
+        if !desc.is_complete() {
+            return None;
+        }
+        barrier::device_read_barrier();
+        self.tail = self.next(index);
+        self.in_flight -= 1;
+        Some((index, desc))
+    }
+
+    /// Returns an iterator which reclaims completed descriptors, in order,
+    /// until one is found which is not complete.
+    pub fn completed(&mut self) -> Completed<'_, 'a, D> {
+        Completed { ring: self }
+    }
+
+    /// Returns the descriptors of the ring.
+    ///
+    /// Writes to descriptors in flight may interfere with the device.
+    pub fn descriptors_mut(&mut self) -> &mut [Volatile<D>] {
+        self.descs
+    }
+
+    fn next(&self, index: usize) -> usize {
+        if index + 1 == self.capacity() {
+            0
+        } else {
+            index + 1
+        }
+    }
+}
+
+impl<D: Descriptor> fmt::Debug for DescriptorRing<'_, D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(type_name::<Self>())
+    }
+}
+
+/// An iterator over the completed descriptors of a [`DescriptorRing`].
+///
+/// This is returned by [`DescriptorRing::completed`]. Each item is the index
+/// and value of a descriptor, which is reclaimed by the ring.
+pub struct Completed<'r, 'a, D: Descriptor> {
+    ring: &'r mut DescriptorRing<'a, D>,
+}
+
+impl<D: Descriptor> Iterator for Completed<'_, '_, D> {
+    type Item = (usize, D);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.ring.complete()
+    }
+}
+
+impl<D: Descriptor> fmt::Debug for Completed<'_, '_, D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(type_name::<Self>())
+    }
+}
@@ -0,0 +1,337 @@
+//! The split virtqueue layout of the virtio specification.
+//!
+//! A split virtqueue of size `N` consists of a descriptor table of `N`
+//! [`VirtqDesc`]s, an available ring, [`VirtqAvail<N>`], which is written by
+//! the driver, and a used ring, [`VirtqUsed<N>`], which is written by the
+//! device. Each is laid out as required by the specification, so they can be
+//! placed in memory shared with the other side, and accessed through
+//! [`Volatile`].
+//!
+//! All values are stored in little-endian byte order, as required by version
+//! 1 of the specification, and converted by the accessors. Indices are 16 bits
+//! and wrap around, so `N` must be a power of two no larger than 32768.
+//! The helpers issue the required barriers: a
+//! [device write barrier](barrier::device_write_barrier) between writing a
+//! ring entry and publishing it by incrementing the index, and a
+//! [device read barrier](barrier::device_read_barrier) between observing a new
+//! index and reading the entry.
+//!
+//! # Example
+//! ```
+//! use volatile_mem::{
+//!     ring::virtio::{VirtqAvail, VirtqUsed},
+//!     Volatile,
+//! };
+//!
+//! let mut avail = VirtqAvail::<4>::new();
+//! let mut used = VirtqUsed::<4>::new();
+//! let avail: &mut Volatile<VirtqAvail<4>> = Volatile::from_mut(&mut avail);
+//! let used: &mut Volatile<VirtqUsed<4>> = Volatile::from_mut(&mut used);
+//!
+//! // The driver makes descriptor 2 available.
+//! avail.push(2);
+//!
+//! // The device takes it, and returns it as used.
+//! let mut last_avail = 0;
+//! let head = avail.pop(&mut last_avail).unwrap();
+//! used.push(head.into(), 64);
+//!
+//! // The driver takes the used element.
+//! let mut last_used = 0;
+//! let elem = used.pop(&mut last_used).unwrap();
+//! assert_eq!((elem.id(), elem.len()), (2, 64));
+//! assert!(used.pop(&mut last_used).is_none());
+//! ```
+use crate::{
+    barrier,
+    volatile::{Read, Write},
+    volatile_project, Volatile, VolatileRead, VolatileWrite,
+};
+
+/// An entry of a virtqueue's descriptor table, describing a buffer.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VirtqDesc {
+    addr: u64,
+    len: u32,
+    flags: u16,
+    next: u16,
+}
+
+impl VirtqDesc {
+    /// Flag indicating that the buffer continues in the descriptor given by
+    /// `next`.
+    pub const F_NEXT: u16 = 1;
+    /// Flag indicating that the buffer is written by the device, rather than
+    /// read.
+    pub const F_WRITE: u16 = 2;
+    /// Flag indicating that the buffer contains a table of indirect
+    /// descriptors.
+    pub const F_INDIRECT: u16 = 4;
+
+    /// Creates a descriptor for the buffer of `len` bytes at the guest physical
+    /// address `addr`.
+    pub const fn new(addr: u64, len: u32, flags: u16, next: u16) -> Self {
+        VirtqDesc {
+            addr: addr.to_le(),
+            len: len.to_le(),
+            flags: flags.to_le(),
+            next: next.to_le(),
+        }
+    }
+
+    /// Returns the guest physical address of the buffer.
+    pub const fn addr(&self) -> u64 {
+        u64::from_le(self.addr)
+    }
+
+    /// Returns the length of the buffer in bytes.
+    pub const fn len(&self) -> u32 {
+        u32::from_le(self.len)
+    }
+
+    /// Returns `true` if the buffer has a length of zero.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the flags of the descriptor.
+    pub const fn flags(&self) -> u16 {
+        u16::from_le(self.flags)
+    }
+
+    /// Returns the index of the next descriptor of the buffer, if the
+    /// [`F_NEXT`](Self::F_NEXT) flag is set.
+    pub const fn next(&self) -> u16 {
+        u16::from_le(self.next)
+    }
+}
+
+/// An element of a virtqueue's used ring, identifying a buffer which the
+/// device has finished with.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VirtqUsedElem {
+    id: u32,
+    len: u32,
+}
+
+impl VirtqUsedElem {
+    /// Creates an element for the buffer whose first descriptor is at index
+    /// `id`, of which `len` bytes were written by the device.
+    pub const fn new(id: u32, len: u32) -> Self {
+        VirtqUsedElem {
+            id: id.to_le(),
+            len: len.to_le(),
+        }
+    }
+
+    /// Returns the index of the first descriptor of the buffer.
+    pub const fn id(&self) -> u32 {
+        u32::from_le(self.id)
+    }
+
+    /// Returns the number of bytes written to the buffer by the device.
+    pub const fn len(&self) -> u32 {
+        u32::from_le(self.len)
+    }
+
+    /// Returns `true` if no bytes were written to the buffer by the device.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+/// The available ring of a virtqueue of size `N`, written by the driver.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct VirtqAvail<const N: usize> {
+    flags: u16,
+    idx: u16,
+    ring: [u16; N],
+    used_event: u16,
+}
+
+impl<const N: usize> VirtqAvail<N> {
+    /// Flag asking the device not to interrupt the driver when it uses a
+    /// buffer.
+    pub const F_NO_INTERRUPT: u16 = 1;
+
+    /// Creates an empty available ring, with no flags set.
+    pub const fn new() -> Self {
+        VirtqAvail {
+            flags: 0,
+            idx: 0,
+            ring: [0; N],
+            used_event: 0,
+        }
+    }
+}
+
+impl<const N: usize> Default for VirtqAvail<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The used ring of a virtqueue of size `N`, written by the device.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct VirtqUsed<const N: usize> {
+    flags: u16,
+    idx: u16,
+    ring: [VirtqUsedElem; N],
+    avail_event: u16,
+}
+
+impl<const N: usize> VirtqUsed<N> {
+    /// Flag asking the driver not to notify the device when it makes a buffer
+    /// available.
+    pub const F_NO_NOTIFY: u16 = 1;
+
+    /// Creates an empty used ring, with no flags set.
+    pub const fn new() -> Self {
+        VirtqUsed {
+            flags: 0,
+            idx: 0,
+            ring: [VirtqUsedElem::new(0, 0); N],
+            avail_event: 0,
+        }
+    }
+}
+
+impl<const N: usize> Default for VirtqUsed<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Returns the position in a ring of size `N` of the entry with index `idx`.
+fn slot<const N: usize>(idx: u16) -> usize {
+    const {
+        assert!(
+            N.is_power_of_two() && N <= 1 << 15,
+            "the size of a virtqueue must be a power of two no larger than 32768"
+        )
+    };
+    usize::from(idx) % N
+}
+
+impl<const N: usize, P: Read> Volatile<VirtqAvail<N>, P> {
+    /// Returns the flags of the available ring.
+    pub fn flags(&self) -> u16 {
+        u16::from_le(volatile_project!(self, .flags).read())
+    }
+
+    /// Returns the index of the next entry to be made available.
+    pub fn idx(&self) -> u16 {
+        u16::from_le(volatile_project!(self, .idx).read())
+    }
+
+    /// Returns the index of the used ring entry after which the driver should
+    /// be interrupted, if event suppression has been negotiated.
+    pub fn used_event(&self) -> u16 {
+        u16::from_le(volatile_project!(self, .used_event).read())
+    }
+
+    /// Reads the next available entry, which is the index of the first
+    /// descriptor of a buffer, for the device.
+    ///
+    /// `last_avail` is the index of the next entry to be taken by the device,
+    /// which is incremented if an entry is returned. Returns `None` if no new
+    /// entries are available.
+    pub fn pop(&self, last_avail: &mut u16) -> Option<u16> {
+        if self.idx() == *last_avail {
+            return None;
+        }
+        barrier::device_read_barrier();
+        let head = volatile_project!(self, .ring)[slot::<N>(*last_avail)].read();
+        *last_avail = last_avail.wrapping_add(1);
+        Some(u16::from_le(head))
+    }
+}
+
+impl<const N: usize, P: Write> Volatile<VirtqAvail<N>, P> {
+    /// Sets the flags of the available ring.
+    pub fn set_flags(&mut self, flags: u16) {
+        volatile_project!(mut self, .flags).write(flags.to_le());
+    }
+
+    /// Sets the index of the used ring entry after which the driver should be
+    /// interrupted, if event suppression has been negotiated.
+    pub fn set_used_event(&mut self, used_event: u16) {
+        volatile_project!(mut self, .used_event).write(used_event.to_le());
+    }
+}
+
+impl<const N: usize, P: Read + Write> Volatile<VirtqAvail<N>, P> {
+    /// Makes the buffer whose first descriptor has index `head` available to
+    /// the device.
+    ///
+    /// The device should then be notified, unless it has asked not to be.
+    pub fn push(&mut self, head: u16) {
+        let idx = self.idx();
+        volatile_project!(mut self, .ring)[slot::<N>(idx)].write(head.to_le());
+        barrier::device_write_barrier();
+        volatile_project!(mut self, .idx).write(idx.wrapping_add(1).to_le());
+    }
+}
+
+impl<const N: usize, P: Read> Volatile<VirtqUsed<N>, P> {
+    /// Returns the flags of the used ring.
+    pub fn flags(&self) -> u16 {
+        u16::from_le(volatile_project!(self, .flags).read())
+    }
+
+    /// Returns the index of the next entry to be used.
+    pub fn idx(&self) -> u16 {
+        u16::from_le(volatile_project!(self, .idx).read())
+    }
+
+    /// Returns the index of the available ring entry after which the device
+    /// should be notified, if event suppression has been negotiated.
+    pub fn avail_event(&self) -> u16 {
+        u16::from_le(volatile_project!(self, .avail_event).read())
+    }
+
+    /// Reads the next used element, for the driver.
+    ///
+    /// `last_used` is the index of the next element to be taken by the driver,
+    /// which is incremented if an element is returned. Returns `None` if no new
+    /// elements have been used.
+    pub fn pop(&self, last_used: &mut u16) -> Option<VirtqUsedElem> {
+        if self.idx() == *last_used {
+            return None;
+        }
+        barrier::device_read_barrier();
+        let elem = volatile_project!(self, .ring)[slot::<N>(*last_used)].read();
+        *last_used = last_used.wrapping_add(1);
+        Some(elem)
+    }
+}
+
+impl<const N: usize, P: Write> Volatile<VirtqUsed<N>, P> {
+    /// Sets the flags of the used ring.
+    pub fn set_flags(&mut self, flags: u16) {
+        volatile_project!(mut self, .flags).write(flags.to_le());
+    }
+
+    /// Sets the index of the available ring entry after which the device should
+    /// be notified, if event suppression has been negotiated.
+    pub fn set_avail_event(&mut self, avail_event: u16) {
+        volatile_project!(mut self, .avail_event).write(avail_event.to_le());
+    }
+}
+
+impl<const N: usize, P: Read + Write> Volatile<VirtqUsed<N>, P> {
+    /// Returns the buffer whose first descriptor has index `id` to the driver,
+    /// with `len` bytes written to it.
+    ///
+    /// The driver should then be interrupted, unless it has asked not to be.
+    pub fn push(&mut self, id: u32, len: u32) {
+        let idx = self.idx();
+        volatile_project!(mut self, .ring)[slot::<N>(idx)].write(VirtqUsedElem::new(id, len));
+        barrier::device_write_barrier();
+        volatile_project!(mut self, .idx).write(idx.wrapping_add(1).to_le());
+    }
+}
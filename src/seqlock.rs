@@ -0,0 +1,105 @@
+use core::{
+    any::type_name,
+    fmt, hint,
+    mem::MaybeUninit,
+    sync::atomic::{fence, Ordering},
+};
+
+use crate::{access, volatile::Read, Volatile, VolatileRead, VolatileReadOnly};
+
+/// A reader of data protected by a sequence lock, such as in memory shared
+/// with another core or a hypervisor.
+///
+/// The writer increments the sequence counter before and after each update of
+/// the data, so the counter is odd while an update is in progress. A
+/// consistent snapshot is one for which the counter was even, and unchanged,
+/// both before and after the data was read.
+///
+/// The reads of the counter and the data are ordered with
+/// [acquire fences](core::sync::atomic::fence), which only order accesses
+/// between CPUs, such as another core or a hypervisor running on one. They are
+/// not device barriers, so this is not suitable for data written by a device,
+/// such as a DMA engine. For such data, the reads must instead be separated by
+/// [`device_read_barrier`](crate::barrier::device_read_barrier).
+///
+/// # Example
+/// ```
+/// use volatile_mem::{SeqlockReader, Volatile};
+///
+/// let seq: &Volatile<u32> = Volatile::from_ref(&2);
+/// let data: &Volatile<(u32, u32)> = Volatile::from_ref(&(1, 2));
+/// let reader = SeqlockReader::new(seq, data);
+/// assert_eq!(reader.read(), (1, 2));
+/// ```
+pub struct SeqlockReader<'a, T: Copy> {
+    seq: &'a VolatileReadOnly<u32>,
+    data: &'a VolatileReadOnly<T>,
+}
+
+impl<'a, T: Copy> SeqlockReader<'a, T> {
+    /// Creates a reader of `data`, which is protected by the sequence counter
+    /// `seq`.
+    pub fn new<P: Read, Q: Read>(seq: &'a Volatile<u32, P>, data: &'a Volatile<T, Q>) -> Self {
+        SeqlockReader {
+            seq: seq.as_read_only(),
+            data: data.as_read_only(),
+        }
+    }
+
+    /// Reads a consistent snapshot of the data, retrying until no update
+    /// occurs during the read.
+    ///
+    /// This spins for as long as the writer is updating the data.
+    pub fn read(&self) -> T {
+        loop {
+            if let Some(value) = self.try_read() {
+                return value;
+            }
+            hint::spin_loop();
+        }
+    }
+
+    /// Reads a snapshot of the data once, returning `None` if an update was in
+    /// progress or occurred during the read.
+    ///
+    /// The reads are ordered with acquire fences, which are not device
+    /// barriers, as described for [`SeqlockReader`].
+    pub fn try_read(&self) -> Option<T> {
+        let before = self.seq.read();
+        if before & 1 != 0 {
+            return None;
+        }
+        fence(Ordering::Acquire);
+        // SAFETY: `self.data` is a reference, so it is valid for reads. The data
+        // is read as `MaybeUninit<T>`, so a torn read, which may not be a valid
+        // `T`, is not undefined behavior unless it is assumed to be
+        // initialized.
+        let value = unsafe {
+            access::read(self.data as *const VolatileReadOnly<T> as *const MaybeUninit<T>)
+        };
+        fence(Ordering::Acquire);
+        if self.seq.read() != before {
+            return None;
+        }
+        // SAFETY: The counter was even and unchanged, so no update occurred
+        // during the read, and the value is a valid `T`.
+        Some(unsafe { value.assume_init() })
+    }
+
+    /// Returns the sequence counter.
+    pub fn seq(&self) -> &'a VolatileReadOnly<u32> {
+        self.seq
+    }
+
+    /// Returns the data, through which reads are not protected by the
+    /// sequence counter.
+    pub fn data(&self) -> &'a VolatileReadOnly<T> {
+        self.data
+    }
+}
+
+impl<T: Copy> fmt::Debug for SeqlockReader<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(type_name::<Self>())
+    }
+}
@@ -0,0 +1,141 @@
+use core::{any::type_name, cell::UnsafeCell, fmt, marker::PhantomData};
+
+use crate::{
+    access,
+    volatile::{Read, ReadWrite, Write},
+    Volatile, VolatileData, VolatileRead, VolatileWrite,
+};
+
+/// Volatile data or memory which can be written through a shared reference.
+///
+/// Unlike [`Volatile`], which requires a mutable reference for writes, this
+/// allows writes through [`write_shared`](Self::write_shared), which takes
+/// `&self`. This is intended for contexts, such as interrupt handlers, where
+/// obtaining a mutable reference is impractical. The data is contained in an
+/// [`UnsafeCell`], so writes through a shared reference are permitted.
+///
+/// As a consequence, any shared reference to a `VolatileShared` may observe
+/// writes made through another shared reference. A `VolatileShared` must not
+/// be created from a shared reference to the same memory, such as a `&T` or a
+/// `&Volatile<T>`, since such references assume the memory is not written
+/// while they exist.
+///
+/// `VolatileShared` is not [`Sync`], so it can still only be accessed from one
+/// thread at a time, unless it is wrapped in a
+/// [`SyncVolatile`](crate::SyncVolatile). A race between a write operation and
+/// any other operation to the same location is undefined behavior.
+///
+/// See [crate-level documentation](crate) for details.
+#[repr(transparent)]
+pub struct VolatileShared<T: Copy, Permission = ReadWrite> {
+    data: UnsafeCell<T>,
+    _perm: PhantomData<Permission>,
+}
+
+impl<T: Copy, P> VolatileShared<T, P> {
+    /// Creates a `VolatileShared` containing `val`.
+    ///
+    /// This is intended for statics, such as a flag shared with an interrupt
+    /// handler through a [`SyncVolatile`](crate::SyncVolatile).
+    pub const fn new(val: T) -> Self {
+        VolatileShared {
+            data: UnsafeCell::new(val),
+            _perm: PhantomData,
+        }
+    }
+
+    /// Converts a mutable pointer to `T` into a shared reference to
+    /// `VolatileShared<T>`.
+    ///
+    /// # Safety
+    /// Behavior is undefined if any of the following conditions are violated:
+    ///
+    /// - `mem` must be [valid](core::ptr#safety) for reads and/or writes.
+    ///
+    /// - `mem` must be properly aligned.
+    ///
+    /// - `mem` must point to a properly initialized value of type `T` (unless
+    ///   the resulting `VolatileShared<T>` is write-only).
+    ///
+    /// - The memory must not be accessed through any reference other than a
+    ///   `VolatileShared` while the returned reference exists.
+    ///
+    /// Note that even if `T` has size zero, the pointer must be non-NULL and
+    /// properly aligned.
+    pub const unsafe fn from_mut_ptr<'a>(mem: *mut T) -> &'a Self {
+        // SAFETY: The caller must ensure the pointer is safe to use. It is
+        // safe to cast to `*const Self` because `Self` is transparent.
+        unsafe { &*(mem as *const Self) }
+    }
+
+    /// Converts a mutable reference to a [`Volatile`] into a shared reference
+    /// to a `VolatileShared`.
+    pub const fn from_volatile_mut(vol: &mut Volatile<T, P>) -> &Self {
+        // SAFETY: `vol` is a mutable reference, so the memory cannot be
+        // accessed other than through the returned reference while it exists.
+        // It is safe to cast to `*const Self` because both types are
+        // transparent.
+        unsafe { &*(vol as *mut Volatile<T, P> as *const Self) }
+    }
+
+    /// Returns a mutable reference to the underlying [`Volatile`].
+    pub const fn as_volatile_mut(&mut self) -> &mut Volatile<T, P> {
+        // SAFETY: `self` is a mutable reference. It is safe to cast to
+        // `*mut Volatile<T, P>` because both types are transparent.
+        unsafe { &mut *(self as *mut Self as *mut Volatile<T, P>) }
+    }
+}
+
+impl<T: Copy, P: Write> VolatileShared<T, P> {
+    /// Performs a volatile write of `self` with the given value without reading
+    /// the old value.
+    ///
+    /// Unlike [`write`](VolatileWrite::write), this only requires a shared
+    /// reference.
+    ///
+    /// # Safety
+    /// Just like in C, whether an operation is volatile has no bearing
+    /// whatsoever on questions involving concurrent access from multiple
+    /// threads. Volatile accesses behave exactly like non-atomic accesses in
+    /// that regard. In particular, a race between a write operation any other
+    /// operation (reading or writing) to the same location is undefined
+    /// behavior.
+    pub fn write_shared(&self, val: T) {
+        // SAFETY: The data is contained in an `UnsafeCell`, so it may be
+        // written through a shared reference. `T` is safe to write since it
+        // is `Copy`.
+        unsafe { access::write(self.data.get(), val) }
+    }
+}
+
+impl<T: Copy, P> VolatileData<T> for VolatileShared<T, P> {}
+
+impl<T: Copy, P: Read> VolatileRead<T> for VolatileShared<T, P> {
+    /// Performs a volatile read of the value in `self` without moving it. This
+    /// leaves the memory in `self` unchanged.
+    fn read(&self) -> T {
+        // SAFETY: `T` is safe to read since it is `Copy` and guaranteed to be
+        // initialized.
+        unsafe { access::read(self.data.get()) }
+    }
+}
+
+impl<T: Copy, P: Write> VolatileWrite<T> for VolatileShared<T, P> {
+    /// Performs a volatile write of `self` with the given value without reading
+    /// the old value.
+    fn write(&mut self, val: T) {
+        self.write_shared(val);
+    }
+}
+
+impl<'a, T: Copy, P> From<&'a mut Volatile<T, P>> for &'a VolatileShared<T, P> {
+    fn from(vol: &'a mut Volatile<T, P>) -> Self {
+        VolatileShared::from_volatile_mut(vol)
+    }
+}
+
+impl<T: Copy, P> fmt::Debug for VolatileShared<T, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(type_name::<Self>())
+    }
+}
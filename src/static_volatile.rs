@@ -0,0 +1,100 @@
+#[cfg(target_has_atomic = "8")]
+use core::sync::atomic::{AtomicBool, Ordering};
+use core::{any::type_name, cell::UnsafeCell, fmt, marker::PhantomData, mem::MaybeUninit};
+
+use crate::{
+    volatile::{ReadWrite, WriteOnly},
+    Volatile,
+};
+
+/// Storage for volatile memory in a `static`, which hands out a single mutable
+/// reference to it, as declared by [`static_volatile!`](crate::static_volatile).
+///
+/// This is intended for memory reserved in a special linker section, such as
+/// memory shared with another core or buffers used by a DMA engine, which must
+/// be accessed volatilely.
+///
+/// # Example
+/// ```
+/// use volatile_mem::{static_volatile, Volatile, VolatileRead, VolatileWrite};
+///
+/// static_volatile! {
+///     static BUFFER: Volatile<[u8; 4]> = [1, 2, 3, 4];
+/// }
+///
+/// let buffer = BUFFER.take().unwrap();
+/// buffer[0].write(5);
+/// assert_eq!(buffer.read(), [5, 2, 3, 4]);
+/// assert!(BUFFER.take().is_none());
+/// ```
+pub struct StaticVolatile<T: Copy, Permission = ReadWrite> {
+    data: UnsafeCell<MaybeUninit<T>>,
+    #[cfg(target_has_atomic = "8")]
+    taken: AtomicBool,
+    _perm: PhantomData<Permission>,
+}
+
+impl<T: Copy, P> StaticVolatile<T, P> {
+    /// Creates storage initialized with `val`.
+    pub const fn new(val: T) -> Self {
+        StaticVolatile {
+            data: UnsafeCell::new(MaybeUninit::new(val)),
+            #[cfg(target_has_atomic = "8")]
+            taken: AtomicBool::new(false),
+            _perm: PhantomData,
+        }
+    }
+
+    /// Returns the mutable reference to the memory the first time it is
+    /// called, or `None` after that.
+    #[cfg(target_has_atomic = "8")]
+    #[allow(clippy::mut_from_ref)]
+    pub fn take(&'static self) -> Option<&'static mut Volatile<T, P>> {
+        if self.taken.swap(true, Ordering::AcqRel) {
+            return None;
+        }
+        // SAFETY: This is the only reference ever returned, and the memory is
+        // either initialized or write-only.
+        Some(unsafe { self.take_unchecked() })
+    }
+
+    /// Returns a mutable reference to the memory without checking whether one
+    /// has already been returned.
+    ///
+    /// # Safety
+    /// No other reference returned by [`take`](Self::take) or
+    /// `take_unchecked` may be in use while the returned reference exists.
+    #[allow(clippy::mut_from_ref)]
+    pub unsafe fn take_unchecked(&'static self) -> &'static mut Volatile<T, P> {
+        // SAFETY: The caller must ensure the reference is unique. It is safe to
+        // cast to `*mut T` because `MaybeUninit` is transparent.
+        unsafe { Volatile::from_mut_ptr(self.data.get() as *mut T) }
+    }
+
+    /// Returns a pointer to the memory, such as to program a DMA engine.
+    pub const fn as_ptr(&self) -> *mut T {
+        self.data.get() as *mut T
+    }
+}
+
+impl<T: Copy> StaticVolatile<T, WriteOnly> {
+    /// Creates uninitialized storage, which can only be written.
+    pub const fn uninit() -> Self {
+        StaticVolatile {
+            data: UnsafeCell::new(MaybeUninit::uninit()),
+            #[cfg(target_has_atomic = "8")]
+            taken: AtomicBool::new(false),
+            _perm: PhantomData,
+        }
+    }
+}
+
+// SAFETY: Only one mutable reference to the data is ever handed out, so it is
+// only accessed from one thread at a time.
+unsafe impl<T: Copy + Send, P> Sync for StaticVolatile<T, P> {}
+
+impl<T: Copy, P> fmt::Debug for StaticVolatile<T, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(type_name::<Self>())
+    }
+}
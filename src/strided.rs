@@ -0,0 +1,192 @@
+use core::{
+    any::type_name,
+    fmt,
+    mem::{self, MaybeUninit},
+    ops::{Index, IndexMut},
+};
+
+use crate::{volatile::ReadWrite, Volatile};
+
+/// An array of `N` volatile elements of type `T`, each located `STRIDE` bytes
+/// after the previous one.
+///
+/// This models registers which are replicated at a regular interval, such as
+/// channel banks, without having to model the gaps between them. The gaps are
+/// never accessed. The size of the array is `N * STRIDE` bytes and its
+/// alignment is that of `T`, so it can be used as a field of a `#[repr(C)]`
+/// register struct.
+///
+/// `STRIDE` must be a multiple of the alignment of `T` and must be at least the
+/// size of `T`. This is checked at compile time when an element is accessed.
+///
+/// See [crate-level documentation](crate) for details.
+#[repr(C)]
+pub struct VolatileStridedArray<
+    T: Copy,
+    const N: usize,
+    const STRIDE: usize,
+    Permission = ReadWrite,
+> {
+    _align: [Volatile<T, Permission>; 0],
+    _data: [[MaybeUninit<u8>; STRIDE]; N],
+}
+
+impl<T: Copy, P, const N: usize, const STRIDE: usize> VolatileStridedArray<T, N, STRIDE, P> {
+    /// Converts a pointer to `T` into a reference to a strided array which
+    /// begins at that location.
+    ///
+    /// # Safety
+    /// Behavior is undefined if any of the following conditions are violated:
+    ///
+    /// - The entire range of `N * STRIDE` bytes starting at `mem`, including
+    ///   the gaps between the elements and after the last one, must be
+    ///   [dereferenceable](core::ptr#safety), and must not be accessed through
+    ///   any mutable reference while the returned reference exists.
+    ///
+    /// - Each element must be [valid](core::ptr#safety) for reads and/or
+    ///   writes.
+    ///
+    /// - `mem` must be properly aligned.
+    ///
+    /// - Each element must be a properly initialized value of type `T` (unless
+    ///   the strided array is write-only).
+    ///
+    /// Just like in C, whether an operation is volatile has no bearing
+    /// whatsoever on questions involving concurrent access from multiple
+    /// threads. Volatile accesses behave exactly like non-atomic accesses in
+    /// that regard. In particular, a race between a write operation any other
+    /// operation (reading or writing) to the same location is undefined
+    /// behavior.
+    pub const unsafe fn from_ptr<'a>(mem: *const T) -> &'a Self {
+        // SAFETY: The caller must ensure the pointer is safe to use.
+        unsafe { &*(mem as *const Self) }
+    }
+
+    /// Converts a mutable pointer to `T` into a mutable reference to a strided
+    /// array which begins at that location.
+    ///
+    /// # Safety
+    /// Behavior is undefined if any of the following conditions are violated:
+    ///
+    /// - The entire range of `N * STRIDE` bytes starting at `mem`, including
+    ///   the gaps between the elements and after the last one, must be
+    ///   [dereferenceable](core::ptr#safety), and must not be accessed through
+    ///   any other reference while the returned reference exists.
+    ///
+    /// - Each element must be [valid](core::ptr#safety) for reads and/or
+    ///   writes.
+    ///
+    /// - `mem` must be properly aligned.
+    ///
+    /// - Each element must be a properly initialized value of type `T` (unless
+    ///   the strided array is write-only).
+    pub const unsafe fn from_mut_ptr<'a>(mem: *mut T) -> &'a mut Self {
+        // SAFETY: The caller must ensure the pointer is safe to use.
+        unsafe { &mut *(mem as *mut Self) }
+    }
+
+    /// Returns the number of elements in the array.
+    pub const fn len(&self) -> usize {
+        N
+    }
+
+    /// Returns `true` if the array has no elements.
+    pub const fn is_empty(&self) -> bool {
+        N == 0
+    }
+
+    /// Returns a reference to the element at `index`, or `None` if it is out of
+    /// bounds.
+    pub fn get(&self, index: usize) -> Option<&Volatile<T, P>> {
+        if index < N {
+            let ptr = (self as *const Self as *const u8).wrapping_add(Self::offset(index));
+            // SAFETY: The element is in bounds and properly aligned. Its
+            // validity is guaranteed by the creator of `self`.
+            Some(unsafe { &*(ptr as *const Volatile<T, P>) })
+        } else {
+            None
+        }
+    }
+
+    /// Returns a mutable reference to the element at `index`, or `None` if it
+    /// is out of bounds.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut Volatile<T, P>> {
+        if index < N {
+            let ptr = (self as *mut Self as *mut u8).wrapping_add(Self::offset(index));
+            // SAFETY: The element is in bounds and properly aligned. Its
+            // validity is guaranteed by the creator of `self`.
+            Some(unsafe { &mut *(ptr as *mut Volatile<T, P>) })
+        } else {
+            None
+        }
+    }
+
+    /// Returns an iterator over the elements of the array.
+    pub fn iter(&self) -> impl Iterator<Item = &Volatile<T, P>> + '_ {
+        (0..N).map(move |i| &self[i])
+    }
+
+    /// Returns an iterator over mutable references to the elements of the
+    /// array.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut Volatile<T, P>> + '_ {
+        let base = self as *mut Self as *mut u8;
+        (0..N).map(move |i| {
+            let ptr = base.wrapping_add(Self::offset(i));
+            // SAFETY: Each element is in bounds, properly aligned, and
+            // returned only once, so the references do not alias.
+            unsafe { &mut *(ptr as *mut Volatile<T, P>) }
+        })
+    }
+
+    const fn offset(index: usize) -> usize {
+        const {
+            assert!(
+                STRIDE >= mem::size_of::<T>(),
+                "the stride must be at least the size of the element type"
+            );
+            assert!(
+                STRIDE.is_multiple_of(mem::align_of::<T>()),
+                "the stride must be a multiple of the alignment of the element type"
+            );
+        }
+        index * STRIDE
+    }
+}
+
+impl<T: Copy, P, const N: usize, const STRIDE: usize> Index<usize>
+    for VolatileStridedArray<T, N, STRIDE, P>
+{
+    type Output = Volatile<T, P>;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        match self.get(index) {
+            Some(elem) => elem,
+            None => panic!(
+                "index out of bounds: the len is {} but the index is {}",
+                N, index
+            ),
+        }
+    }
+}
+
+impl<T: Copy, P, const N: usize, const STRIDE: usize> IndexMut<usize>
+    for VolatileStridedArray<T, N, STRIDE, P>
+{
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        match self.get_mut(index) {
+            Some(elem) => elem,
+            None => panic!(
+                "index out of bounds: the len is {} but the index is {}",
+                N, index
+            ),
+        }
+    }
+}
+
+impl<T: Copy, P, const N: usize, const STRIDE: usize> fmt::Debug
+    for VolatileStridedArray<T, N, STRIDE, P>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(type_name::<Self>())
+    }
+}
@@ -0,0 +1,81 @@
+use core::{
+    any::type_name,
+    fmt,
+    ops::{Deref, DerefMut},
+};
+
+/// A wrapper which implements [`Send`] and [`Sync`] for volatile data which is
+/// externally synchronized.
+///
+/// A [`VolatileShared`](crate::VolatileShared) is not `Sync`, so it cannot be
+/// put in a `static` and shared with an interrupt handler, even when accesses
+/// are known not to race, such as on a single-core system where the handler is
+/// masked while the data is accessed. `SyncVolatile` carries the caller's
+/// promise of external synchronization, which is made when it is created, and
+/// dereferences to the wrapped data.
+///
+/// The wrapper is only `Send` and `Sync` if the wrapped data is `Send`, which
+/// is the case for the volatile types of this crate, and references to them,
+/// if the data they contain is.
+///
+/// # Example
+/// ```
+/// use volatile_mem::{SyncVolatile, VolatileRead, VolatileShared};
+///
+/// // SAFETY: `FLAG` is only accessed from one thread.
+/// static FLAG: SyncVolatile<VolatileShared<u32>> =
+///     unsafe { SyncVolatile::new(VolatileShared::new(0)) };
+///
+/// FLAG.write_shared(1);
+/// assert_eq!(FLAG.read(), 1);
+/// ```
+#[repr(transparent)]
+pub struct SyncVolatile<V> {
+    inner: V,
+}
+
+impl<V> SyncVolatile<V> {
+    /// Wraps `inner`, allowing it to be sent and shared between threads.
+    ///
+    /// # Safety
+    /// All accesses to `inner`, through the wrapper or otherwise, must be
+    /// externally synchronized, such that no two accesses race, where at least
+    /// one of them is a write. This includes accesses from interrupt handlers.
+    pub const unsafe fn new(inner: V) -> Self {
+        SyncVolatile { inner }
+    }
+
+    /// Unwraps the data.
+    pub fn into_inner(self) -> V {
+        self.inner
+    }
+}
+
+// SAFETY: `V` is `Send`, so the wrapper may be as well.
+unsafe impl<V: Send> Send for SyncVolatile<V> {}
+// SAFETY: The creator of the wrapper must ensure accesses are externally
+// synchronized, so sharing a reference only allows synchronized accesses from
+// other threads. `V` is `Send`, so such accesses are allowed from any thread,
+// which excludes types such as `Rc`, which must not be used from other threads
+// however the accesses are synchronized.
+unsafe impl<V: Send> Sync for SyncVolatile<V> {}
+
+impl<V> Deref for SyncVolatile<V> {
+    type Target = V;
+
+    fn deref(&self) -> &V {
+        &self.inner
+    }
+}
+
+impl<V> DerefMut for SyncVolatile<V> {
+    fn deref_mut(&mut self) -> &mut V {
+        &mut self.inner
+    }
+}
+
+impl<V> fmt::Debug for SyncVolatile<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(type_name::<Self>())
+    }
+}
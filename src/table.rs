@@ -0,0 +1,260 @@
+//! Reading firmware tables, such as those of ACPI, SMBIOS, and the MultiProcessor
+//! Specification, from volatile memory.
+//!
+//! These tables share a common shape: a header giving the total length of the
+//! table, a checksum which makes the bytes of the table sum to zero, and a
+//! sequence of fixed-size entries following the header. A [`Table`] validates
+//! the length and checksum of such a table in a region of volatile bytes, and
+//! then provides its [`header`](Table::header) and an iterator over its
+//! [`entries`](Table::entries). All data is read with volatile reads of
+//! individual bytes, so the header and entries need not be aligned.
+//!
+//! # Example
+//! ```
+//! use volatile_mem::{
+//!     table::{Plain, Table, TableHeader},
+//!     Volatile,
+//! };
+//!
+//! #[derive(Clone, Copy)]
+//! #[repr(C)]
+//! struct Header {
+//!     signature: [u8; 4],
+//!     length: [u8; 4],
+//!     checksum: u8,
+//!     _reserved: [u8; 3],
+//! }
+//!
+//! // SAFETY: `Header` consists only of bytes, so every bit pattern is valid.
+//! unsafe impl Plain for Header {}
+//!
+//! impl TableHeader for Header {
+//!     fn length(&self) -> usize {
+//!         u32::from_le_bytes(self.length) as usize
+//!     }
+//! }
+//!
+//! let mut mem = [0u8; 20];
+//! mem[..4].copy_from_slice(b"TEST");
+//! mem[4] = 20;
+//! mem[12..].copy_from_slice(&[1, 0, 0, 0, 2, 0, 0, 0]);
+//! mem[8] = 0u8.wrapping_sub(mem.iter().fold(0, |sum: u8, b| sum.wrapping_add(*b)));
+//!
+//! let region: &Volatile<[u8; 20]> = Volatile::from_mut(&mut mem);
+//! let table = Table::<Header, u32>::new(region)?;
+//! assert_eq!(table.header().signature, *b"TEST");
+//! assert!(table.entries().eq([1, 2]));
+//! # Ok::<(), volatile_mem::table::TableError>(())
+//! ```
+use core::{any::type_name, error, fmt, iter::FusedIterator, marker::PhantomData, mem};
+
+use crate::{
+    volatile::{Read, ReadWrite},
+    Volatile, VolatileChecksum, VolatileRead,
+};
+
+/// A type for which every bit pattern of its size is a valid value, so that it
+/// can be read from arbitrary bytes.
+///
+/// This is implemented for integers and arrays of such types.
+///
+/// # Safety
+/// Every bit pattern of `size_of::<Self>()` bytes must be a valid value of the
+/// type. In particular, the type must not contain padding, references,
+/// `bool`, `char`, or enums.
+pub unsafe trait Plain: Copy {}
+
+macro_rules! impl_plain {
+    ($($ty:ty),*) => {
+        $(
+            // SAFETY: Every bit pattern is a valid integer.
+            unsafe impl Plain for $ty {}
+        )*
+    };
+}
+
+impl_plain!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+// SAFETY: An array has no padding between its elements, so every bit pattern
+// is valid if it is valid for the elements.
+unsafe impl<T: Plain, const N: usize> Plain for [T; N] {}
+
+/// The header of a firmware table.
+pub trait TableHeader: Plain {
+    /// Returns the total length of the table in bytes, including the header.
+    fn length(&self) -> usize;
+}
+
+/// An error returned when a firmware table is not valid.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TableError {
+    /// The length of the table is less than the size of its header, or
+    /// greater than the size of the region containing it.
+    Length {
+        /// The length of the table, or the size of the header if the region is
+        /// too small to contain one.
+        length: usize,
+        /// The size of the region in bytes.
+        available: usize,
+    },
+    /// The bytes of the table do not sum to zero.
+    Checksum {
+        /// The sum of the bytes, wrapping around on overflow.
+        sum: u8,
+    },
+}
+
+impl fmt::Display for TableError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            TableError::Length { length, available } => write!(
+                f,
+                "table of {} bytes does not fit in a region of {} bytes",
+                length, available
+            ),
+            TableError::Checksum { sum } => {
+                write!(f, "table has a checksum of {:#04x} instead of zero", sum)
+            }
+        }
+    }
+}
+
+impl error::Error for TableError {}
+
+/// A validated firmware table, with a header of type `H`, followed by entries
+/// of type `E`.
+///
+/// See the [module-level documentation](self) for details.
+pub struct Table<'a, H, E, P = ReadWrite> {
+    bytes: &'a [Volatile<u8, P>],
+    _types: PhantomData<(H, E)>,
+}
+
+impl<'a, H: TableHeader, E: Plain, P: Read> Table<'a, H, E, P> {
+    /// Validates the table at the start of `region`.
+    ///
+    /// The table is valid if its length, as given by its header, is at least
+    /// the size of the header and at most the size of `region`, and if its
+    /// bytes sum to zero. The bytes of `region` past the end of the table are
+    /// not read.
+    ///
+    /// # Errors
+    /// Returns [`TableError::Length`] if the length is invalid, or
+    /// [`TableError::Checksum`] if the bytes do not sum to zero.
+    pub fn new(region: &'a [Volatile<u8, P>]) -> Result<Self, TableError> {
+        let available = region.len();
+        let header_size = mem::size_of::<H>();
+        if header_size > available {
+            return Err(TableError::Length {
+                length: header_size,
+                available,
+            });
+        }
+        let length = read_bytes::<H, P>(region).length();
+        if length < header_size || length > available {
+            return Err(TableError::Length { length, available });
+        }
+        let bytes = &region[..length];
+        match bytes.checksum8() {
+            0 => Ok(Table {
+                bytes,
+                _types: PhantomData,
+            }),
+            sum => Err(TableError::Checksum { sum }),
+        }
+    }
+
+    /// Reads the header of the table.
+    pub fn header(&self) -> H {
+        read_bytes(self.bytes)
+    }
+
+    /// Returns the bytes of the table, including the header.
+    pub fn bytes(&self) -> &'a [Volatile<u8, P>] {
+        self.bytes
+    }
+
+    /// Returns an iterator which reads the entries of the table.
+    ///
+    /// Any bytes at the end of the table which are too few for an entry are
+    /// ignored.
+    pub fn entries(&self) -> Entries<'a, E, P> {
+        Entries {
+            bytes: &self.bytes[mem::size_of::<H>()..],
+            _entry: PhantomData,
+        }
+    }
+}
+
+impl<H, E, P> fmt::Debug for Table<'_, H, E, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {{ {:p}, {:#x} }}",
+            type_name::<Self>(),
+            self.bytes.as_ptr(),
+            self.bytes.len()
+        )
+    }
+}
+
+/// An iterator over the entries of a [`Table`].
+///
+/// This is returned by [`Table::entries`]. Each entry is read when it is
+/// returned.
+pub struct Entries<'a, E, P = ReadWrite> {
+    bytes: &'a [Volatile<u8, P>],
+    _entry: PhantomData<E>,
+}
+
+impl<E: Plain, P: Read> Iterator for Entries<'_, E, P> {
+    type Item = E;
+
+    fn next(&mut self) -> Option<E> {
+        let size = mem::size_of::<E>();
+        if size == 0 || self.bytes.len() < size {
+            return None;
+        }
+        let (entry, rest) = self.bytes.split_at(size);
+        self.bytes = rest;
+        Some(read_bytes(entry))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<E: Plain, P: Read> ExactSizeIterator for Entries<'_, E, P> {
+    fn len(&self) -> usize {
+        match mem::size_of::<E>() {
+            0 => 0,
+            size => self.bytes.len() / size,
+        }
+    }
+}
+
+impl<E: Plain, P: Read> FusedIterator for Entries<'_, E, P> {}
+
+impl<E, P> fmt::Debug for Entries<'_, E, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(type_name::<Self>())
+    }
+}
+
+/// Reads a `T` from the first `size_of::<T>()` bytes of `bytes`, which must
+/// contain at least that many.
+fn read_bytes<T: Plain, P: Read>(bytes: &[Volatile<u8, P>]) -> T {
+    let mut val = mem::MaybeUninit::<T>::uninit();
+    let dst = val.as_mut_ptr() as *mut u8;
+    for (i, byte) in bytes[..mem::size_of::<T>()].iter().enumerate() {
+        // SAFETY: `i` is less than the size of `T`, so the write is within
+        // `val`.
+        unsafe { dst.add(i).write(byte.read()) };
+    }
+    // SAFETY: Every byte of `val` has been written, and every bit pattern is a
+    // valid `T`.
+    unsafe { val.assume_init() }
+}
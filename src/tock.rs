@@ -0,0 +1,266 @@
+//! Interoperability with [`tock-registers`](tock_registers).
+//!
+//! [`Volatile`] and [`VolatileShared`] implement [`Readable`] with a
+//! [`RegisterLongName`] of `()`, and [`VolatileShared`] also implements
+//! [`Writeable`], and so [`ReadWriteable`], since it can be written through a
+//! shared reference. To use the typed fields generated by
+//! `tock_registers::register_bitfields!`, use [`SharedRegister`], which wraps a
+//! [`VolatileShared`] along with the register's [`RegisterLongName`], so that
+//! existing drivers written against `tock-registers` only need to change the
+//! types of their registers.
+//!
+//! [`Register`] similarly wraps a [`Volatile`]. Since [`Volatile`] only allows
+//! writes through a mutable reference, it cannot implement [`Writeable`], so
+//! [`WriteableMut`] provides the same methods, taking `&mut self` instead.
+//!
+//! Note that the method names of [`Readable`], [`Writeable`], and
+//! [`WriteableMut`] conflict with those of [`VolatileRead`] and
+//! [`VolatileWrite`], so it may be necessary to call
+//! them using the fully qualified syntax if both are in scope.
+//!
+//! [`ReadWriteable`]: tock_registers::interfaces::ReadWriteable
+use core::{
+    any::type_name,
+    fmt,
+    marker::PhantomData,
+    ops::{Deref, DerefMut},
+};
+
+use tock_registers::{
+    fields::FieldValue,
+    interfaces::{Readable, Writeable},
+    LocalRegisterCopy, RegisterLongName, UIntLike,
+};
+
+use crate::{
+    volatile::{Read, ReadOnly, ReadWrite, Write, WriteOnly},
+    Volatile, VolatileRead, VolatileShared, VolatileWrite,
+};
+
+/// A [`Volatile`] register associated with a [`RegisterLongName`], for use with
+/// the typed fields of `tock-registers`.
+///
+/// `Register` dereferences to the underlying [`Volatile`].
+#[repr(transparent)]
+pub struct Register<T: Copy, R = (), Permission = ReadWrite> {
+    vol: Volatile<T, Permission>,
+    _name: PhantomData<R>,
+}
+
+/// A read-only [`Register`].
+pub type ReadOnlyRegister<T, R = ()> = Register<T, R, ReadOnly>;
+
+/// A write-only [`Register`].
+pub type WriteOnlyRegister<T, R = ()> = Register<T, R, WriteOnly>;
+
+impl<T: Copy, R, P> Register<T, R, P> {
+    /// Converts a reference to a [`Volatile`] into a reference to a
+    /// `Register`.
+    pub fn from_volatile(vol: &Volatile<T, P>) -> &Self {
+        // SAFETY: It is safe to cast to `*const Self` because `Self` is
+        // transparent.
+        unsafe { &*(vol as *const Volatile<T, P> as *const Self) }
+    }
+
+    /// Converts a mutable reference to a [`Volatile`] into a mutable reference
+    /// to a `Register`.
+    pub fn from_volatile_mut(vol: &mut Volatile<T, P>) -> &mut Self {
+        // SAFETY: It is safe to cast to `*mut Self` because `Self` is
+        // transparent.
+        unsafe { &mut *(vol as *mut Volatile<T, P> as *mut Self) }
+    }
+}
+
+impl<T: Copy, R, P> Deref for Register<T, R, P> {
+    type Target = Volatile<T, P>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.vol
+    }
+}
+
+impl<T: Copy, R, P> DerefMut for Register<T, R, P> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.vol
+    }
+}
+
+impl<T: Copy, R, P> fmt::Debug for Register<T, R, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(type_name::<Self>())
+    }
+}
+
+/// A [`VolatileShared`] register associated with a [`RegisterLongName`], for
+/// use with the typed fields of `tock-registers`.
+///
+/// `SharedRegister` implements [`Readable`], [`Writeable`], and
+/// [`ReadWriteable`](tock_registers::interfaces::ReadWriteable), so it can
+/// replace the register types of `tock-registers`, and dereferences to the
+/// underlying [`VolatileShared`].
+///
+/// # Example
+/// ```
+/// use tock_registers::{
+///     interfaces::{ReadWriteable, Readable, Writeable},
+///     register_bitfields,
+/// };
+/// use volatile_mem::{tock::SharedRegister, Volatile, VolatileShared};
+///
+/// register_bitfields![u32,
+///     CTRL [
+///         ENABLE OFFSET(0) NUMBITS(1) [],
+///         MODE OFFSET(4) NUMBITS(2) [Idle = 0, Run = 2],
+///     ]
+/// ];
+///
+/// let mut vol = Volatile::<u32>::new(0);
+/// let ctrl: &SharedRegister<u32, CTRL::Register> =
+///     SharedRegister::from_shared(VolatileShared::from_volatile_mut(&mut vol));
+/// ctrl.write(CTRL::MODE::Run);
+/// ctrl.modify(CTRL::ENABLE::SET);
+/// assert!(ctrl.is_set(CTRL::ENABLE));
+/// assert_eq!(ctrl.get(), 0x21);
+/// ```
+#[repr(transparent)]
+pub struct SharedRegister<T: Copy, R = (), Permission = ReadWrite> {
+    vol: VolatileShared<T, Permission>,
+    _name: PhantomData<R>,
+}
+
+impl<T: Copy, R, P> SharedRegister<T, R, P> {
+    /// Converts a reference to a [`VolatileShared`] into a reference to a
+    /// `SharedRegister`.
+    pub fn from_shared(vol: &VolatileShared<T, P>) -> &Self {
+        // SAFETY: It is safe to cast to `*const Self` because `Self` is
+        // transparent.
+        unsafe { &*(vol as *const VolatileShared<T, P> as *const Self) }
+    }
+}
+
+impl<T: Copy, R, P> Deref for SharedRegister<T, R, P> {
+    type Target = VolatileShared<T, P>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.vol
+    }
+}
+
+impl<T: Copy, R, P> fmt::Debug for SharedRegister<T, R, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(type_name::<Self>())
+    }
+}
+
+impl<T: UIntLike, P: Read> Readable for Volatile<T, P> {
+    type T = T;
+    type R = ();
+
+    fn get(&self) -> T {
+        VolatileRead::read(self)
+    }
+}
+
+impl<T: UIntLike, R: RegisterLongName, P: Read> Readable for Register<T, R, P> {
+    type T = T;
+    type R = R;
+
+    fn get(&self) -> T {
+        VolatileRead::read(&self.vol)
+    }
+}
+
+impl<T: UIntLike, P: Read> Readable for VolatileShared<T, P> {
+    type T = T;
+    type R = ();
+
+    fn get(&self) -> T {
+        VolatileRead::read(self)
+    }
+}
+
+impl<T: UIntLike, R: RegisterLongName, P: Read> Readable for SharedRegister<T, R, P> {
+    type T = T;
+    type R = R;
+
+    fn get(&self) -> T {
+        VolatileRead::read(&self.vol)
+    }
+}
+
+impl<T: UIntLike, P: Write> Writeable for VolatileShared<T, P> {
+    type T = T;
+    type R = ();
+
+    fn set(&self, value: T) {
+        self.write_shared(value);
+    }
+}
+
+impl<T: UIntLike, R: RegisterLongName, P: Write> Writeable for SharedRegister<T, R, P> {
+    type T = T;
+    type R = R;
+
+    fn set(&self, value: T) {
+        self.vol.write_shared(value);
+    }
+}
+
+/// The equivalent of [`Writeable`] and
+/// [`ReadWriteable`](tock_registers::interfaces::ReadWriteable), taking
+/// `&mut self`, for [`Volatile`] and [`Register`], which can only be written
+/// through a mutable reference.
+pub trait WriteableMut {
+    /// The underlying integer type of the register.
+    type T: UIntLike;
+    /// The register's name, identifying its fields.
+    type R: RegisterLongName;
+
+    /// Sets the raw register value.
+    fn set(&mut self, value: Self::T);
+
+    /// Writes the value of one or more fields, overwriting the other fields
+    /// with zero.
+    fn write(&mut self, field: FieldValue<Self::T, Self::R>) {
+        self.set(field.value);
+    }
+
+    /// Writes the value of one or more fields, maintaining the value of
+    /// unchanged fields via a provided original value, rather than a register
+    /// read.
+    fn modify_no_read(
+        &mut self,
+        original: LocalRegisterCopy<Self::T, Self::R>,
+        field: FieldValue<Self::T, Self::R>,
+    ) {
+        self.set(field.modify(original.get()));
+    }
+
+    /// Writes the value of one or more fields, leaving the other fields
+    /// unchanged.
+    fn modify(&mut self, field: FieldValue<<Self as WriteableMut>::T, <Self as WriteableMut>::R>)
+    where
+        Self: Readable<T = <Self as WriteableMut>::T, R = <Self as WriteableMut>::R>,
+    {
+        let value = field.modify(Readable::get(self));
+        self.set(value);
+    }
+}
+
+impl<T: UIntLike, P: Write> WriteableMut for Volatile<T, P> {
+    type T = T;
+    type R = ();
+
+    fn set(&mut self, value: T) {
+        VolatileWrite::write(self, value);
+    }
+}
+
+impl<T: UIntLike, R: RegisterLongName, P: Write> WriteableMut for Register<T, R, P> {
+    type T = T;
+    type R = R;
+
+    fn set(&mut self, value: T) {
+        VolatileWrite::write(&mut self.vol, value);
+    }
+}
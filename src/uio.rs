@@ -0,0 +1,169 @@
+use core::{any::type_name, convert::TryFrom, fmt};
+use std::{
+    format,
+    fs::{self, File, OpenOptions},
+    io::{self, Read, Write},
+    string::String,
+    vec::Vec,
+};
+
+use crate::VolatileMmap;
+
+/// The description of one of the memory regions of a [`UioDevice`], as
+/// reported by sysfs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UioMapInfo {
+    /// The name of the region, which may be empty.
+    pub name: String,
+    /// The physical address of the region.
+    pub addr: u64,
+    /// The size of the region in bytes.
+    pub size: usize,
+    /// The offset of the region from the start of its first page.
+    pub offset: usize,
+}
+
+/// A Linux userspace I/O (UIO) device, such as `/dev/uio0`.
+///
+/// The memory regions of the device are listed by [`maps`](Self::maps) and
+/// mapped by [`map`](Self::map), which returns a [`VolatileMmap`] covering
+/// exactly the region, so the registers of the device can be accessed with the
+/// same code used by a kernel or firmware driver. Interrupts are waited for
+/// with [`wait_irq`](Self::wait_irq).
+///
+/// # Example
+/// ```no_run
+/// use volatile_mem::{UioDevice, VolatileRead};
+///
+/// let uio = UioDevice::open(0)?;
+/// let regs = uio.map(0)?;
+/// let id = regs.array::<4>(0).unwrap().read();
+/// uio.enable_irq()?;
+/// let count = uio.wait_irq()?;
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub struct UioDevice {
+    file: File,
+    index: usize,
+    maps: Vec<UioMapInfo>,
+}
+
+impl UioDevice {
+    /// Opens `/dev/uio{index}`, reading the descriptions of its memory regions
+    /// from `/sys/class/uio/uio{index}/maps`.
+    ///
+    /// # Errors
+    /// Returns an error if the device cannot be opened, or if the description
+    /// of a region cannot be read or parsed.
+    pub fn open(index: usize) -> io::Result<Self> {
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .open(format!("/dev/uio{}", index))?;
+        let mut maps = Vec::new();
+        loop {
+            let dir = format!("/sys/class/uio/uio{}/maps/map{}", index, maps.len());
+            if fs::metadata(&dir).is_err() {
+                break;
+            }
+            maps.push(UioMapInfo {
+                name: fs::read_to_string(format!("{}/name", dir))
+                    .map(|name| name.trim_end().into())
+                    .unwrap_or_default(),
+                addr: read_hex(&dir, "addr")?,
+                size: to_usize(read_hex(&dir, "size")?)?,
+                offset: to_usize(read_hex(&dir, "offset").unwrap_or(0))?,
+            });
+        }
+        Ok(UioDevice { file, index, maps })
+    }
+
+    /// Returns the index of the device, as in `/dev/uio{index}`.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the descriptions of the memory regions of the device.
+    pub fn maps(&self) -> &[UioMapInfo] {
+        &self.maps
+    }
+
+    /// Maps the memory region at `index` in [`maps`](Self::maps), returning a
+    /// mapping which starts at the start of the region and has its size.
+    ///
+    /// # Errors
+    /// Returns an error if there is no such region, or the error reported by
+    /// the operating system if it cannot be mapped.
+    pub fn map(&self, index: usize) -> io::Result<VolatileMmap> {
+        let info = self
+            .maps
+            .get(index)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "no such UIO map"))?;
+        // SAFETY: `sysconf` has no preconditions.
+        let page_size = unsafe { libc::sysconf(libc::_SC_PAGESIZE) } as u64;
+        let len = info
+            .offset
+            .checked_add(info.size)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "UIO map is too large"))?;
+        // SAFETY: The memory of a device cannot be truncated. The UIO driver
+        // selects the region using an offset of `index` pages.
+        let map = unsafe { VolatileMmap::map_file(&self.file, index as u64 * page_size, len)? };
+        Ok(map.skip(info.offset))
+    }
+
+    /// Waits for an interrupt, returning the total number of interrupts which
+    /// have occurred.
+    ///
+    /// With many drivers, such as `uio_pdrv_genirq`, the interrupt is disabled
+    /// once it occurs, and must be re-enabled with
+    /// [`enable_irq`](Self::enable_irq) before waiting for the next one.
+    ///
+    /// # Errors
+    /// Returns the error reported by the operating system if the device cannot
+    /// be read.
+    pub fn wait_irq(&self) -> io::Result<u32> {
+        let mut count = [0; 4];
+        (&self.file).read_exact(&mut count)?;
+        Ok(u32::from_ne_bytes(count))
+    }
+
+    /// Enables the interrupt of the device, for drivers which support it.
+    ///
+    /// # Errors
+    /// Returns the error reported by the operating system if the device cannot
+    /// be written, such as if the driver does not support enabling interrupts.
+    pub fn enable_irq(&self) -> io::Result<()> {
+        self.set_irq(true)
+    }
+
+    /// Disables the interrupt of the device, for drivers which support it.
+    ///
+    /// # Errors
+    /// Returns the error reported by the operating system if the device cannot
+    /// be written, such as if the driver does not support disabling interrupts.
+    pub fn disable_irq(&self) -> io::Result<()> {
+        self.set_irq(false)
+    }
+
+    fn set_irq(&self, enabled: bool) -> io::Result<()> {
+        (&self.file).write_all(&u32::from(enabled).to_ne_bytes())
+    }
+}
+
+impl fmt::Debug for UioDevice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {{ /dev/uio{} }}", type_name::<Self>(), self.index)
+    }
+}
+
+fn read_hex(dir: &str, name: &str) -> io::Result<u64> {
+    let text = fs::read_to_string(format!("{}/{}", dir, name))?;
+    let text = text.trim();
+    u64::from_str_radix(text.trim_start_matches("0x"), 16)
+        .map_err(|_| io::Error::new(io::ErrorKind::InvalidData, "invalid UIO map attribute"))
+}
+
+fn to_usize(val: u64) -> io::Result<usize> {
+    usize::try_from(val)
+        .map_err(|_| io::Error::new(io::ErrorKind::InvalidData, "UIO map is too large"))
+}
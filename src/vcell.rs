@@ -0,0 +1,35 @@
+use vcell::VolatileCell;
+
+use crate::{
+    volatile::{Read, Write},
+    Volatile,
+};
+
+impl<T: Copy, P: Read + Write> Volatile<T, P> {
+    /// Returns a [`VolatileCell`] view of `self`.
+    ///
+    /// A mutable reference is required because a [`VolatileCell`] can be
+    /// written through a shared reference.
+    pub fn as_volatile_cell(&mut self) -> &VolatileCell<T> {
+        // SAFETY: `self` is a mutable reference, so the memory cannot be
+        // accessed other than through the returned reference while it exists.
+        // It is safe to cast to `*const VolatileCell<T>` because both types are
+        // transparent.
+        unsafe { &*(self as *mut Self as *const VolatileCell<T>) }
+    }
+}
+
+impl<'a, T: Copy, P: Read + Write> From<&'a mut Volatile<T, P>> for &'a VolatileCell<T> {
+    fn from(vol: &'a mut Volatile<T, P>) -> &'a VolatileCell<T> {
+        vol.as_volatile_cell()
+    }
+}
+
+impl<'a, T: Copy, P> From<&'a mut VolatileCell<T>> for &'a mut Volatile<T, P> {
+    fn from(cell: &'a mut VolatileCell<T>) -> &'a mut Volatile<T, P> {
+        // SAFETY: `cell` is a mutable reference to a `Copy` type, which is
+        // initialized. It is safe to cast to `*mut Volatile<T, P>` because
+        // both types are transparent.
+        unsafe { &mut *(cell.as_ptr() as *mut Volatile<T, P>) }
+    }
+}
@@ -0,0 +1,119 @@
+use voladdress::{Safe, VolAddress, VolBlock};
+
+use crate::{
+    volatile::{NoAccess, ReadOnly, ReadWrite, WriteOnly},
+    Volatile,
+};
+
+/// Maps a permission to the corresponding `voladdress` permissions.
+pub trait VolAddressPermission {
+    /// The read permission.
+    type R;
+    /// The write permission.
+    type W;
+}
+
+impl VolAddressPermission for ReadWrite {
+    type R = Safe;
+    type W = Safe;
+}
+
+impl VolAddressPermission for ReadOnly {
+    type R = Safe;
+    type W = ();
+}
+
+impl VolAddressPermission for WriteOnly {
+    type R = ();
+    type W = Safe;
+}
+
+impl VolAddressPermission for NoAccess {
+    type R = ();
+    type W = ();
+}
+
+impl<T: Copy, P> Volatile<T, P> {
+    /// Converts a [`VolAddress`] into a reference to `Volatile<T>`.
+    ///
+    /// # Safety
+    /// `addr` must meet the requirements of [`from_ptr`](Volatile::from_ptr),
+    /// and must not be used to write to the memory while the resulting
+    /// reference exists.
+    pub unsafe fn from_vol_address<'a, R, W>(addr: VolAddress<T, R, W>) -> &'a Self {
+        // SAFETY: The caller must ensure the address is safe to use.
+        unsafe { Self::from_ptr(addr.as_ptr()) }
+    }
+
+    /// Converts a [`VolAddress`] into a mutable reference to `Volatile<T>`.
+    ///
+    /// # Safety
+    /// `addr` must meet the requirements of
+    /// [`from_mut_ptr`](Volatile::from_mut_ptr), and must not be used to access
+    /// the memory while the resulting reference exists.
+    pub unsafe fn from_vol_address_mut<'a, R, W>(addr: VolAddress<T, R, W>) -> &'a mut Self {
+        // SAFETY: The caller must ensure the address is safe to use.
+        unsafe { Self::from_mut_ptr(addr.as_mut_ptr()) }
+    }
+}
+
+impl<T: Copy, P: VolAddressPermission> Volatile<T, P> {
+    /// Returns a [`VolAddress`] for `self`, with the same permissions as
+    /// `self`.
+    ///
+    /// # Safety
+    /// A [`VolAddress`] can be copied and used for any amount of time, so the
+    /// caller must ensure it is not used once `self` is no longer valid. If
+    /// `self` is writable, the returned address must not be used for writes
+    /// while a reference to `self` exists, other than the mutable reference
+    /// used to create it.
+    pub unsafe fn as_vol_address(&mut self) -> VolAddress<T, P::R, P::W> {
+        // SAFETY: The address comes from a reference, so it is non-NULL,
+        // properly aligned, and valid for the permission of `self`. The caller
+        // must ensure it is not used after `self` is no longer valid.
+        unsafe { VolAddress::new(self as *mut Self as usize) }
+    }
+}
+
+impl<T: Copy, P, const C: usize> Volatile<[T; C], P> {
+    /// Converts a [`VolBlock`] into a reference to `Volatile<[T; C]>`.
+    ///
+    /// # Safety
+    /// `block` must meet the requirements of [`from_ptr`](Volatile::from_ptr)
+    /// for an array of `C` elements, and must not be used to write to the
+    /// memory while the resulting reference exists.
+    pub unsafe fn from_vol_block<'a, R, W>(block: VolBlock<T, R, W, C>) -> &'a Self {
+        // SAFETY: The caller must ensure the block is safe to use.
+        unsafe { Self::from_ptr(block.as_ptr() as *const [T; C]) }
+    }
+
+    /// Converts a [`VolBlock`] into a mutable reference to `Volatile<[T; C]>`.
+    ///
+    /// # Safety
+    /// `block` must meet the requirements of
+    /// [`from_mut_ptr`](Volatile::from_mut_ptr) for an array of `C` elements,
+    /// and must not be used to access the memory while the resulting reference
+    /// exists.
+    pub unsafe fn from_vol_block_mut<'a, R, W>(block: VolBlock<T, R, W, C>) -> &'a mut Self {
+        // SAFETY: The caller must ensure the block is safe to use.
+        unsafe { Self::from_mut_ptr(block.as_mut_ptr() as *mut [T; C]) }
+    }
+}
+
+impl<T: Copy, P: VolAddressPermission, const C: usize> Volatile<[T; C], P> {
+    /// Returns a [`VolBlock`] for `self`, with the same permissions as `self`.
+    ///
+    /// # Safety
+    /// A [`VolBlock`] can be copied and used for any amount of time, so the
+    /// caller must ensure it is not used once `self` is no longer valid. If
+    /// `self` is writable, the returned block must not be used for writes
+    /// while a reference to `self` exists, other than the mutable reference
+    /// used to create it.
+    pub unsafe fn as_vol_block(&mut self) -> VolBlock<T, P::R, P::W, C> {
+        // SAFETY: The address comes from a reference, so it is non-NULL,
+        // properly aligned, and valid for `C` elements with the permission of
+        // `self`. The caller must ensure it is not used after `self` is no
+        // longer valid.
+        unsafe { VolBlock::new(self as *mut Self as usize) }
+    }
+}
@@ -1,25 +1,48 @@
 use core::{
     any::type_name,
     borrow::{Borrow, BorrowMut},
+    convert::TryFrom,
     fmt,
     marker::PhantomData,
-    ops::{Deref, DerefMut},
+    mem,
+    ops::{
+        AddAssign, BitAndAssign, BitOrAssign, BitXorAssign, Deref, DerefMut, ShlAssign, ShrAssign,
+        SubAssign,
+    },
+    ptr::{self, NonNull},
     slice,
+    sync::atomic::{fence, Ordering},
 };
 
-use crate::{VolatileData, VolatileRead, VolatileWrite};
+use crate::{access, barrier, VolatileData, VolatileError, VolatileRead, VolatileWrite};
 
+/// A permission which allows both reads and writes.
 #[derive(Debug)]
 pub struct ReadWrite;
+/// A permission which allows only reads.
 #[derive(Debug)]
 pub struct ReadOnly;
+/// A permission which allows only writes.
 #[derive(Debug)]
 pub struct WriteOnly;
+/// A permission which allows neither reads nor writes.
+#[derive(Debug)]
+pub struct NoAccess;
+/// A permission which allows only reads, which have side effects.
+#[derive(Debug)]
+pub struct ReadClear;
+/// A permission which allows reads, and a single write through a
+/// [`WriteOnceCapability`].
+#[derive(Debug)]
+pub struct WriteOnce;
 
+/// A permission which allows reads.
 pub trait Read {}
 impl Read for ReadWrite {}
 impl Read for ReadOnly {}
+impl Read for WriteOnce {}
 
+/// A permission which allows writes.
 pub trait Write {}
 impl Write for ReadWrite {}
 impl Write for WriteOnly {}
@@ -49,7 +72,143 @@ pub type VolatileReadOnly<T> = Volatile<T, ReadOnly>;
 /// and [trait implementations](Volatile<T>#trait-implementations).
 pub type VolatileWriteOnly<T> = Volatile<T, WriteOnly>;
 
+/// Volatile data or memory which is cleared, or otherwise changed, by reading
+/// it.
+///
+/// This models registers such as some interrupt status registers, whose read
+/// has side effects. Its [`read`](Volatile::read) requires a mutable reference,
+/// so the data cannot be read accidentally through a shared reference, such as
+/// from logging code.
+///
+/// See [crate-level documentation](crate) for details.
+///
+/// See [`Volatile<T>`] for methods and [methods](Volatile<T>#implementations)
+/// and [trait implementations](Volatile<T>#trait-implementations).
+pub type VolatileReadClear<T> = Volatile<T, ReadClear>;
+
+/// Volatile data or memory which may only be written once, such as a
+/// configuration register which locks after its first write following reset.
+///
+/// It can be read as usual, but can only be written by consuming a
+/// [`WriteOnceCapability`].
+///
+/// See [crate-level documentation](crate) for details.
+///
+/// See [`Volatile<T>`] for methods and [methods](Volatile<T>#implementations)
+/// and [trait implementations](Volatile<T>#trait-implementations).
+pub type VolatileWriteOnce<T> = Volatile<T, WriteOnce>;
+
+/// The capability to write a [`VolatileWriteOnce`] a single time.
+///
+/// Writing consumes the capability, so as long as only one capability is
+/// created for each register, such as in initialization code which is run once
+/// after reset, the register cannot be written a second time.
+///
+/// # Example
+/// ```
+/// use volatile_mem::{VolatileWriteOnce, WriteOnceCapability};
+///
+/// let mut mem = 0u32;
+/// let vol = VolatileWriteOnce::<u32>::from_mut(&mut mem);
+/// // SAFETY: No other capability is created for `vol`.
+/// let cap = unsafe { WriteOnceCapability::new(vol) };
+/// cap.write(7);
+/// ```
+///
+/// A capability cannot be used for a second write.
+/// ```compile_fail
+/// use volatile_mem::{VolatileWriteOnce, WriteOnceCapability};
+///
+/// let mut mem = 0u32;
+/// let vol = VolatileWriteOnce::<u32>::from_mut(&mut mem);
+/// let cap = unsafe { WriteOnceCapability::new(vol) };
+/// cap.write(7);
+/// cap.write(8);
+/// ```
+#[must_use = "the register is only written by calling `write`"]
+pub struct WriteOnceCapability<'a, T: Copy> {
+    vol: &'a mut VolatileWriteOnce<T>,
+}
+
+impl<'a, T: Copy> WriteOnceCapability<'a, T> {
+    /// Creates the capability to write `vol` once.
+    ///
+    /// # Safety
+    /// This may only be called once for each register after each reset.
+    /// Calling it again after the register has been written would allow it to
+    /// be written again, which the hardware may ignore or treat as an error.
+    pub unsafe fn new(vol: &'a mut VolatileWriteOnce<T>) -> Self {
+        WriteOnceCapability { vol }
+    }
+
+    /// Performs a volatile write of the register with the given value without
+    /// reading the old value, consuming the capability.
+    ///
+    /// Returns a reference through which the register can still be read.
+    ///
+    /// # Safety
+    /// Just like in C, whether an operation is volatile has no bearing
+    /// whatsoever on questions involving concurrent access from multiple
+    /// threads. Volatile accesses behave exactly like non-atomic accesses in
+    /// that regard. In particular, a race between a write operation any other
+    /// operation (reading or writing) to the same location is undefined
+    /// behavior.
+    pub fn write(self, val: T) -> &'a VolatileWriteOnce<T> {
+        // SAFETY: `self.vol` is a mutable reference. It is safe to cast to
+        // `*mut T` because `Volatile` is transparent. `T` is safe to write
+        // since it is `Copy`.
+        unsafe { access::write(self.vol as *mut VolatileWriteOnce<T> as *mut T, val) };
+        self.vol
+    }
+}
+
+impl<T: Copy> fmt::Debug for WriteOnceCapability<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(type_name::<Self>())
+    }
+}
+
+/// Reserved volatile memory of `N` bytes, which can be neither read nor
+/// written.
+///
+/// This is intended for reserved or padding regions in `#[repr(C)]` register
+/// structs. It has the same size as `[u8; N]` and an alignment of one, so it
+/// does not affect the layout of the rest of the struct.
+///
+/// See [crate-level documentation](crate) for details.
+pub type Reserved<const N: usize> = Volatile<[u8; N], NoAccess>;
+
 impl<T: Copy, P> Volatile<T, P> {
+    /// Fails to evaluate if `T` has size zero, unless the `allow-zst` feature
+    /// is enabled, since volatile accesses to zero-sized types are no-ops.
+    const ASSERT_NOT_ZST: () = assert!(
+        cfg!(feature = "allow-zst") || mem::size_of::<T>() != 0,
+        "`Volatile<T>` requires a `T` which is not zero-sized, since volatile \
+         accesses to zero-sized types are no-ops (enable the `allow-zst` feature \
+         to permit them)"
+    );
+
+    /// Creates a `Volatile` containing `val`.
+    ///
+    /// This is intended for statics, such as a shadow copy of a device's
+    /// registers for simulation or tests, or shared memory placed by the
+    /// linker. To access existing memory, use [`from_ptr`](Self::from_ptr) or
+    /// [`from_ref`](Self::from_ref) instead.
+    ///
+    /// # Example
+    /// ```
+    /// use volatile_mem::{Volatile, VolatileRead, VolatileWrite};
+    ///
+    /// let mut vol: Volatile<u32> = Volatile::new(7);
+    /// assert_eq!(vol.read(), 7);
+    /// vol.write(8);
+    /// assert_eq!(vol.read(), 8);
+    /// ```
+    pub const fn new(val: T) -> Self {
+        let () = Self::ASSERT_NOT_ZST;
+        Volatile { _data: val }
+    }
+
     /// Converts a pointer to `T` into a reference to `Volatile<T>`, which can
     /// be [read-only](VolatileReadOnly), [write-only](VolatileWriteOnly), or
     /// both readable and writable (the default).
@@ -62,10 +221,10 @@ impl<T: Copy, P> Volatile<T, P> {
     /// - `mem` must be properly aligned.
     ///
     /// - `mem` must point to a properly initialized value of type `T` (unless
-    /// the resulting `Volatile<T>` is [write-only](VolatileWriteOnly)).
+    ///   the resulting `Volatile<T>` is [write-only](VolatileWriteOnly)).
     ///
-    /// Note that even if `T` has size zero, the pointer must be non-NULL and
-    /// properly aligned.
+    /// Note that even if `T` has size zero, which is only permitted with the
+    /// `allow-zst` feature, the pointer must be non-NULL and properly aligned.
     ///
     /// Just like in C, whether an operation is volatile has no bearing
     /// whatsoever on questions involving concurrent access from multiple
@@ -73,7 +232,8 @@ impl<T: Copy, P> Volatile<T, P> {
     /// that regard. In particular, a race between a write operation any other
     /// operation (reading or writing) to the same location is undefined
     /// behavior.
-    pub unsafe fn from_ptr<'a>(mem: *const T) -> &'a Self {
+    pub const unsafe fn from_ptr<'a>(mem: *const T) -> &'a Self {
+        let () = Self::ASSERT_NOT_ZST;
         // SAFETY: The caller must ensure the pointer is safe to use. It is
         // safe to cast to `*const Self` because `Self` is transparent.
         unsafe { &*(mem as *const Self) }
@@ -92,21 +252,251 @@ impl<T: Copy, P> Volatile<T, P> {
     /// - `mem` must be properly aligned.
     ///
     /// - `mem` must point to a properly initialized value of type `T` (unless
-    /// the resulting `Volatile<T>` is [write-only](VolatileWriteOnly)).
+    ///   the resulting `Volatile<T>` is [write-only](VolatileWriteOnly)).
     ///
-    /// Note that even if `T` has size zero, the pointer must be non-NULL and
-    /// properly aligned.
-    pub unsafe fn from_mut_ptr<'a>(mem: *mut T) -> &'a mut Self {
+    /// Note that even if `T` has size zero, which is only permitted with the
+    /// `allow-zst` feature, the pointer must be non-NULL and properly aligned.
+    pub const unsafe fn from_mut_ptr<'a>(mem: *mut T) -> &'a mut Self {
+        let () = Self::ASSERT_NOT_ZST;
         // SAFETY: The caller must ensure the pointer is safe to use. It is
         // safe to cast to `*mut Self` because `Self` is transparent.
         unsafe { &mut *(mem as *mut Self) }
     }
 
+    /// Converts a pointer to `T` into a reference to `Volatile<T>`, like
+    /// [`from_ptr`](Self::from_ptr), or returns `None` if the pointer is NULL.
+    ///
+    /// This is intended for pointers provided by a device, such as the next
+    /// pointer of a linked list, which is NULL at the end of the list.
+    ///
+    /// # Safety
+    /// If `mem` is non-NULL, the requirements are the same as for
+    /// [`from_ptr`](Self::from_ptr).
+    pub const unsafe fn from_ptr_checked<'a>(mem: *const T) -> Option<&'a Self> {
+        if mem.is_null() {
+            return None;
+        }
+        // SAFETY: The pointer is non-NULL, and the caller must ensure it is
+        // otherwise safe to use.
+        Some(unsafe { Self::from_ptr(mem) })
+    }
+
+    /// Converts a mutable pointer to `T` into a mutable reference to
+    /// `Volatile<T>`, like [`from_mut_ptr`](Self::from_mut_ptr), or returns
+    /// `None` if the pointer is NULL.
+    ///
+    /// # Safety
+    /// If `mem` is non-NULL, the requirements are the same as for
+    /// [`from_mut_ptr`](Self::from_mut_ptr).
+    pub const unsafe fn from_mut_ptr_checked<'a>(mem: *mut T) -> Option<&'a mut Self> {
+        if mem.is_null() {
+            return None;
+        }
+        // SAFETY: The pointer is non-NULL, and the caller must ensure it is
+        // otherwise safe to use.
+        Some(unsafe { Self::from_mut_ptr(mem) })
+    }
+
+    /// Converts a pointer to `T` into a reference to `Volatile<T>`, like
+    /// [`from_ptr`](Self::from_ptr), after checking at runtime that the
+    /// pointer is non-NULL and properly aligned.
+    ///
+    /// # Errors
+    /// Returns [`VolatileError::Null`] if `mem` is NULL, or
+    /// [`VolatileError::Misaligned`] if it is not properly aligned for `T`.
+    ///
+    /// # Safety
+    /// Except for NULL pointers and alignment, which are checked, the
+    /// requirements are the same as for [`from_ptr`](Self::from_ptr).
+    pub unsafe fn try_from_ptr<'a>(mem: *const T) -> Result<&'a Self, VolatileError> {
+        check_ptr(mem)?;
+        // SAFETY: The pointer is non-NULL and properly aligned, and the caller
+        // must ensure it is otherwise safe to use.
+        Ok(unsafe { Self::from_ptr(mem) })
+    }
+
+    /// Converts a mutable pointer to `T` into a mutable reference to
+    /// `Volatile<T>`, like [`from_mut_ptr`](Self::from_mut_ptr), after checking
+    /// at runtime that the pointer is non-NULL and properly aligned.
+    ///
+    /// # Errors
+    /// Returns [`VolatileError::Null`] if `mem` is NULL, or
+    /// [`VolatileError::Misaligned`] if it is not properly aligned for `T`.
+    ///
+    /// # Safety
+    /// Except for NULL pointers and alignment, which are checked, the
+    /// requirements are the same as for [`from_mut_ptr`](Self::from_mut_ptr).
+    pub unsafe fn try_from_mut_ptr<'a>(mem: *mut T) -> Result<&'a mut Self, VolatileError> {
+        check_ptr(mem)?;
+        // SAFETY: The pointer is non-NULL and properly aligned, and the caller
+        // must ensure it is otherwise safe to use.
+        Ok(unsafe { Self::from_mut_ptr(mem) })
+    }
+
+    /// Converts a [`NonNull`] pointer to `T` into a reference to
+    /// `Volatile<T>`, like [`from_ptr`](Self::from_ptr).
+    ///
+    /// # Safety
+    /// The requirements are the same as for [`from_ptr`](Self::from_ptr).
+    pub const unsafe fn from_non_null<'a>(mem: NonNull<T>) -> &'a Self {
+        // SAFETY: The caller must ensure the pointer is safe to use.
+        unsafe { Self::from_ptr(mem.as_ptr()) }
+    }
+
+    /// Converts a [`NonNull`] pointer to `T` into a mutable reference to
+    /// `Volatile<T>`, like [`from_mut_ptr`](Self::from_mut_ptr).
+    ///
+    /// # Safety
+    /// The requirements are the same as for
+    /// [`from_mut_ptr`](Self::from_mut_ptr).
+    pub const unsafe fn from_non_null_mut<'a>(mem: NonNull<T>) -> &'a mut Self {
+        // SAFETY: The caller must ensure the pointer is safe to use.
+        unsafe { Self::from_mut_ptr(mem.as_ptr()) }
+    }
+
+    /// Returns a [`NonNull`] pointer to the data in `self`.
+    ///
+    /// The pointer is derived from a shared reference, so it must not be used
+    /// for writes.
+    pub fn as_non_null(&self) -> NonNull<T> {
+        NonNull::from(self).cast()
+    }
+
+    /// Returns a [`NonNull`] pointer to the data in `self`, which may be used
+    /// for writes.
+    pub fn as_non_null_mut(&mut self) -> NonNull<T> {
+        NonNull::from(self).cast()
+    }
+
+    /// Converts the address of a memory-mapped register, such as one taken
+    /// from a datasheet, into a reference to `Volatile<T>`.
+    ///
+    /// The pointer is created with [`ptr::with_exposed_provenance`] rather
+    /// than an `as` cast, making the use of exposed provenance explicit. Miri
+    /// accepts this in its default permissive provenance mode, but not with
+    /// `-Zmiri-strict-provenance`.
+    ///
+    /// # Safety
+    /// The requirements are the same as for [`from_ptr`](Self::from_ptr). In
+    /// addition, `addr` must either be outside of any allocation known to
+    /// Rust, such as a hardware register, or have been previously exposed.
+    ///
+    /// # Example
+    /// ```
+    /// use volatile_mem::{Volatile, VolatileRead};
+    ///
+    /// let mem = 42u32;
+    /// let addr = (&mem as *const u32).expose_provenance();
+    /// let vol: &Volatile<u32> = unsafe { Volatile::from_exposed_addr(addr) };
+    /// assert_eq!(vol.read(), 42);
+    /// ```
+    pub const unsafe fn from_exposed_addr<'a>(addr: usize) -> &'a Self {
+        // SAFETY: The caller must ensure the address is safe to use.
+        unsafe { Self::from_ptr(ptr::with_exposed_provenance(addr)) }
+    }
+
+    /// Converts the address of a memory-mapped register into a mutable
+    /// reference to `Volatile<T>`, like
+    /// [`from_exposed_addr`](Self::from_exposed_addr).
+    ///
+    /// # Safety
+    /// The requirements are the same as for
+    /// [`from_mut_ptr`](Self::from_mut_ptr). In addition, `addr` must either
+    /// be outside of any allocation known to Rust, such as a hardware
+    /// register, or have been previously exposed.
+    pub const unsafe fn from_exposed_addr_mut<'a>(addr: usize) -> &'a mut Self {
+        // SAFETY: The caller must ensure the address is safe to use.
+        unsafe { Self::from_mut_ptr(ptr::with_exposed_provenance_mut(addr)) }
+    }
+
+    /// Converts a pointer to the base of a block of memory, such as a
+    /// peripheral's register block, and a byte offset from the base into a
+    /// reference to `Volatile<T>`.
+    ///
+    /// The offset is applied with `map_addr`, so the resulting pointer keeps
+    /// the provenance of `base`.
+    ///
+    /// # Safety
+    /// The requirements are the same as for [`from_ptr`](Self::from_ptr), for
+    /// the pointer `offset` bytes past `base`. In addition, that pointer must
+    /// be within the same allocation as `base`.
+    ///
+    /// # Example
+    /// ```
+    /// use volatile_mem::{Volatile, VolatileRead};
+    ///
+    /// let mem = [1u16, 2, 3];
+    /// let vol: &Volatile<u16> = unsafe { Volatile::from_base_offset(mem.as_ptr(), 4) };
+    /// assert_eq!(vol.read(), 3);
+    /// ```
+    pub unsafe fn from_base_offset<'a, B>(base: *const B, offset: usize) -> &'a Self {
+        let mem = base.map_addr(|addr| addr.wrapping_add(offset)).cast::<T>();
+        // SAFETY: The caller must ensure the resulting pointer is safe to use.
+        unsafe { Self::from_ptr(mem) }
+    }
+
+    /// Converts a mutable pointer to the base of a block of memory and a byte
+    /// offset from the base into a mutable reference to `Volatile<T>`, like
+    /// [`from_base_offset`](Self::from_base_offset).
+    ///
+    /// # Safety
+    /// The requirements are the same as for
+    /// [`from_mut_ptr`](Self::from_mut_ptr), for the pointer `offset` bytes
+    /// past `base`. In addition, that pointer must be within the same
+    /// allocation as `base`.
+    pub unsafe fn from_base_offset_mut<'a, B>(base: *mut B, offset: usize) -> &'a mut Self {
+        let mem = base.map_addr(|addr| addr.wrapping_add(offset)).cast::<T>();
+        // SAFETY: The caller must ensure the resulting pointer is safe to use.
+        unsafe { Self::from_mut_ptr(mem) }
+    }
+
+    /// Returns a raw pointer to the data in `self`.
+    ///
+    /// The pointer is derived from a shared reference, so it must not be used
+    /// for writes.
+    pub const fn as_ptr(&self) -> *const T {
+        self as *const Self as *const T
+    }
+
+    /// Returns a raw pointer to the data in `self`, which may be used for
+    /// writes.
+    pub const fn as_mut_ptr(&mut self) -> *mut T {
+        self as *mut Self as *mut T
+    }
+
+    /// Returns the address of the data in `self`, such as to program a DMA
+    /// engine or to log which register is being accessed.
+    ///
+    /// # Example
+    /// ```
+    /// use volatile_mem::Volatile;
+    ///
+    /// let mem = [0u32; 2];
+    /// let vol: &Volatile<[u32; 2]> = Volatile::from_ref(&mem);
+    /// assert_eq!(vol.addr(), mem.as_ptr() as usize);
+    /// assert_eq!(vol[1].addr(), vol.addr() + 4);
+    /// ```
+    pub fn addr(&self) -> usize {
+        self.as_ptr() as usize
+    }
+
     /// Converts a shared reference to `T` into a shared reference to
     /// `Volatile<T>`, which can be [read-only](VolatileReadOnly),
     /// [write-only](VolatileWriteOnly), or both readable and writable (the
     /// default).
-    pub fn from_ref<'a>(mem: &T) -> &'a Self {
+    ///
+    /// This is a `const fn`, so it can be used to initialize a `static`.
+    ///
+    /// # Example
+    /// ```
+    /// use volatile_mem::{VolatileRead, VolatileReadOnly};
+    ///
+    /// static VERSION: u32 = 3;
+    /// static VERSION_REG: &VolatileReadOnly<u32> = VolatileReadOnly::from_ref(&VERSION);
+    /// assert_eq!(VERSION_REG.read(), 3);
+    /// ```
+    pub const fn from_ref<'a>(mem: &T) -> &'a Self {
+        let () = Self::ASSERT_NOT_ZST;
         // SAFETY: `mem` is a reference to a `Copy` type. It is safe to cast to
         // `*const Self` because `Self` is transparent.
         unsafe { &*(mem as *const T as *const Volatile<T, P>) }
@@ -116,11 +506,434 @@ impl<T: Copy, P> Volatile<T, P> {
     /// `Volatile<T>`, which can be [read-only](VolatileReadOnly),
     /// [write-only](VolatileWriteOnly), or both readable and writable (the
     /// default).
-    pub fn from_mut<'a>(mem: &mut T) -> &'a mut Self {
+    pub const fn from_mut<'a>(mem: &mut T) -> &'a mut Self {
+        let () = Self::ASSERT_NOT_ZST;
         // SAFETY: `mem` is a mutable reference to a `Copy` type. It is safe to
         // cast to `*mut Self` because `Self` is transparent.
         unsafe { &mut *(mem as *mut T as *mut Volatile<T, P>) }
     }
+
+    /// Converts a shared slice of `T` into a shared slice of `Volatile<T>`,
+    /// like [`from_ref`](Self::from_ref) for each element.
+    pub const fn from_slice(mem: &[T]) -> &[Self] {
+        let () = Self::ASSERT_NOT_ZST;
+        // SAFETY: `mem` is a slice of a `Copy` type. It is safe to cast to
+        // `*const Self` because `Self` is transparent, so the slices have the
+        // same layout.
+        unsafe { slice::from_raw_parts(mem.as_ptr() as *const Self, mem.len()) }
+    }
+
+    /// Converts a mutable slice of `T` into a mutable slice of `Volatile<T>`,
+    /// like [`from_mut`](Self::from_mut) for each element.
+    pub const fn from_slice_mut(mem: &mut [T]) -> &mut [Self] {
+        let () = Self::ASSERT_NOT_ZST;
+        // SAFETY: `mem` is a mutable slice of a `Copy` type. It is safe to cast
+        // to `*mut Self` because `Self` is transparent, so the slices have the
+        // same layout.
+        unsafe { slice::from_raw_parts_mut(mem.as_mut_ptr() as *mut Self, mem.len()) }
+    }
+
+    /// Reinterprets `self` as having a different permission, such as viewing a
+    /// [write-only](VolatileWriteOnly) register as [read-only](VolatileReadOnly).
+    ///
+    /// Prefer [`as_read_only`](Volatile::as_read_only) or
+    /// [`as_write_only`](Volatile::as_write_only) when they suffice, since they
+    /// are safe.
+    ///
+    /// # Safety
+    /// The permission of `self` is a promise that the memory is only accessed
+    /// in the ways it allows. Behavior is undefined if the new permission is
+    /// used to access the memory in a way the underlying memory does not
+    /// actually support. In particular:
+    ///
+    /// - If the new permission allows reads, the memory must be
+    ///   [valid](core::ptr#safety) for reads and must contain a properly
+    ///   initialized value of type `T`.
+    ///
+    /// - If the new permission allows writes, the memory must be
+    ///   [valid](core::ptr#safety) for writes.
+    pub const unsafe fn assume_permission<Q>(&self) -> &Volatile<T, Q> {
+        // SAFETY: The caller must ensure the new permission is valid for the
+        // memory. It is safe to cast to `*const Volatile<T, Q>` because only
+        // the permission differs.
+        unsafe { &*(self as *const Self as *const Volatile<T, Q>) }
+    }
+
+    /// Reinterprets `self` as having a different permission, such as viewing a
+    /// [write-only](VolatileWriteOnly) register as both readable and writable.
+    ///
+    /// Prefer [`as_read_only`](Volatile::as_read_only) or
+    /// [`as_write_only`](Volatile::as_write_only) when they suffice, since they
+    /// are safe.
+    ///
+    /// # Safety
+    /// The permission of `self` is a promise that the memory is only accessed
+    /// in the ways it allows. Behavior is undefined if the new permission is
+    /// used to access the memory in a way the underlying memory does not
+    /// actually support. In particular:
+    ///
+    /// - If the new permission allows reads, the memory must be
+    ///   [valid](core::ptr#safety) for reads and must contain a properly
+    ///   initialized value of type `T`.
+    ///
+    /// - If the new permission allows writes, the memory must be
+    ///   [valid](core::ptr#safety) for writes.
+    pub const unsafe fn assume_permission_mut<Q>(&mut self) -> &mut Volatile<T, Q> {
+        // SAFETY: The caller must ensure the new permission is valid for the
+        // memory. It is safe to cast to `*mut Volatile<T, Q>` because only the
+        // permission differs.
+        unsafe { &mut *(self as *mut Self as *mut Volatile<T, Q>) }
+    }
+
+    /// Returns a reference to the `Volatile<U>` located `offset` bytes from the
+    /// start of `self`, with the same permission as `self`.
+    ///
+    /// This is intended for register maps which are described only by numeric
+    /// offsets.
+    ///
+    /// # Safety
+    /// Behavior is undefined if any of the following conditions are violated:
+    ///
+    /// - `offset + size_of::<U>()` must not be greater than `size_of::<T>()`.
+    ///
+    /// - The resulting address must be properly aligned for `U`.
+    ///
+    /// - The memory at the resulting address must contain a properly
+    ///   initialized value of type `U` (unless `self` is
+    ///   [write-only](VolatileWriteOnly)).
+    ///
+    /// The first two conditions are checked by debug assertions.
+    pub unsafe fn project_at<U: Copy>(&self, offset: usize) -> &Volatile<U, P> {
+        let () = Volatile::<U, P>::ASSERT_NOT_ZST;
+        let ptr = (self as *const Self as *const u8).wrapping_add(offset);
+        debug_assert_projection::<T, U>(ptr as usize, offset);
+        // SAFETY: The caller must ensure the resulting address is in bounds,
+        // properly aligned, and initialized. It is safe to cast to
+        // `*const Volatile<U, P>` because `Volatile` is transparent.
+        unsafe { &*(ptr as *const Volatile<U, P>) }
+    }
+
+    /// Returns a mutable reference to the `Volatile<U>` located `offset` bytes
+    /// from the start of `self`, with the same permission as `self`.
+    ///
+    /// This is intended for register maps which are described only by numeric
+    /// offsets.
+    ///
+    /// # Safety
+    /// Behavior is undefined if any of the following conditions are violated:
+    ///
+    /// - `offset + size_of::<U>()` must not be greater than `size_of::<T>()`.
+    ///
+    /// - The resulting address must be properly aligned for `U`.
+    ///
+    /// - The memory at the resulting address must contain a properly
+    ///   initialized value of type `U` (unless `self` is
+    ///   [write-only](VolatileWriteOnly)).
+    ///
+    /// The first two conditions are checked by debug assertions.
+    pub unsafe fn project_at_mut<U: Copy>(&mut self, offset: usize) -> &mut Volatile<U, P> {
+        let () = Volatile::<U, P>::ASSERT_NOT_ZST;
+        let ptr = (self as *mut Self as *mut u8).wrapping_add(offset);
+        debug_assert_projection::<T, U>(ptr as usize, offset);
+        // SAFETY: The caller must ensure the resulting address is in bounds,
+        // properly aligned, and initialized. It is safe to cast to
+        // `*mut Volatile<U, P>` because `Volatile` is transparent.
+        unsafe { &mut *(ptr as *mut Volatile<U, P>) }
+    }
+}
+
+impl<T: Copy, P: Read> Volatile<T, P> {
+    /// Returns a [read-only](VolatileReadOnly) view of `self`.
+    pub const fn as_read_only(&self) -> &VolatileReadOnly<T> {
+        // SAFETY: `self` is a reference. It is safe to cast to
+        // `*const VolatileReadOnly<T>` because only the permission differs,
+        // and `self` is readable.
+        unsafe { &*(self as *const Self as *const VolatileReadOnly<T>) }
+    }
+
+    /// Performs a volatile read of `self`, followed by an
+    /// [acquire fence](fence).
+    ///
+    /// Memory accesses after this read cannot be reordered before it, as
+    /// observed by other CPUs. This is useful when the read observes that
+    /// another core has finished writing data which is then accessed.
+    ///
+    /// The fence only orders accesses between CPUs, and is not a device
+    /// barrier. On ARM, for example, it is a `dmb ish`, which does not order
+    /// accesses by a DMA engine or other device. If the data is written by a
+    /// device, use [`read_device_acquire`](Self::read_device_acquire) instead.
+    pub fn read_acquire(&self) -> T {
+        let value = self.read();
+        fence(Ordering::Acquire);
+        value
+    }
+
+    /// Performs a volatile read of `self`, followed by a
+    /// [`device_read_barrier`](crate::barrier::device_read_barrier).
+    ///
+    /// Memory accesses after this read cannot be reordered before it, as
+    /// observed by devices as well as other CPUs. This is useful when the read
+    /// observes that a DMA engine or other bus master has finished writing
+    /// data, such as a status register reporting a completed transfer, before
+    /// the data is read.
+    ///
+    /// # Example
+    /// ```
+    /// use volatile_mem::{Volatile, VolatileRead};
+    ///
+    /// let status: &Volatile<u32> = Volatile::from_ref(&0x1);
+    /// let buffer: &Volatile<[u8; 4]> = Volatile::from_ref(&[1, 2, 3, 4]);
+    /// if status.read_device_acquire() & 0x1 != 0 {
+    ///     assert_eq!(buffer.read(), [1, 2, 3, 4]);
+    /// }
+    /// ```
+    pub fn read_device_acquire(&self) -> T {
+        let value = self.read();
+        barrier::device_read_barrier();
+        value
+    }
+}
+
+impl<T: Copy, P: Write> Volatile<T, P> {
+    /// Returns a [write-only](VolatileWriteOnly) view of `self`.
+    pub const fn as_write_only(&mut self) -> &mut VolatileWriteOnly<T> {
+        // SAFETY: `self` is a mutable reference. It is safe to cast to
+        // `*mut VolatileWriteOnly<T>` because only the permission differs, and
+        // `self` is writable.
+        unsafe { &mut *(self as *mut Self as *mut VolatileWriteOnly<T>) }
+    }
+
+    /// Performs a [release fence](fence), followed by a volatile write of
+    /// `self` with the given value.
+    ///
+    /// Memory accesses before this write cannot be reordered after it, as
+    /// observed by other CPUs. This is useful when the write signals another
+    /// core to access data which was previously written.
+    ///
+    /// The fence only orders accesses between CPUs, and is not a device
+    /// barrier. On ARM, for example, it is a `dmb ish`, which does not order
+    /// the write with respect to a DMA engine or other device. If the write
+    /// signals a device, use [`write_device_release`](Self::write_device_release)
+    /// instead.
+    pub fn write_release(&mut self, val: T) {
+        fence(Ordering::Release);
+        self.write(val);
+    }
+
+    /// Performs a
+    /// [`device_write_barrier`](crate::barrier::device_write_barrier),
+    /// followed by a volatile write of `self` with the given value.
+    ///
+    /// Memory accesses before this write cannot be reordered after it, as
+    /// observed by devices as well as other CPUs. This is useful when the
+    /// write signals a DMA engine or other bus master to access data which was
+    /// previously written, such as a doorbell register after descriptors have
+    /// been filled in.
+    ///
+    /// # Example
+    /// ```
+    /// use volatile_mem::{Volatile, VolatileRead, VolatileWrite};
+    ///
+    /// let (mut descriptor, mut doorbell) = (0u64, 0u32);
+    /// Volatile::<u64>::from_mut(&mut descriptor).write(0x8000_1000);
+    /// Volatile::<u32>::from_mut(&mut doorbell).write_device_release(1);
+    /// assert_eq!(doorbell, 1);
+    /// ```
+    pub fn write_device_release(&mut self, val: T) {
+        barrier::device_write_barrier();
+        self.write(val);
+    }
+
+    /// Performs a volatile write of every byte of `self` with `byte`, without
+    /// constructing a value of type `T`.
+    ///
+    /// Each byte is written with a separate volatile store, in order of
+    /// increasing address, so this is not suitable for registers which require
+    /// accesses of their full width. It is intended for clearing memory, such
+    /// as a descriptor ring, before use.
+    ///
+    /// # Safety
+    /// If `self` is read afterwards, `byte` repeated must be a valid value of
+    /// type `T`. This is always the case for integers and arrays of integers.
+    ///
+    /// # Example
+    /// ```
+    /// use volatile_mem::{Volatile, VolatileRead};
+    ///
+    /// let mut mem = [0u16; 4];
+    /// let vol = Volatile::<[u16; 4]>::from_mut(&mut mem);
+    /// unsafe { vol.write_bytes(0xab) };
+    /// assert_eq!(vol.read(), [0xabab; 4]);
+    /// ```
+    pub unsafe fn write_bytes(&mut self, byte: u8) {
+        let ptr = self as *mut Self as *mut u8;
+        for i in 0..mem::size_of::<T>() {
+            // SAFETY: `self` is a mutable reference, so each of its bytes is
+            // valid for writes. The caller must ensure the bytes are a valid
+            // value of `T` if it is read.
+            unsafe { access::write(ptr.add(i), byte) };
+        }
+    }
+
+    /// Performs a volatile write of zero to every byte of `self`, like
+    /// [`write_bytes`](Self::write_bytes).
+    ///
+    /// # Safety
+    /// If `self` is read afterwards, all zero bytes must be a valid value of
+    /// type `T`. This is the case for integers, arrays of integers, and most
+    /// `#[repr(C)]` structures of them, but not for references or
+    /// [`NonNull`].
+    pub unsafe fn write_zeroed(&mut self) {
+        // SAFETY: The caller must ensure zero is a valid value of `T`.
+        unsafe { self.write_bytes(0) }
+    }
+}
+
+impl<T: Copy> Volatile<T, ReadClear> {
+    /// Performs a volatile read of the value in `self` without moving it.
+    ///
+    /// Since the read has side effects, such as clearing the data, this
+    /// requires a mutable reference.
+    ///
+    /// # Safety
+    /// Just like in C, whether an operation is volatile has no bearing
+    /// whatsoever on questions involving concurrent access from multiple
+    /// threads. Volatile accesses behave exactly like non-atomic accesses in
+    /// that regard. In particular, a race between a read operation any write
+    /// operation to the same location is undefined behavior.
+    pub fn read(&mut self) -> T {
+        // SAFETY: `self` is a reference. It is safe to cast to `*const T`
+        // because `Self` is transparent. `T` is safe to read since it is `Copy`
+        // and guaranteed to be initialized.
+        unsafe { access::read(self as *const Self as *const T) }
+    }
+
+    /// Performs a volatile read of `self` and discards the value, such as to
+    /// clear an interrupt status register.
+    #[inline]
+    pub fn read_discard(&mut self) {
+        let _ = self.read();
+    }
+}
+
+impl<T: Copy + PartialEq, P: Read + Write> Volatile<T, P> {
+    /// Performs a volatile read of `self`, and if the value equals `expected`,
+    /// a volatile write of `new`.
+    ///
+    /// Returns `Ok` with the previous value if it was written, or `Err` with
+    /// the value read if it was not.
+    ///
+    /// This is **not** atomic: another bus master or an interrupt handler may
+    /// write `self` between the read and the write. It is intended for
+    /// single-writer protocols, where the comparison checks the state of the
+    /// protocol, such as whether a mailbox slot has been consumed, rather than
+    /// guarding against races.
+    ///
+    /// # Example
+    /// ```
+    /// use volatile_mem::{Volatile, VolatileRead};
+    ///
+    /// let mut mem = 1u8;
+    /// let state = Volatile::<u8>::from_mut(&mut mem);
+    /// assert_eq!(state.compare_and_write(1, 2), Ok(1));
+    /// assert_eq!(state.compare_and_write(1, 3), Err(2));
+    /// assert_eq!(state.read(), 2);
+    /// ```
+    pub fn compare_and_write(&mut self, expected: T, new: T) -> Result<T, T> {
+        let old = self.read();
+        if old == expected {
+            self.write(new);
+            Ok(old)
+        } else {
+            Err(old)
+        }
+    }
+}
+
+impl<A: Copy, B: Copy, P> Volatile<(A, B), P> {
+    /// Splits `self` into independent views of its two elements.
+    pub fn split(&mut self) -> (&mut Volatile<A, P>, &mut Volatile<B, P>) {
+        let ptr = self as *mut Self as *mut (A, B);
+        // SAFETY: `ptr` comes from a mutable reference, so both fields are
+        // valid, and they do not overlap. Neither field is read here, only its
+        // address is taken.
+        unsafe {
+            (
+                &mut *(ptr::addr_of_mut!((*ptr).0) as *mut Volatile<A, P>),
+                &mut *(ptr::addr_of_mut!((*ptr).1) as *mut Volatile<B, P>),
+            )
+        }
+    }
+}
+
+impl<A: Copy, B: Copy, P: Read + Write> Volatile<(A, B), P> {
+    /// Splits `self` into a [read-only](VolatileReadOnly) view of the first
+    /// element and a [write-only](VolatileWriteOnly) view of the second.
+    pub fn split_read_write(&mut self) -> (&VolatileReadOnly<A>, &mut VolatileWriteOnly<B>) {
+        let (a, b) = self.split();
+        (a.as_read_only(), b.as_write_only())
+    }
+
+    /// Splits `self` into a [write-only](VolatileWriteOnly) view of the first
+    /// element and a [read-only](VolatileReadOnly) view of the second.
+    pub fn split_write_read(&mut self) -> (&mut VolatileWriteOnly<A>, &VolatileReadOnly<B>) {
+        let (a, b) = self.split();
+        (a.as_write_only(), b.as_read_only())
+    }
+}
+
+impl<T: Copy, P: Read + Write, const N: usize> Volatile<[T; N], P> {
+    /// Splits `self` at `mid` into a [read-only](VolatileReadOnly) view of the
+    /// elements before `mid` and a [write-only](VolatileWriteOnly) view of the
+    /// remaining elements.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `mid > N`.
+    pub fn split_read_write(
+        &mut self,
+        mid: usize,
+    ) -> (&[VolatileReadOnly<T>], &mut [VolatileWriteOnly<T>]) {
+        let (read, write) = self.split_at_mut(mid);
+        // SAFETY: Both slices come from `split_at_mut`, so they are valid and
+        // do not overlap. It is safe to cast the element types because only
+        // the permission differs, and `self` is both readable and writable.
+        unsafe {
+            (
+                slice::from_raw_parts(read.as_ptr() as *const VolatileReadOnly<T>, read.len()),
+                slice::from_raw_parts_mut(
+                    write.as_mut_ptr() as *mut VolatileWriteOnly<T>,
+                    write.len(),
+                ),
+            )
+        }
+    }
+
+    /// Splits `self` at `mid` into a [write-only](VolatileWriteOnly) view of
+    /// the elements before `mid` and a [read-only](VolatileReadOnly) view of
+    /// the remaining elements.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `mid > N`.
+    pub fn split_write_read(
+        &mut self,
+        mid: usize,
+    ) -> (&mut [VolatileWriteOnly<T>], &[VolatileReadOnly<T>]) {
+        let (write, read) = self.split_at_mut(mid);
+        // SAFETY: Both slices come from `split_at_mut`, so they are valid and
+        // do not overlap. It is safe to cast the element types because only
+        // the permission differs, and `self` is both readable and writable.
+        unsafe {
+            (
+                slice::from_raw_parts_mut(
+                    write.as_mut_ptr() as *mut VolatileWriteOnly<T>,
+                    write.len(),
+                ),
+                slice::from_raw_parts(read.as_ptr() as *const VolatileReadOnly<T>, read.len()),
+            )
+        }
+    }
 }
 
 impl<'a, T: Copy, P> From<&'a T> for &'a Volatile<T, P> {
@@ -135,6 +948,82 @@ impl<'a, T: Copy, P> From<&'a mut T> for &'a mut Volatile<T, P> {
     }
 }
 
+impl<'a, T: Copy, P, const N: usize> TryFrom<&'a [T]> for &'a Volatile<[T; N], P> {
+    type Error = VolatileError;
+
+    /// Converts a slice of length `N` into a reference to a volatile array.
+    ///
+    /// The slice is always properly aligned, since an array has the same
+    /// alignment as its elements.
+    ///
+    /// # Errors
+    /// Returns [`VolatileError::LengthMismatch`] if the length of `mem` is not
+    /// `N`.
+    fn try_from(mem: &'a [T]) -> Result<Self, Self::Error> {
+        let () = Volatile::<[T; N], P>::ASSERT_NOT_ZST;
+        check_len::<N>(mem.len())?;
+        // SAFETY: The slice has length `N`, so it has the same layout as
+        // `[T; N]`, and `Volatile<[T; N], P>` is transparent.
+        Ok(unsafe { &*(mem.as_ptr() as *const Volatile<[T; N], P>) })
+    }
+}
+
+impl<'a, T: Copy, P, const N: usize> TryFrom<&'a mut [T]> for &'a mut Volatile<[T; N], P> {
+    type Error = VolatileError;
+
+    /// Converts a mutable slice of length `N` into a mutable reference to a
+    /// volatile array.
+    ///
+    /// # Errors
+    /// Returns [`VolatileError::LengthMismatch`] if the length of `mem` is not
+    /// `N`.
+    fn try_from(mem: &'a mut [T]) -> Result<Self, Self::Error> {
+        let () = Volatile::<[T; N], P>::ASSERT_NOT_ZST;
+        check_len::<N>(mem.len())?;
+        // SAFETY: The slice has length `N`, so it has the same layout as
+        // `[T; N]`, and `Volatile<[T; N], P>` is transparent.
+        Ok(unsafe { &mut *(mem.as_mut_ptr() as *mut Volatile<[T; N], P>) })
+    }
+}
+
+impl<'a, T: Copy, P, const N: usize> TryFrom<&'a [Volatile<T, P>]> for &'a Volatile<[T; N], P> {
+    type Error = VolatileError;
+
+    /// Converts a slice of volatile elements of length `N`, such as part of a
+    /// larger buffer in shared memory, into a reference to a volatile array.
+    ///
+    /// # Errors
+    /// Returns [`VolatileError::LengthMismatch`] if the length of `mem` is not
+    /// `N`.
+    fn try_from(mem: &'a [Volatile<T, P>]) -> Result<Self, Self::Error> {
+        let () = Volatile::<[T; N], P>::ASSERT_NOT_ZST;
+        check_len::<N>(mem.len())?;
+        // SAFETY: The slice has length `N`, so it has the same layout as
+        // `[T; N]`, since `Volatile<T, P>` is transparent.
+        Ok(unsafe { &*(mem.as_ptr() as *const Volatile<[T; N], P>) })
+    }
+}
+
+impl<'a, T: Copy, P, const N: usize> TryFrom<&'a mut [Volatile<T, P>]>
+    for &'a mut Volatile<[T; N], P>
+{
+    type Error = VolatileError;
+
+    /// Converts a mutable slice of volatile elements of length `N` into a
+    /// mutable reference to a volatile array.
+    ///
+    /// # Errors
+    /// Returns [`VolatileError::LengthMismatch`] if the length of `mem` is not
+    /// `N`.
+    fn try_from(mem: &'a mut [Volatile<T, P>]) -> Result<Self, Self::Error> {
+        let () = Volatile::<[T; N], P>::ASSERT_NOT_ZST;
+        check_len::<N>(mem.len())?;
+        // SAFETY: The slice has length `N`, so it has the same layout as
+        // `[T; N]`, since `Volatile<T, P>` is transparent.
+        Ok(unsafe { &mut *(mem.as_mut_ptr() as *mut Volatile<[T; N], P>) })
+    }
+}
+
 impl<T: Copy, P> VolatileData<T> for Volatile<T, P> {}
 
 impl<T: Copy, P: Read> VolatileRead<T> for Volatile<T, P> {
@@ -144,7 +1033,7 @@ impl<T: Copy, P: Read> VolatileRead<T> for Volatile<T, P> {
         // SAFETY: `self` is a reference. It is safe to cast to `*const T`
         // because `Self` is transparent. `T` is safe to read since it is `Copy`
         // and guaranteed to be initialized.
-        unsafe { (self as *const _ as *const T).read_volatile() }
+        unsafe { access::read(self as *const _ as *const T) }
     }
 }
 
@@ -155,10 +1044,119 @@ impl<T: Copy, P: Write> VolatileWrite<T> for Volatile<T, P> {
         // SAFETY: `self` is a mutable reference. It is safe to cast to `*mut T`
         // because `Self` is transparent. `T` is safe to write since it is
         // `Copy`.
-        unsafe { (self as *mut _ as *mut T).write_volatile(val) }
+        unsafe { access::write(self as *mut _ as *mut T, val) }
     }
 }
 
+macro_rules! impl_op_assign {
+    ($($trait:ident::$method:ident),*) => {
+        $(
+            /// Performs a volatile read of `self`, applies the operator to the
+            /// value, and performs a volatile write of the result.
+            ///
+            /// This is a read-modify-write sequence, rather than an atomic
+            /// operation, so it can race with other bus masters or interrupt
+            /// handlers which write the same data.
+            impl<T, P, Rhs> $trait<Rhs> for Volatile<T, P>
+            where
+                T: Copy + $trait<Rhs>,
+                P: Read + Write,
+            {
+                #[inline]
+                fn $method(&mut self, rhs: Rhs) {
+                    let mut value = self.read();
+                    value.$method(rhs);
+                    self.write(value);
+                }
+            }
+        )*
+    };
+}
+
+impl_op_assign!(
+    AddAssign::add_assign,
+    SubAssign::sub_assign,
+    BitAndAssign::bitand_assign,
+    BitOrAssign::bitor_assign,
+    BitXorAssign::bitxor_assign,
+    ShlAssign::shl_assign,
+    ShrAssign::shr_assign
+);
+
+macro_rules! impl_fetch {
+    ($($ty:ty),*) => {
+        $(
+            impl<P: Read + Write> Volatile<$ty, P> {
+                /// Performs a volatile read of `self`, and a volatile write of
+                /// the value plus `val`, wrapping around on overflow, returning
+                /// the previous value.
+                ///
+                /// This is a read-modify-write sequence, **not** an atomic
+                /// operation, so it is only suitable for data with a single
+                /// writer, such as a sequence number in shared memory which is
+                /// only read by the other side.
+                ///
+                /// # Example
+                /// ```
+                /// use volatile_mem::{Volatile, VolatileRead};
+                ///
+                #[doc = concat!("let mut mem: ", stringify!($ty), " = 7;")]
+                #[doc = concat!("let seq = Volatile::<", stringify!($ty), ">::from_mut(&mut mem);")]
+                /// assert_eq!(seq.fetch_add(1), 7);
+                /// assert_eq!(seq.read(), 8);
+                /// ```
+                pub fn fetch_add(&mut self, val: $ty) -> $ty {
+                    self.fetch_update(|old| old.wrapping_add(val))
+                }
+
+                /// Performs a volatile read of `self`, and a volatile write of
+                /// the value minus `val`, wrapping around on overflow,
+                /// returning the previous value.
+                ///
+                /// Like [`fetch_add`](Self::fetch_add), this is not atomic.
+                pub fn fetch_sub(&mut self, val: $ty) -> $ty {
+                    self.fetch_update(|old| old.wrapping_sub(val))
+                }
+
+                /// Performs a volatile read of `self`, and a volatile write of
+                /// the bitwise AND of the value and `val`, returning the
+                /// previous value.
+                ///
+                /// Like [`fetch_add`](Self::fetch_add), this is not atomic.
+                pub fn fetch_and(&mut self, val: $ty) -> $ty {
+                    self.fetch_update(|old| old & val)
+                }
+
+                /// Performs a volatile read of `self`, and a volatile write of
+                /// the bitwise OR of the value and `val`, returning the
+                /// previous value.
+                ///
+                /// Like [`fetch_add`](Self::fetch_add), this is not atomic.
+                pub fn fetch_or(&mut self, val: $ty) -> $ty {
+                    self.fetch_update(|old| old | val)
+                }
+
+                /// Performs a volatile read of `self`, and a volatile write of
+                /// the bitwise XOR of the value and `val`, returning the
+                /// previous value.
+                ///
+                /// Like [`fetch_add`](Self::fetch_add), this is not atomic.
+                pub fn fetch_xor(&mut self, val: $ty) -> $ty {
+                    self.fetch_update(|old| old ^ val)
+                }
+
+                fn fetch_update(&mut self, f: impl FnOnce($ty) -> $ty) -> $ty {
+                    let old = self.read();
+                    self.write(f(old));
+                    old
+                }
+            }
+        )*
+    };
+}
+
+impl_fetch!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
 impl<T: Copy, P, const N: usize> Deref for Volatile<[T; N], P> {
     type Target = [Volatile<T, P>];
 
@@ -205,8 +1203,111 @@ impl<T: Copy, P, const N: usize> AsMut<[Volatile<T, P>]> for Volatile<[T; N], P>
     }
 }
 
+/// Checks that a slice of length `len` can be converted to an array of length
+/// `N`.
+fn check_len<const N: usize>(len: usize) -> Result<(), VolatileError> {
+    if len == N {
+        Ok(())
+    } else {
+        Err(VolatileError::LengthMismatch {
+            expected: N,
+            actual: len,
+        })
+    }
+}
+
+/// Checks that `mem` is non-NULL and properly aligned for `T`.
+fn check_ptr<T>(mem: *const T) -> Result<(), VolatileError> {
+    let addr = mem as usize;
+    let align = mem::align_of::<T>();
+    if addr == 0 {
+        Err(VolatileError::Null)
+    } else if !addr.is_multiple_of(align) {
+        Err(VolatileError::Misaligned { addr, align })
+    } else {
+        Ok(())
+    }
+}
+
 impl<T: Copy, P> fmt::Debug for Volatile<T, P> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.pad(type_name::<Self>())
     }
 }
+
+/// Projects `vol` to the field whose address is returned by `field`.
+///
+/// # Panics
+///
+/// This function will panic if the field is not entirely contained in `vol`.
+///
+/// # Safety
+/// `field` must return the address of a field of type `U` within the `T`
+/// pointed to by its argument.
+#[doc(hidden)]
+pub unsafe fn project<T, U, P, F>(vol: &Volatile<T, P>, field: F) -> &Volatile<U, P>
+where
+    T: Copy,
+    U: Copy,
+    F: FnOnce(*const T) -> *const U,
+{
+    let () = Volatile::<U, P>::ASSERT_NOT_ZST;
+    let base = vol as *const _ as *const T;
+    let ptr = field(base);
+    assert_contained::<T, U>(base as usize, ptr as usize);
+    // SAFETY: The caller must ensure `ptr` is the address of a field of type
+    // `U`, which is valid because it is contained in `vol`. It is safe to cast
+    // to `*const Volatile<U, P>` because `Volatile` is transparent.
+    unsafe { &*(ptr as *const Volatile<U, P>) }
+}
+
+/// Projects `vol` to the field whose address is returned by `field`.
+///
+/// # Panics
+///
+/// This function will panic if the field is not entirely contained in `vol`.
+///
+/// # Safety
+/// `field` must return the address of a field of type `U` within the `T`
+/// pointed to by its argument.
+#[doc(hidden)]
+pub unsafe fn project_mut<T, U, P, F>(vol: &mut Volatile<T, P>, field: F) -> &mut Volatile<U, P>
+where
+    T: Copy,
+    U: Copy,
+    F: FnOnce(*mut T) -> *mut U,
+{
+    let () = Volatile::<U, P>::ASSERT_NOT_ZST;
+    let base = vol as *mut _ as *mut T;
+    let ptr = field(base);
+    assert_contained::<T, U>(base as usize, ptr as usize);
+    // SAFETY: The caller must ensure `ptr` is the address of a field of type
+    // `U`, which is valid because it is contained in `vol`. It is safe to cast
+    // to `*mut Volatile<U, P>` because `Volatile` is transparent.
+    unsafe { &mut *(ptr as *mut Volatile<U, P>) }
+}
+
+fn assert_contained<T, U>(base: usize, addr: usize) {
+    let offset = addr.wrapping_sub(base);
+    assert!(
+        offset <= mem::size_of::<T>() && mem::size_of::<U>() <= mem::size_of::<T>() - offset,
+        "projected field is not contained in `{}`",
+        type_name::<T>()
+    );
+}
+
+fn debug_assert_projection<T, U>(addr: usize, offset: usize) {
+    debug_assert!(
+        offset <= mem::size_of::<T>() && mem::size_of::<U>() <= mem::size_of::<T>() - offset,
+        "`{}` at offset {:#x} is not contained in `{}`",
+        type_name::<U>(),
+        offset,
+        type_name::<T>()
+    );
+    debug_assert!(
+        addr.is_multiple_of(mem::align_of::<U>()),
+        "`{}` at address {:#x} is not properly aligned",
+        type_name::<U>(),
+        addr
+    );
+}
@@ -0,0 +1,73 @@
+use volatile_register::{RO, RW, WO};
+
+use crate::{
+    volatile::{Read, Write},
+    Volatile, VolatileReadOnly, VolatileWriteOnly,
+};
+
+impl<T: Copy, P: Read> Volatile<T, P> {
+    /// Returns a [`RO`] view of `self`.
+    pub fn as_ro(&self) -> &RO<T> {
+        // SAFETY: `RO` only allows reads, which `self` permits. It is safe to
+        // cast to `*const RO<T>` because both types are transparent.
+        unsafe { &*(self as *const Self as *const RO<T>) }
+    }
+}
+
+impl<T: Copy, P: Read + Write> Volatile<T, P> {
+    /// Returns a [`RW`] view of `self`.
+    ///
+    /// A mutable reference is required because a [`RW`] can be written through
+    /// a shared reference.
+    pub fn as_rw(&mut self) -> &RW<T> {
+        // SAFETY: `self` is a mutable reference, so the memory cannot be
+        // accessed other than through the returned reference while it exists.
+        // It is safe to cast to `*const RW<T>` because both types are
+        // transparent.
+        unsafe { &*(self as *mut Self as *const RW<T>) }
+    }
+}
+
+impl<T: Copy, P: Write> Volatile<T, P> {
+    /// Returns a [`WO`] view of `self`.
+    ///
+    /// A mutable reference is required because a [`WO`] can be written through
+    /// a shared reference.
+    pub fn as_wo(&mut self) -> &WO<T> {
+        // SAFETY: `self` is a mutable reference, so the memory cannot be
+        // accessed other than through the returned reference while it exists.
+        // It is safe to cast to `*const WO<T>` because both types are
+        // transparent.
+        unsafe { &*(self as *mut Self as *const WO<T>) }
+    }
+}
+
+impl<'a, T: Copy> From<&'a RO<T>> for &'a VolatileReadOnly<T> {
+    fn from(reg: &'a RO<T>) -> &'a VolatileReadOnly<T> {
+        // SAFETY: `RO` does not allow writes, so the memory is not modified
+        // through `reg`, and `VolatileReadOnly` only allows reads. It is safe
+        // to cast to `*const VolatileReadOnly<T>` because both types are
+        // transparent.
+        unsafe { &*(reg as *const RO<T> as *const VolatileReadOnly<T>) }
+    }
+}
+
+impl<'a, T: Copy> From<&'a mut RW<T>> for &'a mut Volatile<T> {
+    fn from(reg: &'a mut RW<T>) -> &'a mut Volatile<T> {
+        // SAFETY: `reg` is a mutable reference, so the memory cannot be
+        // accessed other than through the returned reference while it exists.
+        // It is safe to cast to `*mut Volatile<T>` because both types are
+        // transparent.
+        unsafe { &mut *(reg as *mut RW<T> as *mut Volatile<T>) }
+    }
+}
+
+impl<'a, T: Copy> From<&'a mut WO<T>> for &'a mut VolatileWriteOnly<T> {
+    fn from(reg: &'a mut WO<T>) -> &'a mut VolatileWriteOnly<T> {
+        // SAFETY: `reg` is a mutable reference, so the memory cannot be
+        // accessed other than through the returned reference while it exists.
+        // It is safe to cast to `*mut VolatileWriteOnly<T>` because both types
+        // are transparent.
+        unsafe { &mut *(reg as *mut WO<T> as *mut VolatileWriteOnly<T>) }
+    }
+}
@@ -0,0 +1,150 @@
+use core::{
+    any::type_name,
+    arch::wasm32,
+    convert::TryFrom,
+    fmt,
+    ops::{Deref, DerefMut},
+    ptr::{self, NonNull},
+    slice,
+};
+
+use crate::{Volatile, VolatileError};
+
+const PAGE_SIZE: usize = 65536;
+
+/// A region of the linear memory of a WebAssembly module, accessed as a slice
+/// of volatile bytes.
+///
+/// This is intended for memory which is shared with JavaScript or with other
+/// instances, such as a shared `WebAssembly.Memory` used by several workers.
+/// Since the compiler cannot see accesses made by JavaScript or by other
+/// instances, ordinary accesses to such memory may be elided or merged, while
+/// accesses through a `WasmSharedRegion` are always performed.
+///
+/// The region is identified by its offset and length in linear memory, which
+/// are the values exchanged with JavaScript, such as a `byteOffset` and
+/// `byteLength` of a typed array. Like `VolatileMmap`, it dereferences to a
+/// slice of [`Volatile<u8>`](Volatile), and fixed-size views of it can be
+/// obtained with [`array`](Self::array) and [`array_mut`](Self::array_mut).
+///
+/// # Example
+/// ```no_run
+/// use volatile_mem::{VolatileRead, WasmSharedRegion};
+///
+/// // The offset and length of a buffer, as agreed with JavaScript.
+/// let region = unsafe { WasmSharedRegion::new(0x10000, 256)? };
+/// let flag = region[0].read();
+/// # Ok::<(), volatile_mem::VolatileError>(())
+/// ```
+pub struct WasmSharedRegion {
+    ptr: NonNull<u8>,
+    len: usize,
+}
+
+impl WasmSharedRegion {
+    /// Creates a handle to the `len` bytes of memory index 0 (the default
+    /// linear memory) starting at `offset`.
+    ///
+    /// # Errors
+    /// Returns [`VolatileError::Null`] if `offset` is zero and `len` is not,
+    /// or [`VolatileError::OutOfBounds`] if the region is not entirely within
+    /// the current size of linear memory.
+    ///
+    /// # Safety
+    /// The region must not be accessed through any Rust reference other than
+    /// those obtained from the handle while it exists, and must not be used
+    /// by Rust for anything else, such as the stack or the heap. Accesses by
+    /// JavaScript or by other instances are permitted, since the memory is
+    /// only accessed volatilely.
+    pub unsafe fn new(offset: usize, len: usize) -> Result<Self, VolatileError> {
+        let size = wasm32::memory_size(0).saturating_mul(PAGE_SIZE);
+        if offset > size || len > size - offset {
+            return Err(VolatileError::OutOfBounds { offset, len, size });
+        }
+        if len == 0 {
+            return Ok(WasmSharedRegion {
+                ptr: NonNull::dangling(),
+                len,
+            });
+        }
+        let ptr =
+            NonNull::new(ptr::with_exposed_provenance_mut(offset)).ok_or(VolatileError::Null)?;
+        Ok(WasmSharedRegion { ptr, len })
+    }
+
+    /// Returns the offset of the region in linear memory.
+    pub fn offset(&self) -> usize {
+        if self.len == 0 {
+            0
+        } else {
+            self.ptr.as_ptr().addr()
+        }
+    }
+
+    /// Returns the length of the region in bytes.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the region has a length of zero.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns a pointer to the start of the region.
+    pub fn as_ptr(&self) -> *mut u8 {
+        self.ptr.as_ptr()
+    }
+
+    /// Returns a reference to the `N` bytes starting `offset` bytes into the
+    /// region, or `None` if they are not all within the region.
+    pub fn array<const N: usize>(&self, offset: usize) -> Option<&Volatile<[u8; N]>> {
+        let bytes = self.get(offset..offset.checked_add(N)?)?;
+        <&Volatile<[u8; N]>>::try_from(bytes).ok()
+    }
+
+    /// Returns a mutable reference to the `N` bytes starting `offset` bytes
+    /// into the region, or `None` if they are not all within the region.
+    pub fn array_mut<const N: usize>(&mut self, offset: usize) -> Option<&mut Volatile<[u8; N]>> {
+        let bytes = self.get_mut(offset..offset.checked_add(N)?)?;
+        <&mut Volatile<[u8; N]>>::try_from(bytes).ok()
+    }
+}
+
+impl Deref for WasmSharedRegion {
+    type Target = [Volatile<u8>];
+
+    fn deref(&self) -> &[Volatile<u8>] {
+        // SAFETY: The region is within linear memory, every byte of which is
+        // initialized, and the creator of the handle must ensure it is not
+        // otherwise used by Rust.
+        unsafe { slice::from_raw_parts(self.as_ptr() as *const Volatile<u8>, self.len) }
+    }
+}
+
+impl DerefMut for WasmSharedRegion {
+    fn deref_mut(&mut self) -> &mut [Volatile<u8>] {
+        // SAFETY: The region is within linear memory, every byte of which is
+        // initialized, and the creator of the handle must ensure it is not
+        // otherwise used by Rust.
+        unsafe { slice::from_raw_parts_mut(self.as_ptr() as *mut Volatile<u8>, self.len) }
+    }
+}
+
+// SAFETY: Linear memory is shared by all threads of an instance, and the
+// creator of the handle must ensure it is not otherwise used by Rust.
+unsafe impl Send for WasmSharedRegion {}
+// SAFETY: Shared access only permits reads of the bytes.
+unsafe impl Sync for WasmSharedRegion {}
+
+impl fmt::Debug for WasmSharedRegion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {{ {:#x}, {:#x} }}",
+            type_name::<Self>(),
+            self.offset(),
+            self.len
+        )
+    }
+}
@@ -0,0 +1,124 @@
+use core::{any::type_name, fmt, hint, ops::ControlFlow};
+
+use crate::{
+    bitfield::UInt,
+    volatile::{Read, ReadWrite},
+    Volatile, VolatileRead,
+};
+
+/// A watcher which detects changes in the value of a readable register, such
+/// as a GPIO input register or a status word in shared memory.
+///
+/// The watcher remembers the last value it observed. Each call to
+/// [`check`](Self::check) or [`check_edges`](Self::check_edges) performs a
+/// volatile read, reports how the value differs, and remembers the new value.
+///
+/// # Example
+/// ```
+/// use volatile_mem::{Volatile, VolatileWatch};
+///
+/// let vol: &Volatile<u8> = Volatile::from_ref(&0b01);
+/// let mut watch = VolatileWatch::new(vol);
+/// assert_eq!(watch.check(), None);
+/// assert!(watch.check_edges().is_empty());
+/// assert_eq!(watch.last(), 0b01);
+/// ```
+pub struct VolatileWatch<'a, T: Copy, P = ReadWrite> {
+    vol: &'a Volatile<T, P>,
+    last: T,
+}
+
+impl<'a, T: Copy, P: Read> VolatileWatch<'a, T, P> {
+    /// Creates a watcher of `vol`, performing a volatile read to obtain its
+    /// initial value.
+    pub fn new(vol: &'a Volatile<T, P>) -> Self {
+        VolatileWatch {
+            vol,
+            last: vol.read(),
+        }
+    }
+
+    /// Returns the last value observed.
+    pub fn last(&self) -> T {
+        self.last
+    }
+}
+
+impl<T: Copy + PartialEq, P: Read> VolatileWatch<'_, T, P> {
+    /// Performs a volatile read of the register, returning the new value if it
+    /// differs from the last value observed.
+    pub fn check(&mut self) -> Option<T> {
+        let value = self.vol.read();
+        if value == self.last {
+            return None;
+        }
+        self.last = value;
+        Some(value)
+    }
+
+    /// Repeatedly polls the register, calling `f` with the old and new values
+    /// each time the value changes, until `f` returns [`ControlFlow::Break`].
+    ///
+    /// [`spin_loop`](hint::spin_loop) is called between polls in which the
+    /// value does not change.
+    pub fn watch<B>(&mut self, mut f: impl FnMut(T, T) -> ControlFlow<B>) -> B {
+        loop {
+            let old = self.last;
+            match self.check() {
+                Some(new) => {
+                    if let ControlFlow::Break(result) = f(old, new) {
+                        return result;
+                    }
+                }
+                None => hint::spin_loop(),
+            }
+        }
+    }
+}
+
+impl<T: UInt, P: Read> VolatileWatch<'_, T, P> {
+    /// Performs a volatile read of the register, returning the bits which have
+    /// been set and cleared since the last value observed.
+    pub fn check_edges(&mut self) -> Edges<T> {
+        let value = self.vol.read();
+        let changed = value ^ self.last;
+        self.last = value;
+        Edges {
+            rising: changed & value,
+            falling: changed & !value,
+        }
+    }
+}
+
+impl<T: Copy, P> fmt::Debug for VolatileWatch<'_, T, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(type_name::<Self>())
+    }
+}
+
+/// The bits of a register which changed between two reads, as returned by
+/// [`VolatileWatch::check_edges`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Edges<T> {
+    /// The bits which were clear and are now set.
+    pub rising: T,
+    /// The bits which were set and are now clear.
+    pub falling: T,
+}
+
+impl<T: UInt> Edges<T> {
+    /// Returns `true` if no bits changed.
+    pub fn is_empty(&self) -> bool {
+        self.rising == T::ZERO && self.falling == T::ZERO
+    }
+
+    /// Returns `true` if any of the bits in `mask` changed from clear to set.
+    pub fn rose(&self, mask: T) -> bool {
+        self.rising & mask != T::ZERO
+    }
+
+    /// Returns `true` if any of the bits in `mask` changed from set to clear.
+    pub fn fell(&self, mask: T) -> bool {
+        self.falling & mask != T::ZERO
+    }
+}
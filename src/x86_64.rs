@@ -0,0 +1,66 @@
+use x86_64::{PhysAddr, VirtAddr};
+
+use crate::Volatile;
+
+impl<T: Copy, P> Volatile<T, P> {
+    /// Converts a virtual address into a reference to `Volatile<T>`, like
+    /// [`from_ptr`](Self::from_ptr).
+    ///
+    /// # Safety
+    /// The requirements are the same as for [`from_ptr`](Self::from_ptr).
+    pub unsafe fn from_virt_addr<'a>(addr: VirtAddr) -> &'a Self {
+        // SAFETY: The caller must ensure the address is safe to use.
+        unsafe { Self::from_ptr(addr.as_ptr()) }
+    }
+
+    /// Converts a virtual address into a mutable reference to `Volatile<T>`,
+    /// like [`from_mut_ptr`](Self::from_mut_ptr).
+    ///
+    /// # Safety
+    /// The requirements are the same as for
+    /// [`from_mut_ptr`](Self::from_mut_ptr).
+    pub unsafe fn from_virt_addr_mut<'a>(addr: VirtAddr) -> &'a mut Self {
+        // SAFETY: The caller must ensure the address is safe to use.
+        unsafe { Self::from_mut_ptr(addr.as_mut_ptr()) }
+    }
+
+    /// Converts a physical address into a reference to `Volatile<T>`, where
+    /// all of physical memory is mapped at the virtual address
+    /// `physical_memory_offset`, as set up by bootloaders such as
+    /// `bootloader`.
+    ///
+    /// # Safety
+    /// The requirements are the same as for [`from_ptr`](Self::from_ptr), for
+    /// the virtual address `physical_memory_offset + addr`.
+    ///
+    /// # Panics
+    /// Panics if the resulting virtual address is not canonical.
+    pub unsafe fn from_phys_addr<'a>(addr: PhysAddr, physical_memory_offset: VirtAddr) -> &'a Self {
+        // SAFETY: The caller must ensure the address is safe to use.
+        unsafe { Self::from_virt_addr(physical_memory_offset + addr.as_u64()) }
+    }
+
+    /// Converts a physical address into a mutable reference to `Volatile<T>`,
+    /// where all of physical memory is mapped at the virtual address
+    /// `physical_memory_offset`.
+    ///
+    /// # Safety
+    /// The requirements are the same as for
+    /// [`from_mut_ptr`](Self::from_mut_ptr), for the virtual address
+    /// `physical_memory_offset + addr`.
+    ///
+    /// # Panics
+    /// Panics if the resulting virtual address is not canonical.
+    pub unsafe fn from_phys_addr_mut<'a>(
+        addr: PhysAddr,
+        physical_memory_offset: VirtAddr,
+    ) -> &'a mut Self {
+        // SAFETY: The caller must ensure the address is safe to use.
+        unsafe { Self::from_virt_addr_mut(physical_memory_offset + addr.as_u64()) }
+    }
+
+    /// Returns the virtual address of the data in `self`.
+    pub fn virt_addr(&self) -> VirtAddr {
+        VirtAddr::from_ptr(self.as_ptr())
+    }
+}
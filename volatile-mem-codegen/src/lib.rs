@@ -0,0 +1,554 @@
+//! Generates Rust modules describing register maps using the
+//! [`volatile-mem`](https://docs.rs/volatile-mem) crate.
+//!
+//! A [`Device`] is made up of [`Peripheral`]s, each of which is made up of
+//! [`Register`]s. [`Device::generate`] produces a module for each peripheral,
+//! containing a `RegisterBlock` struct declared with `volatile_mem::mmio_struct!`,
+//! so that the layout of each register block is checked at compile time. Gaps
+//! between registers are filled with `volatile_mem::Reserved` fields.
+//!
+//! This is intended to be used from a build script, with the generated code
+//! written to `OUT_DIR` and included with `include!`.
+//!
+//! # Example
+//! ```
+//! use volatile_mem_codegen::{Access, Device, Peripheral, Register, Width};
+//!
+//! let device = Device::new().peripheral(
+//!     Peripheral::new("UART0", 0x4000_0000)
+//!         .register(Register::new("DR", 0x0, Width::U32, Access::ReadWrite).description("Data."))
+//!         .register(Register::new("SR", 0x8, Width::U16, Access::ReadOnly).reset(0x80)),
+//! );
+//! let code = device.generate()?;
+//! assert!(code.contains(
+//!     "
+//!             /// Data.
+//!             (0x0 => pub dr: u32, rw),
+//!             (0x4 => _reserved0),
+//!             (0x8 => pub sr: u16, ro, reset = 0x80),
+//!             (0xa => _reserved1),
+//!             (0xc => @END),
+//! "
+//! ));
+//! # Ok::<(), volatile_mem_codegen::Error>(())
+//! ```
+//!
+//! This generates the following code.
+//! ```text
+//! // Generated by volatile-mem-codegen. Do not edit.
+//! pub mod uart0 {
+//!     /// The base address of the `UART0` register block.
+//!     pub const BASE_ADDRESS: usize = 0x40000000;
+//!
+//!     volatile_mem::mmio_struct! {
+//!         /// The `UART0` register block.
+//!         pub struct RegisterBlock {
+//!             /// Data.
+//!             (0x0 => pub dr: u32, rw),
+//!             (0x4 => _reserved0),
+//!             (0x8 => pub sr: u16, ro, reset = 0x80),
+//!             (0xa => _reserved1),
+//!             (0xc => @END),
+//!         }
+//!     }
+//!
+//!     /// Returns a mutable reference to the `UART0` register block.
+//!     ///
+//!     /// # Safety
+//!     /// The register block must be valid at `BASE_ADDRESS`, and no other
+//!     /// reference to it may be used while the returned reference exists.
+//!     pub unsafe fn registers<'a>() -> &'a mut RegisterBlock {
+//!         // SAFETY: The caller must ensure the register block is valid and not
+//!         // otherwise accessed.
+//!         unsafe { &mut *(BASE_ADDRESS as *mut RegisterBlock) }
+//!     }
+//! }
+//! ```
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+#![warn(unused_extern_crates)]
+#![warn(clippy::todo)]
+#![warn(clippy::unimplemented)]
+#![warn(clippy::unwrap_used)]
+
+use std::{collections::BTreeSet, error, fmt, fmt::Write as _, fs, io, path::Path};
+
+/// The access permission of a [`Register`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Access {
+    /// The register can be read and written.
+    ReadWrite,
+    /// The register can only be read.
+    ReadOnly,
+    /// The register can only be written.
+    WriteOnly,
+}
+
+impl Access {
+    fn keyword(self) -> &'static str {
+        match self {
+            Access::ReadWrite => "rw",
+            Access::ReadOnly => "ro",
+            Access::WriteOnly => "wo",
+        }
+    }
+}
+
+/// The width of a [`Register`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Width {
+    /// An 8-bit register.
+    U8,
+    /// A 16-bit register.
+    U16,
+    /// A 32-bit register.
+    U32,
+    /// A 64-bit register.
+    U64,
+}
+
+impl Width {
+    /// Returns the size of the register in bytes.
+    pub fn size(self) -> u64 {
+        match self {
+            Width::U8 => 1,
+            Width::U16 => 2,
+            Width::U32 => 4,
+            Width::U64 => 8,
+        }
+    }
+
+    fn type_name(self) -> &'static str {
+        match self {
+            Width::U8 => "u8",
+            Width::U16 => "u16",
+            Width::U32 => "u32",
+            Width::U64 => "u64",
+        }
+    }
+}
+
+/// A register of a [`Peripheral`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Register {
+    name: String,
+    description: Option<String>,
+    offset: u64,
+    width: Width,
+    access: Access,
+    reset: Option<u64>,
+}
+
+impl Register {
+    /// Creates a register at `offset` bytes from the start of its peripheral.
+    pub fn new(name: impl Into<String>, offset: u64, width: Width, access: Access) -> Self {
+        Register {
+            name: name.into(),
+            description: None,
+            offset,
+            width,
+            access,
+            reset: None,
+        }
+    }
+
+    /// Sets the description of the register, which is used as its doc
+    /// comment.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Sets the reset value of the register.
+    ///
+    /// [`Device::generate`] fails with [`Error::ResetOutOfRange`] if the value
+    /// does not fit in the width of the register.
+    ///
+    /// # Example
+    /// ```
+    /// use volatile_mem_codegen::{Access, Device, Error, Peripheral, Register, Width};
+    ///
+    /// let device = Device::new().peripheral(
+    ///     Peripheral::new("TIMER", 0x4000_1000)
+    ///         .register(Register::new("CTRL", 0x0, Width::U8, Access::ReadWrite).reset(0x1ff)),
+    /// );
+    /// assert!(matches!(device.generate(), Err(Error::ResetOutOfRange { reset: 0x1ff, .. })));
+    /// ```
+    pub fn reset(mut self, reset: u64) -> Self {
+        self.reset = Some(reset);
+        self
+    }
+}
+
+/// A peripheral of a [`Device`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Peripheral {
+    name: String,
+    description: Option<String>,
+    base_address: u64,
+    size: Option<u64>,
+    registers: Vec<Register>,
+}
+
+impl Peripheral {
+    /// Creates a peripheral with no registers, located at `base_address`.
+    pub fn new(name: impl Into<String>, base_address: u64) -> Self {
+        Peripheral {
+            name: name.into(),
+            description: None,
+            base_address,
+            size: None,
+            registers: Vec::new(),
+        }
+    }
+
+    /// Sets the description of the peripheral, which is used as its doc
+    /// comment.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Sets the total size of the peripheral's register block in bytes.
+    ///
+    /// By default, the register block ends at the end of its last register,
+    /// rounded up to a multiple of the width of its widest register, which is
+    /// the alignment of the generated struct. [`Device::generate`] fails with
+    /// [`Error::MisalignedSize`] if `size` is not such a multiple.
+    pub fn size(mut self, size: u64) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Adds a register to the peripheral.
+    pub fn register(mut self, register: Register) -> Self {
+        self.registers.push(register);
+        self
+    }
+}
+
+/// A device made up of [`Peripheral`]s.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Device {
+    peripherals: Vec<Peripheral>,
+}
+
+impl Device {
+    /// Creates a device with no peripherals.
+    pub fn new() -> Self {
+        Device::default()
+    }
+
+    /// Adds a peripheral to the device.
+    pub fn peripheral(mut self, peripheral: Peripheral) -> Self {
+        self.peripherals.push(peripheral);
+        self
+    }
+
+    /// Generates Rust code for the device, with a module for each
+    /// peripheral.
+    pub fn generate(&self) -> Result<String, Error> {
+        let mut out = String::from("// Generated by volatile-mem-codegen. Do not edit.\n");
+        let mut modules = BTreeSet::new();
+        for peripheral in &self.peripherals {
+            let module = identifier(&peripheral.name)?;
+            if !modules.insert(module.clone()) {
+                return Err(Error::DuplicateName(module));
+            }
+            generate_peripheral(&mut out, &module, peripheral)?;
+        }
+        Ok(out)
+    }
+
+    /// Generates Rust code for the device, and writes it to `path`.
+    pub fn generate_to_file(&self, path: impl AsRef<Path>) -> Result<(), Error> {
+        let code = self.generate()?;
+        fs::write(path, code).map_err(Error::Io)
+    }
+}
+
+/// An error generating code for a [`Device`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Error {
+    /// A peripheral or register name is not a valid identifier.
+    InvalidName(String),
+    /// Two peripherals, or two fields of a peripheral, have the same name once
+    /// converted to an identifier, such as `CR` and `cr`, or a register named
+    /// like a generated reserved field, such as `_reserved0`.
+    DuplicateName(String),
+    /// Two registers of a peripheral overlap.
+    Overlap {
+        /// The name of the peripheral.
+        peripheral: String,
+        /// The name of the first register.
+        first: String,
+        /// The name of the second register.
+        second: String,
+    },
+    /// A register is not aligned to its width.
+    Misaligned {
+        /// The name of the peripheral.
+        peripheral: String,
+        /// The name of the register.
+        register: String,
+    },
+    /// A register extends past the size of its peripheral, or past the end of
+    /// the address space.
+    OutOfBounds {
+        /// The name of the peripheral.
+        peripheral: String,
+        /// The name of the register.
+        register: String,
+    },
+    /// The size of a peripheral is not a multiple of the width of its widest
+    /// register.
+    MisalignedSize {
+        /// The name of the peripheral.
+        peripheral: String,
+        /// The size of the peripheral.
+        size: u64,
+    },
+    /// The reset value of a register does not fit in its width.
+    ResetOutOfRange {
+        /// The name of the peripheral.
+        peripheral: String,
+        /// The name of the register.
+        register: String,
+        /// The reset value.
+        reset: u64,
+    },
+    /// The generated code could not be written.
+    Io(io::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidName(name) => write!(f, "`{}` is not a valid identifier", name),
+            Error::DuplicateName(name) => {
+                write!(f, "identifier `{}` is used more than once", name)
+            }
+            Error::Overlap {
+                peripheral,
+                first,
+                second,
+            } => write!(
+                f,
+                "registers `{}` and `{}` of `{}` overlap",
+                first, second, peripheral
+            ),
+            Error::Misaligned {
+                peripheral,
+                register,
+            } => write!(
+                f,
+                "register `{}` of `{}` is not aligned to its width",
+                register, peripheral
+            ),
+            Error::OutOfBounds {
+                peripheral,
+                register,
+            } => write!(
+                f,
+                "register `{}` extends past the end of `{}`",
+                register, peripheral
+            ),
+            Error::MisalignedSize { peripheral, size } => write!(
+                f,
+                "size {:#x} of `{}` is not a multiple of the width of its widest register",
+                size, peripheral
+            ),
+            Error::ResetOutOfRange {
+                peripheral,
+                register,
+                reset,
+            } => write!(
+                f,
+                "reset value {:#x} of register `{}` of `{}` does not fit in its width",
+                reset, register, peripheral
+            ),
+            Error::Io(err) => write!(f, "failed to write generated code: {}", err),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+fn generate_peripheral(
+    out: &mut String,
+    module: &str,
+    peripheral: &Peripheral,
+) -> Result<(), Error> {
+    let mut registers = peripheral.registers.iter().collect::<Vec<_>>();
+    registers.sort_by_key(|reg| reg.offset);
+
+    let mut end = 0;
+    let mut align = 1;
+    let mut prev: Option<&Register> = None;
+    let mut fields = String::new();
+    let mut reserved = 0;
+    let mut names = BTreeSet::new();
+    let mut reserve = |fields: &mut String, offset: u64| {
+        let name = format!("_reserved{}", reserved);
+        reserved += 1;
+        let _ = writeln!(fields, "            ({:#x} => {}),", offset, name);
+        name
+    };
+    for reg in registers {
+        let name = identifier(&reg.name)?;
+        if !names.insert(name.clone()) {
+            return Err(Error::DuplicateName(name));
+        }
+        if let Some(reset) = reg.reset {
+            if reset.checked_shr(8 * reg.width.size() as u32).unwrap_or(0) != 0 {
+                return Err(Error::ResetOutOfRange {
+                    peripheral: peripheral.name.clone(),
+                    register: reg.name.clone(),
+                    reset,
+                });
+            }
+        }
+        if reg.offset % reg.width.size() != 0 {
+            return Err(Error::Misaligned {
+                peripheral: peripheral.name.clone(),
+                register: reg.name.clone(),
+            });
+        }
+        if let Some(prev) = prev.filter(|_| reg.offset < end) {
+            return Err(Error::Overlap {
+                peripheral: peripheral.name.clone(),
+                first: prev.name.clone(),
+                second: reg.name.clone(),
+            });
+        }
+        if reg.offset > end {
+            let name = reserve(&mut fields, end);
+            if !names.insert(name.clone()) {
+                return Err(Error::DuplicateName(name));
+            }
+        }
+        doc_comment(&mut fields, "            ", reg.description.as_deref());
+        let reset = reg
+            .reset
+            .map(|reset| format!(", reset = {:#x}", reset))
+            .unwrap_or_default();
+        let _ = writeln!(
+            fields,
+            "            ({:#x} => pub {}: {}, {}{}),",
+            reg.offset,
+            name,
+            reg.width.type_name(),
+            reg.access.keyword(),
+            reset
+        );
+        end = reg
+            .offset
+            .checked_add(reg.width.size())
+            .ok_or_else(|| Error::OutOfBounds {
+                peripheral: peripheral.name.clone(),
+                register: reg.name.clone(),
+            })?;
+        align = align.max(reg.width.size());
+        prev = Some(reg);
+    }
+    let size = match peripheral.size {
+        Some(size) => size,
+        None => end
+            .checked_add(align - 1)
+            .map(|end| end & !(align - 1))
+            .ok_or_else(|| Error::OutOfBounds {
+                peripheral: peripheral.name.clone(),
+                register: prev.map(|reg| reg.name.clone()).unwrap_or_default(),
+            })?,
+    };
+    if let Some(prev) = prev.filter(|_| end > size) {
+        return Err(Error::OutOfBounds {
+            peripheral: peripheral.name.clone(),
+            register: prev.name.clone(),
+        });
+    }
+    if size % align != 0 {
+        return Err(Error::MisalignedSize {
+            peripheral: peripheral.name.clone(),
+            size,
+        });
+    }
+    if size > end {
+        let name = reserve(&mut fields, end);
+        if !names.insert(name.clone()) {
+            return Err(Error::DuplicateName(name));
+        }
+    }
+
+    doc_comment(out, "", peripheral.description.as_deref());
+    let _ = write!(
+        out,
+        "pub mod {module} {{
+    /// The base address of the `{name}` register block.
+    pub const BASE_ADDRESS: usize = {base:#x};
+
+    volatile_mem::mmio_struct! {{
+        /// The `{name}` register block.
+        pub struct RegisterBlock {{
+{fields}            ({size:#x} => @END),
+        }}
+    }}
+
+    /// Returns a mutable reference to the `{name}` register block.
+    ///
+    /// # Safety
+    /// The register block must be valid at `BASE_ADDRESS`, and no other
+    /// reference to it may be used while the returned reference exists.
+    pub unsafe fn registers<'a>() -> &'a mut RegisterBlock {{
+        // SAFETY: The caller must ensure the register block is valid and not
+        // otherwise accessed.
+        unsafe {{ &mut *(BASE_ADDRESS as *mut RegisterBlock) }}
+    }}
+}}
+",
+        module = module,
+        name = peripheral.name,
+        base = peripheral.base_address,
+        fields = fields,
+        size = size,
+    );
+
+    Ok(())
+}
+
+fn doc_comment(out: &mut String, indent: &str, description: Option<&str>) {
+    for line in description.into_iter().flat_map(str::lines) {
+        let _ = writeln!(out, "{}/// {}", indent, line.trim());
+    }
+}
+
+/// Converts `name` to a lowercase identifier.
+fn identifier(name: &str) -> Result<String, Error> {
+    let ident = name.to_ascii_lowercase();
+    let mut chars = ident.chars();
+    let valid = matches!(chars.next(), Some(c) if c == '_' || c.is_ascii_alphabetic())
+        && chars.all(|c| c == '_' || c.is_ascii_alphanumeric())
+        && ident != "_"
+        && !KEYWORDS.contains(&ident.as_str());
+    if valid {
+        Ok(ident)
+    } else {
+        Err(Error::InvalidName(name.into()))
+    }
+}
+
+const KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "crate",
+    "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if", "impl",
+    "in", "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref",
+    "return", "self", "static", "struct", "super", "trait", "true", "try", "type", "typeof",
+    "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
+];
@@ -0,0 +1,68 @@
+//! Checks that generated code compiles against `volatile-mem` and accesses the
+//! registers it describes.
+//!
+//! `generated/device.rs` is the output for [`device`], and must be regenerated
+//! when the output changes.
+use std::mem;
+
+use volatile_mem::{ResetMismatch, VolatileRead, VolatileWrite};
+use volatile_mem_codegen::{Access, Device, Peripheral, Register, Width};
+
+include!("generated/device.rs");
+
+fn device() -> Device {
+    Device::new()
+        .peripheral(
+            Peripheral::new("UART0", 0x4000_0000)
+                .description("Serial port.")
+                .register(
+                    Register::new("DR", 0x0, Width::U32, Access::ReadWrite).description("Data."),
+                )
+                .register(Register::new("SR", 0x8, Width::U16, Access::ReadOnly).reset(0x80))
+                .register(Register::new("CR", 0xc, Width::U32, Access::WriteOnly).reset(0)),
+        )
+        .peripheral(
+            Peripheral::new("TIMER", 0x4000_1000)
+                .size(0x20)
+                .register(Register::new("CTRL", 0x0, Width::U8, Access::ReadWrite))
+                .register(Register::new("COUNT", 0x8, Width::U64, Access::ReadWrite).reset(0)),
+        )
+        .peripheral(
+            Peripheral::new("GPIO", 0x4000_2000)
+                .register(Register::new("ODR", 0x0, Width::U32, Access::ReadWrite))
+                .register(Register::new("IDR", 0x4, Width::U16, Access::ReadOnly)),
+        )
+}
+
+#[test]
+fn generated_code_is_up_to_date() {
+    let code = device().generate().expect("the device is valid");
+    assert_eq!(code, include_str!("generated/device.rs"));
+}
+
+#[test]
+fn generated_code_accesses_registers() {
+    assert_eq!(uart0::BASE_ADDRESS, 0x4000_0000);
+    assert_eq!(mem::size_of::<uart0::RegisterBlock>(), 0x10);
+    assert_eq!(mem::size_of::<timer::RegisterBlock>(), 0x20);
+    assert_eq!(mem::size_of::<gpio::RegisterBlock>(), 0x8);
+    let _: unsafe fn() -> &'static mut uart0::RegisterBlock = uart0::registers;
+    let _: unsafe fn() -> &'static mut timer::RegisterBlock = timer::registers;
+    let _: unsafe fn() -> &'static mut gpio::RegisterBlock = gpio::registers;
+
+    let mut mem = [0u64; 2];
+    mem[1] = 0x80;
+    // SAFETY: `mem` is valid and aligned for the register block, and is not
+    // otherwise accessed while `uart` exists.
+    let uart = unsafe { &mut *(mem.as_mut_ptr() as *mut uart0::RegisterBlock) };
+    uart.dr.write(0x41);
+    assert_eq!(uart.dr.read(), 0x41);
+    assert_eq!(uart.sr.read(), uart0::RegisterBlock::sr);
+    uart.cr.write(1);
+    uart.reset_to_default();
+    let mut mismatches = 0;
+    uart.compare_to_reset(|_: ResetMismatch<'_>| mismatches += 1);
+    assert_eq!(mismatches, 0);
+    assert_eq!(mem[0], 0x41);
+    assert_eq!(mem[1], 0x80);
+}
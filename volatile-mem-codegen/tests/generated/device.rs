@@ -0,0 +1,81 @@
+// Generated by volatile-mem-codegen. Do not edit.
+/// Serial port.
+pub mod uart0 {
+    /// The base address of the `UART0` register block.
+    pub const BASE_ADDRESS: usize = 0x40000000;
+
+    volatile_mem::mmio_struct! {
+        /// The `UART0` register block.
+        pub struct RegisterBlock {
+            /// Data.
+            (0x0 => pub dr: u32, rw),
+            (0x4 => _reserved0),
+            (0x8 => pub sr: u16, ro, reset = 0x80),
+            (0xa => _reserved1),
+            (0xc => pub cr: u32, wo, reset = 0x0),
+            (0x10 => @END),
+        }
+    }
+
+    /// Returns a mutable reference to the `UART0` register block.
+    ///
+    /// # Safety
+    /// The register block must be valid at `BASE_ADDRESS`, and no other
+    /// reference to it may be used while the returned reference exists.
+    pub unsafe fn registers<'a>() -> &'a mut RegisterBlock {
+        // SAFETY: The caller must ensure the register block is valid and not
+        // otherwise accessed.
+        unsafe { &mut *(BASE_ADDRESS as *mut RegisterBlock) }
+    }
+}
+pub mod timer {
+    /// The base address of the `TIMER` register block.
+    pub const BASE_ADDRESS: usize = 0x40001000;
+
+    volatile_mem::mmio_struct! {
+        /// The `TIMER` register block.
+        pub struct RegisterBlock {
+            (0x0 => pub ctrl: u8, rw),
+            (0x1 => _reserved0),
+            (0x8 => pub count: u64, rw, reset = 0x0),
+            (0x10 => _reserved1),
+            (0x20 => @END),
+        }
+    }
+
+    /// Returns a mutable reference to the `TIMER` register block.
+    ///
+    /// # Safety
+    /// The register block must be valid at `BASE_ADDRESS`, and no other
+    /// reference to it may be used while the returned reference exists.
+    pub unsafe fn registers<'a>() -> &'a mut RegisterBlock {
+        // SAFETY: The caller must ensure the register block is valid and not
+        // otherwise accessed.
+        unsafe { &mut *(BASE_ADDRESS as *mut RegisterBlock) }
+    }
+}
+pub mod gpio {
+    /// The base address of the `GPIO` register block.
+    pub const BASE_ADDRESS: usize = 0x40002000;
+
+    volatile_mem::mmio_struct! {
+        /// The `GPIO` register block.
+        pub struct RegisterBlock {
+            (0x0 => pub odr: u32, rw),
+            (0x4 => pub idr: u16, ro),
+            (0x6 => _reserved0),
+            (0x8 => @END),
+        }
+    }
+
+    /// Returns a mutable reference to the `GPIO` register block.
+    ///
+    /// # Safety
+    /// The register block must be valid at `BASE_ADDRESS`, and no other
+    /// reference to it may be used while the returned reference exists.
+    pub unsafe fn registers<'a>() -> &'a mut RegisterBlock {
+        // SAFETY: The caller must ensure the register block is valid and not
+        // otherwise accessed.
+        unsafe { &mut *(BASE_ADDRESS as *mut RegisterBlock) }
+    }
+}
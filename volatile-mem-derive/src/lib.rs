@@ -0,0 +1,247 @@
+//! Derive macros for the [`volatile-mem`](https://docs.rs/volatile-mem) crate.
+//!
+//! These macros are re-exported by `volatile-mem` when its `derive` feature is
+//! enabled, and should be used through that crate rather than directly.
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+#![warn(unused_extern_crates)]
+#![warn(clippy::todo)]
+#![warn(clippy::unimplemented)]
+#![warn(clippy::unwrap_used)]
+
+mod parse;
+
+use parse::{Permission, Struct};
+use proc_macro::TokenStream;
+
+/// Generates per-field accessors on `Volatile` references to a struct.
+///
+/// For a struct named `Regs`, this generates a trait named `RegsFields<P>`,
+/// with the same visibility as the struct, which is implemented for
+/// `Volatile<Regs, P>`. For each field, the trait has a method with the same
+/// name as the field returning a shared reference to the field as a
+/// `Volatile`, and a method with a `_mut` suffix returning a mutable
+/// reference. By default, each field has the same permission as the containing
+/// `Volatile`, but this can be changed with one of the following attributes.
+///
+/// - `#[volatile(read_only)]`: the field is read-only, and only the shared
+///   accessor is generated. It requires the containing `Volatile` to be
+///   readable.
+///
+/// - `#[volatile(write_only)]`: the field is write-only, and only the mutable
+///   accessor is generated. It requires the containing `Volatile` to be
+///   writable.
+///
+/// - `#[volatile(skip)]`: no accessors are generated for the field, such as for
+///   reserved regions.
+///
+/// A field may also be given a reset value with `#[volatile(reset = value)]`,
+/// which may be combined with one of the above, as in
+/// `#[volatile(read_only, reset = 0)]`. This generates an associated constant
+/// of the struct, with the same name and visibility as the field, holding the
+/// reset value. The trait's `reset_to_default` method, which requires the
+/// containing `Volatile` to be writable, writes the reset value of each field
+/// which has one and which can be written.
+///
+/// Only structs with named fields and no generic parameters are supported. The
+/// struct must be `Copy`, as required by `Volatile`.
+#[proc_macro_derive(VolatileFields, attributes(volatile))]
+pub fn derive_volatile_fields(input: TokenStream) -> TokenStream {
+    match parse::parse_struct(input) {
+        Ok(item) => volatile_fields(&item),
+        Err(msg) => compile_error(&msg),
+    }
+    .parse()
+    .expect("generated code should be valid")
+}
+
+/// Generates functions which read or write a whole struct through a `Volatile`
+/// reference, one field at a time.
+///
+/// For a struct named `Regs`, this generates the associated functions
+/// `Regs::read_all(&Volatile<Regs, P>) -> Regs` and
+/// `Regs::write_all(&mut Volatile<Regs, P>, &Regs)`, with the same visibility
+/// as the struct. They access each field with a separate volatile access, in
+/// declaration order, rather than accessing the whole struct at once, which
+/// the compiler may split into accesses of any width.
+///
+/// The `#[volatile(...)]` attributes of `VolatileFields` are respected:
+/// `write_all` does not write `read_only` or `skip` fields, and `read_all` does
+/// not read `write_only` or `skip` fields, which are instead set to
+/// `Default::default()`.
+///
+/// Only structs with named fields and no generic parameters are supported. The
+/// struct must be `Copy`, as required by `Volatile`.
+#[proc_macro_derive(VolatileSnapshot, attributes(volatile))]
+pub fn derive_volatile_snapshot(input: TokenStream) -> TokenStream {
+    match parse::parse_struct(input) {
+        Ok(item) => volatile_snapshot(&item),
+        Err(msg) => compile_error(&msg),
+    }
+    .parse()
+    .expect("generated code should be valid")
+}
+
+fn compile_error(msg: &str) -> String {
+    format!("::core::compile_error!({:?});", msg)
+}
+
+fn volatile_fields(item: &Struct) -> String {
+    let vol = "::volatile_mem::Volatile";
+    let private = "::volatile_mem::__private";
+    let trait_name = format!("{}Fields", item.name);
+    let mut decls = String::new();
+    let mut impls = String::new();
+    let mut resets = String::new();
+    let mut reset_writes = String::new();
+
+    for field in &item.fields {
+        let docs = field.docs.concat();
+        let name = &field.name;
+        let name_mut = format!("{}_mut", field.bare_name());
+        let ty = &field.ty;
+        let project = format!(
+            "::core::ptr::addr_of!((*(self as *const Self as *const {})).{})",
+            item.name, name
+        );
+        let project_mut = format!(
+            "::core::ptr::addr_of_mut!((*(self as *mut Self as *mut {})).{})",
+            item.name, name
+        );
+
+        let (shared, exclusive) = match field.perm {
+            Permission::Inherit => (
+                Some((format!("{}<{}, P>", vol, ty), String::new())),
+                Some((format!("{}<{}, P>", vol, ty), String::new())),
+            ),
+            Permission::ReadOnly => (
+                Some((
+                    format!("::volatile_mem::VolatileReadOnly<{}>", ty),
+                    format!("where P: {}::Read", private),
+                )),
+                None,
+            ),
+            Permission::WriteOnly => (
+                None,
+                Some((
+                    format!("::volatile_mem::VolatileWriteOnly<{}>", ty),
+                    format!("where P: {}::Write", private),
+                )),
+            ),
+            Permission::Skip => (None, None),
+        };
+
+        if let Some((ret, bound)) = shared {
+            decls.push_str(&format!(
+                "{} fn {}(&self) -> &{} {};",
+                docs, name, ret, bound
+            ));
+            impls.push_str(&format!(
+                "fn {}(&self) -> &{} {} {{ unsafe {{ &*({} as *const {}) }} }}",
+                name, ret, bound, project, ret
+            ));
+        }
+        if let Some(reset) = &field.reset {
+            resets.push_str(&format!(
+                "#[doc = {doc:?}] #[allow(non_upper_case_globals)] {vis} const {name}: {ty} = {reset};",
+                doc = format!("The reset value of `{}`.", field.bare_name()),
+                vis = field.vis,
+                name = name,
+                ty = ty,
+                reset = reset,
+            ));
+            if exclusive.is_some() {
+                reset_writes.push_str(&format!(
+                    "::volatile_mem::VolatileWrite::write(self.{}(), {}::{});",
+                    name_mut, item.name, name
+                ));
+            }
+        }
+        if let Some((ret, bound)) = exclusive {
+            decls.push_str(&format!(
+                "{} fn {}(&mut self) -> &mut {} {};",
+                docs, name_mut, ret, bound
+            ));
+            impls.push_str(&format!(
+                "fn {}(&mut self) -> &mut {} {} {{ unsafe {{ &mut *({} as *mut {}) }} }}",
+                name_mut, ret, bound, project_mut, ret
+            ));
+        }
+    }
+
+    format!(
+        "#[doc = {doc:?}] {vis} trait {trait_name}<P> {{ {decls} \
+         #[doc = {reset_doc:?}] fn reset_to_default(&mut self) where P: {private}::Write; }} \
+         impl<P> {trait_name}<P> for {vol}<{name}, P> {{ {impls} \
+         fn reset_to_default(&mut self) where P: {private}::Write {{ {reset_writes} }} }} \
+         impl {name} {{ {resets} }}",
+        doc = format!("Volatile field accessors for [`{}`].", item.name),
+        reset_doc = "Writes the reset value of each field which has one and which can be written.",
+        vis = item.vis,
+        trait_name = trait_name,
+        decls = decls,
+        private = private,
+        vol = vol,
+        name = item.name,
+        impls = impls,
+        reset_writes = reset_writes,
+        resets = resets,
+    )
+}
+
+fn volatile_snapshot(item: &Struct) -> String {
+    let vol = "::volatile_mem::Volatile";
+    let private = "::volatile_mem::__private";
+    let mut reads = String::new();
+    let mut writes = String::new();
+
+    for field in &item.fields {
+        let name = &field.name;
+        let ty = &field.ty;
+        let readable = matches!(field.perm, Permission::Inherit | Permission::ReadOnly);
+        let writable = matches!(field.perm, Permission::Inherit | Permission::WriteOnly);
+
+        if readable {
+            reads.push_str(&format!(
+                "{name}: ::volatile_mem::VolatileRead::read(unsafe {{ \
+                 &*(::core::ptr::addr_of!((*(vol as *const {vol}<Self, P> as *const Self)).{name}) \
+                 as *const {vol}<{ty}, P>) }}),",
+                name = name,
+                vol = vol,
+                ty = ty,
+            ));
+        } else {
+            reads.push_str(&format!("{}: ::core::default::Default::default(),", name));
+        }
+        if writable {
+            writes.push_str(&format!(
+                "::volatile_mem::VolatileWrite::write(unsafe {{ \
+                 &mut *(::core::ptr::addr_of_mut!((*(vol as *mut {vol}<Self, P> as *mut Self)).{name}) \
+                 as *mut {vol}<{ty}, P>) }}, val.{name});",
+                name = name,
+                vol = vol,
+                ty = ty,
+            ));
+        }
+    }
+
+    format!(
+        "impl {name} {{ \
+         #[doc = {read_doc:?}] \
+         #[allow(unused_variables)] \
+         {vis} fn read_all<P: {private}::Read>(vol: &{vol}<Self, P>) -> Self {{ Self {{ {reads} }} }} \
+         #[doc = {write_doc:?}] \
+         #[allow(unused_variables)] \
+         {vis} fn write_all<P: {private}::Write>(vol: &mut {vol}<Self, P>, val: &Self) {{ {writes} }} }}",
+        name = item.name,
+        read_doc = "Reads each readable field of `vol` with a separate volatile read, in \
+                    declaration order.",
+        write_doc = "Writes each writable field of `vol` with a separate volatile write, in \
+                     declaration order.",
+        vis = item.vis,
+        private = private,
+        vol = vol,
+        reads = reads,
+        writes = writes,
+    )
+}
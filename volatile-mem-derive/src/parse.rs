@@ -0,0 +1,241 @@
+use proc_macro::{Delimiter, Spacing, TokenStream, TokenTree};
+
+/// A permission given by a `#[volatile(...)]` field attribute.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Permission {
+    /// The permission of the containing `Volatile`.
+    Inherit,
+    /// Read-only, regardless of the containing `Volatile`.
+    ReadOnly,
+    /// Write-only, regardless of the containing `Volatile`.
+    WriteOnly,
+    /// The field has no accessors.
+    Skip,
+}
+
+/// A named field of a struct.
+#[derive(Debug)]
+pub struct Field {
+    /// The field's doc comments, as `#[doc = ...]` attributes.
+    pub docs: Vec<String>,
+    /// The field's permission.
+    pub perm: Permission,
+    /// The field's visibility.
+    pub vis: String,
+    /// The field's name.
+    pub name: String,
+    /// The field's type.
+    pub ty: String,
+    /// The field's reset value, given by `#[volatile(reset = ...)]`.
+    pub reset: Option<String>,
+}
+
+impl Field {
+    /// Returns the field's name without any `r#` prefix.
+    pub fn bare_name(&self) -> &str {
+        self.name.trim_start_matches("r#")
+    }
+}
+
+/// A struct with named fields.
+#[derive(Debug)]
+pub struct Struct {
+    /// The struct's visibility.
+    pub vis: String,
+    /// The struct's name.
+    pub name: String,
+    /// The struct's fields, in declaration order.
+    pub fields: Vec<Field>,
+}
+
+/// Parses a struct with named fields and no generic parameters.
+pub fn parse_struct(input: TokenStream) -> Result<Struct, String> {
+    let mut tokens = input.into_iter().peekable();
+    skip_attrs(&mut tokens);
+    let vis = parse_vis(&mut tokens);
+
+    match tokens.next() {
+        Some(TokenTree::Ident(ident)) if ident.to_string() == "struct" => {}
+        _ => return Err("only structs are supported".into()),
+    }
+    let name = match tokens.next() {
+        Some(TokenTree::Ident(ident)) => ident.to_string(),
+        _ => return Err("expected a struct name".into()),
+    };
+    let body = match tokens.next() {
+        Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Brace => group.stream(),
+        Some(TokenTree::Punct(punct)) if punct.as_char() == '<' => {
+            return Err("generic structs are not supported".into())
+        }
+        _ => return Err("only structs with named fields are supported".into()),
+    };
+
+    let fields = split_fields(body)
+        .into_iter()
+        .map(parse_field)
+        .collect::<Result<_, _>>()?;
+
+    Ok(Struct { vis, name, fields })
+}
+
+type Tokens = core::iter::Peekable<proc_macro::token_stream::IntoIter>;
+
+fn is_punct(token: Option<&TokenTree>, ch: char) -> bool {
+    matches!(token, Some(TokenTree::Punct(punct)) if punct.as_char() == ch)
+}
+
+fn skip_attrs(tokens: &mut Tokens) {
+    while is_punct(tokens.peek(), '#') {
+        tokens.next();
+        tokens.next();
+    }
+}
+
+fn parse_vis(tokens: &mut Tokens) -> String {
+    match tokens.peek() {
+        Some(TokenTree::Ident(ident)) if ident.to_string() == "pub" => {}
+        _ => return String::new(),
+    }
+    let mut vis = tokens.next().map(|t| t.to_string()).unwrap_or_default();
+    if let Some(TokenTree::Group(group)) = tokens.peek() {
+        if group.delimiter() == Delimiter::Parenthesis {
+            vis.push_str(&group.to_string());
+            tokens.next();
+        }
+    }
+    vis
+}
+
+/// Splits the body of a struct, or the arguments of an attribute, at each
+/// top-level comma.
+fn split_fields(body: TokenStream) -> Vec<Vec<TokenTree>> {
+    let mut fields = Vec::new();
+    let mut field = Vec::new();
+    let mut depth = 0usize;
+    let mut prev_joint_dash = false;
+
+    for token in body {
+        let mut joint_dash = false;
+        if let TokenTree::Punct(punct) = &token {
+            match punct.as_char() {
+                '<' => depth += 1,
+                '>' if !prev_joint_dash => depth = depth.saturating_sub(1),
+                '-' => joint_dash = punct.spacing() == Spacing::Joint,
+                ',' if depth == 0 => {
+                    fields.push(core::mem::take(&mut field));
+                    prev_joint_dash = false;
+                    continue;
+                }
+                _ => {}
+            }
+        }
+        prev_joint_dash = joint_dash;
+        field.push(token);
+    }
+    if !field.is_empty() {
+        fields.push(field);
+    }
+
+    fields
+}
+
+fn parse_field(tokens: Vec<TokenTree>) -> Result<Field, String> {
+    let mut tokens = tokens
+        .into_iter()
+        .collect::<TokenStream>()
+        .into_iter()
+        .peekable();
+    let mut docs = Vec::new();
+    let mut perm = Permission::Inherit;
+    let mut reset = None;
+
+    while is_punct(tokens.peek(), '#') {
+        let pound = tokens.next();
+        let attr = match tokens.next() {
+            Some(TokenTree::Group(group)) => group,
+            _ => return Err("expected an attribute".into()),
+        };
+        let mut inner = attr.stream().into_iter();
+        match inner.next() {
+            Some(TokenTree::Ident(ident)) if ident.to_string() == "doc" => {
+                docs.push(format!(
+                    "{}{}",
+                    pound.map(|t| t.to_string()).unwrap_or_default(),
+                    attr
+                ));
+            }
+            Some(TokenTree::Ident(ident)) if ident.to_string() == "volatile" => {
+                parse_volatile_args(inner.next(), &mut perm, &mut reset)?;
+            }
+            _ => {}
+        }
+    }
+    let vis = parse_vis(&mut tokens);
+
+    let name = match tokens.next() {
+        Some(TokenTree::Ident(ident)) => ident.to_string(),
+        _ => return Err("only structs with named fields are supported".into()),
+    };
+    if !is_punct(tokens.next().as_ref(), ':') {
+        return Err(format!("expected a type for field `{}`", name));
+    }
+    let ty = tokens.collect::<TokenStream>().to_string();
+
+    Ok(Field {
+        docs,
+        perm,
+        vis,
+        name,
+        ty,
+        reset,
+    })
+}
+
+/// Parses the comma-separated arguments of a `#[volatile(...)]` attribute.
+fn parse_volatile_args(
+    args: Option<TokenTree>,
+    perm: &mut Permission,
+    reset: &mut Option<String>,
+) -> Result<(), String> {
+    let args = match args {
+        Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Parenthesis => {
+            group.stream()
+        }
+        _ => TokenStream::new(),
+    };
+    let args = split_fields(args);
+    if args.is_empty() {
+        return Err(volatile_arg_error(""));
+    }
+
+    for arg in args {
+        let mut tokens = arg.iter();
+        match tokens.next() {
+            Some(TokenTree::Ident(ident)) if ident.to_string() == "reset" => {
+                if !is_punct(tokens.next(), '=') || tokens.len() == 0 {
+                    return Err("expected `#[volatile(reset = value)]`".into());
+                }
+                *reset = Some(tokens.cloned().collect::<TokenStream>().to_string());
+            }
+            _ => {
+                let arg = arg.into_iter().collect::<TokenStream>().to_string();
+                *perm = match arg.trim() {
+                    "read_only" => Permission::ReadOnly,
+                    "write_only" => Permission::WriteOnly,
+                    "skip" => Permission::Skip,
+                    _ => return Err(volatile_arg_error(&arg)),
+                };
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn volatile_arg_error(arg: &str) -> String {
+    format!(
+        "expected `read_only`, `write_only`, `skip`, or `reset = value` in \
+         `#[volatile(...)]`, found `{}`",
+        arg
+    )
+}